@@ -0,0 +1,321 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+
+use halo2curves::bn256::Fr;
+use halo2::circuit::Value;
+use halo2::plonk::{
+    Advice, Any, Assigned, Assignment, Challenge, Circuit, Column, ConstraintSystem, Error, Expression,
+    FloorPlanner, Fixed, Instance, Selector,
+};
+
+/// region 밖에서 할당된 셀
+const OUTSIDE_REGION: &str = "<outside region>";
+
+/// 어떤 gate, copy, lookup에도 걸리지 않은 advice 셀
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnconstrainedCell {
+    /// namespace 경로를 포함한 region 이름
+    pub region: String,
+    pub annotation: String,
+    pub column: usize,
+    pub row: usize,
+}
+
+/// 회로 감사 결과
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditReport {
+    pub advice_cells: usize,
+    pub unconstrained: Vec<UnconstrainedCell>,
+}
+
+impl AuditReport {
+    pub fn is_clean(&self) -> bool {
+        self.unconstrained.is_empty()
+    }
+}
+
+impl fmt::Display for AuditReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} / {} advice cells unconstrained", self.unconstrained.len(), self.advice_cells)?;
+        for cell in &self.unconstrained {
+            writeln!(f, "  {} `{}`: advice {} row {}", cell.region, cell.annotation, cell.column, cell.row)?;
+        }
+        Ok(())
+    }
+}
+
+/// 회로를 합성해서 제약에 걸리지 않은 advice 셀을 찾는다
+///
+/// gate가 어떤 행에서 켜졌는지는 다음 기준으로 추정함
+/// - selector를 쓰는 gate: 그중 하나라도 enable된 행
+/// - selector 없이 fixed 컬럼을 쓰는 gate (maingate 계수 등): 쿼리한 fixed 셀 중 0이 아닌 값이 있는 행
+/// - 둘 다 없는 gate: 모든 행
+/// 켜진 행에서 gate가 쿼리하는 advice 셀(회전 포함)은 제약된 것으로 봄. lookup 입력도 같은 방식.
+/// 계수가 0인 항까지 제약으로 세므로 실제로는 자유로운 셀을 놓칠 수 있음 (보고된 셀은 확실히 자유로움)
+pub fn audit<C: Circuit<Fr>>(circuit: &C) -> Result<AuditReport, Error> {
+    let mut cs = ConstraintSystem::default();
+    let config = C::configure(&mut cs);
+    let mut recorder = Recorder::default();
+    C::FloorPlanner::synthesize(&mut recorder, circuit, config, cs.constants().clone())?;
+
+    let rows = recorder.advice.keys().map(|(_, row)| row + 1).max().unwrap_or(0);
+    let mut constrained = recorder.copied.clone();
+
+    let constraints = cs
+        .gates()
+        .iter()
+        .map(|gate| Queries::of(gate.polynomials()))
+        .chain(cs.lookups().iter().map(|lookup| Queries::of(lookup.input_expressions())));
+    for queries in constraints {
+        for row in 0..rows {
+            if queries.active(&recorder, row) {
+                for (column, rotation) in &queries.advice {
+                    if let Some(row) = offset(row, *rotation) {
+                        constrained.insert((*column, row));
+                    }
+                }
+            }
+        }
+    }
+
+    let unconstrained = recorder
+        .advice
+        .iter()
+        .filter(|(cell, _)| !constrained.contains(cell))
+        .map(|(&(column, row), (region, annotation))| UnconstrainedCell {
+            region: region.clone(),
+            annotation: annotation.clone(),
+            column,
+            row,
+        })
+        .collect();
+
+    Ok(AuditReport { advice_cells: recorder.advice.len(), unconstrained })
+}
+
+fn offset(row: usize, rotation: i32) -> Option<usize> {
+    usize::try_from(row as i64 + rotation as i64).ok()
+}
+
+/// gate 또는 lookup 입력 하나가 쿼리하는 셀
+#[derive(Default)]
+struct Queries {
+    selectors: HashSet<Selector>,
+    fixed: HashSet<(usize, i32)>,
+    advice: HashSet<(usize, i32)>,
+}
+
+impl Queries {
+    fn of(expressions: &[Expression<Fr>]) -> Self {
+        let mut queries = Self::default();
+        for expression in expressions {
+            queries.walk(expression);
+        }
+        queries
+    }
+
+    fn walk(&mut self, expression: &Expression<Fr>) {
+        match expression {
+            Expression::Selector(selector) => {
+                self.selectors.insert(*selector);
+            }
+            Expression::Fixed(query) => {
+                self.fixed.insert((query.column_index(), query.rotation().0));
+            }
+            Expression::Advice(query) => {
+                self.advice.insert((query.column_index(), query.rotation().0));
+            }
+            Expression::Negated(a) | Expression::Scaled(a, _) => self.walk(a),
+            Expression::Sum(a, b) | Expression::Product(a, b) => {
+                self.walk(a);
+                self.walk(b);
+            }
+            _ => {}
+        }
+    }
+
+    fn active(&self, recorder: &Recorder, row: usize) -> bool {
+        if !self.selectors.is_empty() {
+            return self.selectors.iter().any(|selector| recorder.selectors.contains(&(*selector, row)));
+        }
+        if !self.fixed.is_empty() {
+            return self.fixed.iter().any(|(column, rotation)| {
+                offset(row, *rotation).map_or(false, |row| recorder.fixed.contains(&(*column, row)))
+            });
+        }
+        true
+    }
+}
+
+/// 할당, selector, copy를 기록하는 Assignment
+#[derive(Default)]
+struct Recorder {
+    namespaces: Vec<String>,
+    region: Option<String>,
+    /// (column, row) -> (region, annotation)
+    advice: BTreeMap<(usize, usize), (String, String)>,
+    /// 0이 아닌 값이 들어간 fixed 셀
+    fixed: HashSet<(usize, usize)>,
+    selectors: HashSet<(Selector, usize)>,
+    /// copy constraint에 걸린 advice 셀
+    copied: HashSet<(usize, usize)>,
+}
+
+impl Assignment<Fr> for Recorder {
+    fn enter_region<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        let mut path = self.namespaces.clone();
+        path.push(name_fn().into());
+        self.region = Some(path.join("/"));
+    }
+
+    fn exit_region(&mut self) {
+        self.region = None;
+    }
+
+    fn enable_selector<A, AR>(&mut self, _: A, selector: &Selector, row: usize) -> Result<(), Error>
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.selectors.insert((*selector, row));
+        Ok(())
+    }
+
+    fn query_instance(&self, _: Column<Instance>, _: usize) -> Result<Value<Fr>, Error> {
+        Ok(Value::unknown())
+    }
+
+    fn assign_advice<V, VR, A, AR>(&mut self, annotation: A, column: Column<Advice>, row: usize, _: V) -> Result<(), Error>
+    where
+        V: FnOnce() -> Value<VR>,
+        VR: Into<Assigned<Fr>>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let region = self.region.clone().unwrap_or_else(|| OUTSIDE_REGION.to_string());
+        self.advice.insert((column.index(), row), (region, annotation().into()));
+        Ok(())
+    }
+
+    fn assign_fixed<V, VR, A, AR>(&mut self, _: A, column: Column<Fixed>, row: usize, to: V) -> Result<(), Error>
+    where
+        V: FnOnce() -> Value<VR>,
+        VR: Into<Assigned<Fr>>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        // fixed 값은 witness와 무관하게 항상 알려져 있음
+        to().map(|v| {
+            if !v.into().is_zero_vartime() {
+                self.fixed.insert((column.index(), row));
+            }
+        });
+        Ok(())
+    }
+
+    fn copy(&mut self, left: Column<Any>, left_row: usize, right: Column<Any>, right_row: usize) -> Result<(), Error> {
+        for (column, row) in [(left, left_row), (right, right_row)] {
+            if matches!(column.column_type(), Any::Advice(_)) {
+                self.copied.insert((column.index(), row));
+            }
+        }
+        Ok(())
+    }
+
+    fn fill_from_row(&mut self, _: Column<Fixed>, _: usize, _: Value<Assigned<Fr>>) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn get_challenge(&self, _: Challenge) -> Value<Fr> {
+        Value::unknown()
+    }
+
+    fn annotate_column<A, AR>(&mut self, _: A, _: Column<Any>)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.namespaces.push(name_fn().into());
+    }
+
+    fn pop_namespace(&mut self, _: Option<String>) {
+        self.namespaces.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::range_check::tests::circuit as range_circuit;
+    use crate::post_proof::PostProofCircuit;
+    use crate::test_utils::{merkle_proof, random_fr};
+    use halo2::circuit::{Layouter, SimpleFloorPlanner};
+
+    /// 값을 할당만 하고 아무 제약도 걸지 않는 회로
+    #[derive(Clone, Default)]
+    struct StrayCell;
+
+    impl Circuit<Fr> for StrayCell {
+        type Config = Column<Advice>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            meta.advice_column()
+        }
+
+        fn synthesize(&self, column: Self::Config, mut layouter: impl Layouter<Fr>) -> Result<(), Error> {
+            layouter.assign_region(
+                || "stray",
+                |mut region| region.assign_advice(|| "free", column, 0, || Value::known(Fr::one())).map(|_| ()),
+            )
+        }
+    }
+
+    #[test]
+    fn reports_stray_cell() {
+        let report = audit(&StrayCell).unwrap();
+        assert_eq!(
+            report.unconstrained,
+            vec![UnconstrainedCell { region: "stray".to_string(), annotation: "free".to_string(), column: 0, row: 0 }]
+        );
+    }
+
+    #[test]
+    fn range_check_is_clean() {
+        let report = audit(&range_circuit(30, 18, 65)).unwrap();
+        assert!(report.is_clean(), "{report}");
+    }
+
+    #[test]
+    fn post_proof_is_clean() {
+        let mut circuit = PostProofCircuit {
+            value: Fr::from(30),
+            min: Fr::from(20),
+            max: Fr::from(40),
+            holder_secret: random_fr(),
+            app_scope: random_fr(),
+            post_hash: random_fr(),
+            merkle_root: Fr::zero(),
+            merkle_proof: vec![],
+            leaf_index: 5,
+        };
+        (circuit.merkle_proof, circuit.merkle_root) = merkle_proof(circuit.claim_hash(), 4, 5);
+        let report = audit(&circuit).unwrap();
+        assert!(report.is_clean(), "{report}");
+    }
+}
@@ -0,0 +1,338 @@
+//! ceremony 결과에서 KZG params 읽기 (모두 로컬 파일)
+//!
+//! - 이 crate의 params 파일 (`keys::write_params`)
+//! - halo2 `ParamsKZG::write` 형식 (변환된 perpetual-powers-of-tau 포함)
+//! - snarkjs `.ptau`
+//!
+//! 읽은 뒤 요청한 k로 줄이고 pairing으로 일관성을 확인함
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use halo2::arithmetic::{best_multiexp, g_to_lagrange};
+use halo2::poly::commitment::{Blind, Params};
+use halo2::poly::kzg::commitment::ParamsKZG;
+use halo2::poly::EvaluationDomain;
+use halo2curves::bn256::{Bn256, Fr, G1Affine, G2Affine, G1};
+use halo2curves::ff::Field;
+use halo2curves::group::prime::PrimeCurveAffine;
+use halo2curves::group::Curve;
+use halo2curves::pairing::Engine;
+use halo2curves::serde::SerdeObject;
+use rand_core::OsRng;
+
+use crate::keys::{self, KeyError, MAGIC as KEYS_MAGIC};
+
+const PTAU_MAGIC: [u8; 4] = *b"ptau";
+const PTAU_HEADER_SECTION: u32 = 1;
+const PTAU_TAU_G1_SECTION: u32 = 2;
+const PTAU_TAU_G2_SECTION: u32 = 3;
+/// BN254 좌표 하나의 바이트 수
+const FQ_BYTES: usize = 32;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParamsFormat {
+    /// header가 붙은 이 crate의 params 파일
+    Keyed,
+    /// halo2 `ParamsKZG::write` (RawBytes)
+    Halo2,
+    /// snarkjs powers of tau
+    Ptau,
+}
+
+#[derive(Debug)]
+pub enum CeremonyError {
+    Io(io::Error),
+    Key(KeyError),
+    /// ptau 파일 구조가 잘못됨
+    InvalidPtau(&'static str),
+    /// 파일에 있는 power보다 큰 k를 요청함
+    TooSmall { available: u32, requested: u32 },
+    /// pairing 등 일관성 검사 실패
+    Inconsistent(&'static str),
+}
+
+impl fmt::Display for CeremonyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CeremonyError::Io(error) => write!(f, "{error}"),
+            CeremonyError::Key(error) => write!(f, "{error}"),
+            CeremonyError::InvalidPtau(reason) => write!(f, "invalid ptau file: {reason}"),
+            CeremonyError::TooSmall { available, requested } => {
+                write!(f, "params support k <= {available}, requested k = {requested}")
+            }
+            CeremonyError::Inconsistent(reason) => write!(f, "inconsistent params: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for CeremonyError {}
+
+impl From<io::Error> for CeremonyError {
+    fn from(error: io::Error) -> Self {
+        CeremonyError::Io(error)
+    }
+}
+
+impl From<KeyError> for CeremonyError {
+    fn from(error: KeyError) -> Self {
+        CeremonyError::Key(error)
+    }
+}
+
+/// 파일 앞부분으로 형식 판별. 모르는 형식은 halo2로 봄
+pub fn detect_format(path: &Path) -> io::Result<ParamsFormat> {
+    let mut magic = [0u8; 8];
+    let read = File::open(path)?.read(&mut magic)?;
+    Ok(if read >= 8 && magic == KEYS_MAGIC {
+        ParamsFormat::Keyed
+    } else if read >= 4 && magic[..4] == PTAU_MAGIC {
+        ParamsFormat::Ptau
+    } else {
+        ParamsFormat::Halo2
+    })
+}
+
+/// params를 읽고 k가 있으면 줄인 뒤 일관성 검사까지 함
+pub fn load_params(path: &Path, k: Option<u32>) -> Result<ParamsKZG<Bn256>, CeremonyError> {
+    let mut params = match detect_format(path)? {
+        ParamsFormat::Keyed => keys::read_params(path)?,
+        ParamsFormat::Halo2 => ParamsKZG::read(&mut BufReader::new(File::open(path)?))?,
+        ParamsFormat::Ptau => read_ptau(path, k)?,
+    };
+    if let Some(k) = k {
+        if k > params.k() {
+            return Err(CeremonyError::TooSmall { available: params.k(), requested: k });
+        }
+        if k < params.k() {
+            params.downsize(k);
+        }
+    }
+    check_params(&params)?;
+    Ok(params)
+}
+
+/// `.ptau`의 tauG1 앞 2^k개와 tauG2 앞 2개로 params 생성
+/// k가 없으면 파일의 power를 그대로 씀
+pub fn read_ptau(path: &Path, k: Option<u32>) -> Result<ParamsKZG<Bn256>, CeremonyError> {
+    let mut reader = BufReader::new(File::open(path)?);
+    if read_bytes::<4>(&mut reader)? != PTAU_MAGIC {
+        return Err(CeremonyError::InvalidPtau("missing ptau magic"));
+    }
+    let _version = u32::from_le_bytes(read_bytes(&mut reader)?);
+    let num_sections = u32::from_le_bytes(read_bytes(&mut reader)?);
+
+    // section 순서는 정해져 있지 않으므로 위치만 먼저 기록
+    let mut sections = vec![];
+    for _ in 0..num_sections {
+        let id = u32::from_le_bytes(read_bytes(&mut reader)?);
+        let size = u64::from_le_bytes(read_bytes(&mut reader)?);
+        let start = reader.stream_position()?;
+        sections.push((id, start, size));
+        reader.seek(SeekFrom::Start(start + size))?;
+    }
+    let section = |id: u32| {
+        sections
+            .iter()
+            .find(|(section, _, _)| *section == id)
+            .map(|(_, start, size)| (*start, *size))
+            .ok_or(CeremonyError::InvalidPtau("missing section"))
+    };
+
+    let (header, _) = section(PTAU_HEADER_SECTION)?;
+    reader.seek(SeekFrom::Start(header))?;
+    let n8 = u32::from_le_bytes(read_bytes(&mut reader)?) as usize;
+    if n8 != FQ_BYTES {
+        return Err(CeremonyError::InvalidPtau("not a BN254 ceremony"));
+    }
+    reader.seek(SeekFrom::Current(n8 as i64))?;
+    let power = u32::from_le_bytes(read_bytes(&mut reader)?);
+    let k = k.unwrap_or(power);
+    if k > power {
+        return Err(CeremonyError::TooSmall { available: power, requested: k });
+    }
+
+    let n = 1usize << k;
+    let (tau_g1, size) = section(PTAU_TAU_G1_SECTION)?;
+    if size < (n * 2 * FQ_BYTES) as u64 {
+        return Err(CeremonyError::InvalidPtau("tauG1 section too short"));
+    }
+    reader.seek(SeekFrom::Start(tau_g1))?;
+    let g = (0..n)
+        .map(|_| {
+            let mut bytes = [0u8; 2 * FQ_BYTES];
+            reader.read_exact(&mut bytes)?;
+            G1Affine::from_raw_bytes(&bytes).ok_or(CeremonyError::InvalidPtau("invalid tauG1 point"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (tau_g2, _) = section(PTAU_TAU_G2_SECTION)?;
+    reader.seek(SeekFrom::Start(tau_g2))?;
+    let mut g2 = [G2Affine::identity(); 2];
+    for point in g2.iter_mut() {
+        let mut bytes = [0u8; 4 * FQ_BYTES];
+        reader.read_exact(&mut bytes)?;
+        *point = G2Affine::from_raw_bytes(&bytes).ok_or(CeremonyError::InvalidPtau("invalid tauG2 point"))?;
+    }
+
+    // ParamsKZG를 직접 만들 수 없으므로 halo2 RawBytes 형식으로 써서 다시 읽음
+    let g_lagrange = g_to_lagrange(g.iter().map(|p| p.to_curve()).collect::<Vec<G1>>(), k);
+    let mut bytes = k.to_le_bytes().to_vec();
+    for point in g.iter().chain(g_lagrange.iter()) {
+        point.write_raw(&mut bytes)?;
+    }
+    g2[0].write_raw(&mut bytes)?;
+    g2[1].write_raw(&mut bytes)?;
+    Ok(ParamsKZG::read(&mut bytes.as_slice())?)
+}
+
+/// params가 하나의 tau에서 나왔는지 확인
+/// - g[0], g2가 생성원
+/// - 임의의 r_i에 대해 e(Σ r_i g[i+1], g2) = e(Σ r_i g[i], s·g2)
+/// - Lagrange 기저의 합이 g[0] (Σ L_i(X) = 1)
+pub fn check_params(params: &ParamsKZG<Bn256>) -> Result<(), CeremonyError> {
+    let g = params.get_g();
+    if g[0] != G1Affine::generator() {
+        return Err(CeremonyError::Inconsistent("g[0] is not the G1 generator"));
+    }
+    if params.g2() != G2Affine::generator() {
+        return Err(CeremonyError::Inconsistent("g2 is not the G2 generator"));
+    }
+
+    let coefficients: Vec<Fr> = (1..g.len()).map(|_| Fr::random(OsRng)).collect();
+    let lhs = best_multiexp(&coefficients, &g[1..]).to_affine();
+    let rhs = best_multiexp(&coefficients, &g[..g.len() - 1]).to_affine();
+    if Bn256::pairing(&lhs, &params.g2()) != Bn256::pairing(&rhs, &params.s_g2()) {
+        return Err(CeremonyError::Inconsistent("powers of tau do not match s_g2"));
+    }
+
+    let domain = EvaluationDomain::<Fr>::new(1, params.k());
+    let mut ones = domain.empty_lagrange();
+    ones.iter_mut().for_each(|value| *value = Fr::one());
+    if params.commit_lagrange(&ones, Blind(Fr::zero())).to_affine() != g[0] {
+        return Err(CeremonyError::Inconsistent("lagrange basis does not match g"));
+    }
+    Ok(())
+}
+
+fn read_bytes<const N: usize>(reader: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prover::setup_params;
+    use std::path::PathBuf;
+
+    /// params의 tau로 snarkjs 형식 `.ptau`를 만듦 (header, tauG1, tauG2 section만)
+    fn ptau_bytes(params: &ParamsKZG<Bn256>, g2: &[G2Affine; 2]) -> Vec<u8> {
+        let mut header = (FQ_BYTES as u32).to_le_bytes().to_vec();
+        header.extend([0u8; FQ_BYTES]);
+        header.extend(params.k().to_le_bytes());
+        let mut tau_g1 = vec![];
+        params.get_g().iter().for_each(|point| point.write_raw(&mut tau_g1).unwrap());
+        let mut tau_g2 = vec![];
+        g2.iter().for_each(|point| point.write_raw(&mut tau_g2).unwrap());
+
+        let mut bytes = PTAU_MAGIC.to_vec();
+        bytes.extend(1u32.to_le_bytes());
+        bytes.extend(3u32.to_le_bytes());
+        // tauG2를 먼저 써서 section 순서에 의존하지 않는지도 확인
+        for (id, section) in [(PTAU_TAU_G2_SECTION, tau_g2), (PTAU_HEADER_SECTION, header), (PTAU_TAU_G1_SECTION, tau_g1)] {
+            bytes.extend(id.to_le_bytes());
+            bytes.extend((section.len() as u64).to_le_bytes());
+            bytes.extend(section);
+        }
+        bytes
+    }
+
+    fn write_temp(name: &str, bytes: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("ceremony-{}-{name}", std::process::id()));
+        std::fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    #[test]
+    fn accepts_generated_params() {
+        let mut params = setup_params(6);
+        check_params(&params).unwrap();
+        params.downsize(4);
+        check_params(&params).unwrap();
+    }
+
+    #[test]
+    fn rejects_params_from_different_taus() {
+        let mut bytes = vec![];
+        setup_params(4).write(&mut bytes).unwrap();
+        let mut other = vec![];
+        setup_params(4).write(&mut other).unwrap();
+        // 마지막 G2 점(s_g2)만 다른 tau의 것으로 바꿈
+        let s_g2 = bytes.len() - 4 * FQ_BYTES;
+        bytes[s_g2..].copy_from_slice(&other[s_g2..]);
+        let mixed = ParamsKZG::<Bn256>::read(&mut bytes.as_slice()).unwrap();
+        assert!(matches!(check_params(&mixed), Err(CeremonyError::Inconsistent(_))));
+    }
+
+    #[test]
+    fn detects_formats() {
+        let params = setup_params(3);
+        let ptau = write_temp("detect.ptau", &ptau_bytes(&params, &[params.g2(), params.s_g2()]));
+        let mut raw = vec![];
+        params.write(&mut raw).unwrap();
+        let halo2 = write_temp("detect.halo2", &raw);
+        let keyed = std::env::temp_dir().join(format!("ceremony-{}-detect.params", std::process::id()));
+        keys::write_params(&keyed, &params).unwrap();
+
+        assert_eq!(detect_format(&ptau).unwrap(), ParamsFormat::Ptau);
+        assert_eq!(detect_format(&halo2).unwrap(), ParamsFormat::Halo2);
+        assert_eq!(detect_format(&keyed).unwrap(), ParamsFormat::Keyed);
+        for path in [ptau, halo2, keyed] {
+            std::fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn reads_synthetic_ptau() {
+        let params = setup_params(4);
+        let path = write_temp("read.ptau", &ptau_bytes(&params, &[params.g2(), params.s_g2()]));
+        let loaded = load_params(&path, None).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(loaded.k(), 4);
+        assert_eq!(loaded.get_g(), params.get_g());
+        assert_eq!(loaded.s_g2(), params.s_g2());
+    }
+
+    #[test]
+    fn downsizes_ptau() {
+        let mut params = setup_params(5);
+        let path = write_temp("downsize.ptau", &ptau_bytes(&params, &[params.g2(), params.s_g2()]));
+        let loaded = load_params(&path, Some(3));
+        let too_large = load_params(&path, Some(6));
+        std::fs::remove_file(path).unwrap();
+
+        let loaded = loaded.unwrap();
+        params.downsize(3);
+        assert_eq!(loaded.k(), 3);
+        assert_eq!(loaded.get_g(), params.get_g());
+        assert!(matches!(too_large, Err(CeremonyError::TooSmall { available: 5, requested: 6 })));
+    }
+
+    #[test]
+    fn rejects_corrupted_ptau() {
+        let params = setup_params(4);
+        // tauG2 section의 s_g2를 다른 tau의 것으로 바꿈
+        let other = setup_params(4);
+        let path = write_temp("corrupted.ptau", &ptau_bytes(&params, &[params.g2(), other.s_g2()]));
+        let read = read_ptau(&path, None);
+        let loaded = load_params(&path, None);
+        std::fs::remove_file(path).unwrap();
+
+        assert!(matches!(check_params(&read.unwrap()), Err(CeremonyError::Inconsistent(_))));
+        assert!(matches!(loaded, Err(CeremonyError::Inconsistent(_))));
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt;
+
+use halo2curves::bn256::{Fr, G1};
+use halo2::dev::CircuitCost;
+use halo2::plonk::{Circuit, Error};
+
+use crate::sizing::estimate;
+
+/// verify gas 추정에 쓰는 EVM precompile / calldata 비용
+const TX_BASE_GAS: u64 = 21_000;
+const CALLDATA_BYTE_GAS: u64 = 16;
+const EC_MUL_GAS: u64 = 6_000;
+const EC_ADD_GAS: u64 = 150;
+const PAIRING_BASE_GAS: u64 = 45_000;
+const PAIRING_PER_PAIR_GAS: u64 = 34_000;
+/// evaluation 하나당 field 연산 + memory 비용. 측정값이 아닌 대략적인 heuristic
+const PER_EVALUATION_GAS: u64 = 1_200;
+
+/// 회로 하나의 비용 요약. snapshot 파일의 한 항목
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CostReport {
+    pub k: u32,
+    pub rows: usize,
+    pub advice_columns: usize,
+    pub fixed_columns: usize,
+    pub instance_columns: usize,
+    pub gates: usize,
+    pub lookups: usize,
+    pub permutation_columns: usize,
+    /// SHPLONK 기준 proof 바이트 수
+    pub proof_size: usize,
+    /// Solidity verifier 호출 gas 추정치 (calldata + EC precompile + pairing + evaluation heuristic)
+    /// 실제 배포한 verifier로 잰 값이 아님
+    pub verify_gas: u64,
+}
+
+impl CostReport {
+    /// num_instances: public input 개수 (proof size, calldata 계산용)
+    pub fn measure<C: Circuit<Fr>>(circuit: &C, num_instances: usize) -> Result<Self, Error> {
+        let size = estimate(circuit)?;
+        let proof_size: usize = CircuitCost::<G1, C>::measure(size.k, circuit).proof_size(num_instances).into();
+
+        // proof 안의 G1 점(64바이트)마다 verifier가 MSM에 한 번씩 넣는다고 보고
+        // 나머지 32바이트 값과 column 수를 evaluation 수로 어림함 (rough heuristic: 실제 verifier의
+        // query 수, rotation, SHPLONK 구조를 반영하지 않으므로 회로 간 비교용으로만 쓸 것)
+        let points = (proof_size / 64) as u64;
+        let evaluations = ((proof_size % 64) / 32) as u64 + (size.advice_columns + size.fixed_columns) as u64;
+        let calldata = (proof_size + 32 * num_instances) as u64;
+        let verify_gas = TX_BASE_GAS
+            + CALLDATA_BYTE_GAS * calldata
+            + (EC_MUL_GAS + EC_ADD_GAS) * (points + num_instances as u64)
+            + PER_EVALUATION_GAS * evaluations
+            + PAIRING_BASE_GAS
+            + 2 * PAIRING_PER_PAIR_GAS;
+
+        Ok(Self {
+            k: size.k,
+            rows: size.rows,
+            advice_columns: size.advice_columns,
+            fixed_columns: size.fixed_columns,
+            instance_columns: size.instance_columns,
+            gates: size.gates,
+            lookups: size.lookups,
+            permutation_columns: size.permutation_columns,
+            proof_size,
+            verify_gas,
+        })
+    }
+
+    /// snapshot 파일 형식: 한 줄에 `key = value`
+    pub fn to_snapshot(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for CostReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "k = {}", self.k)?;
+        writeln!(f, "rows = {}", self.rows)?;
+        writeln!(f, "advice_columns = {}", self.advice_columns)?;
+        writeln!(f, "fixed_columns = {}", self.fixed_columns)?;
+        writeln!(f, "instance_columns = {}", self.instance_columns)?;
+        writeln!(f, "gates = {}", self.gates)?;
+        writeln!(f, "lookups = {}", self.lookups)?;
+        writeln!(f, "permutation_columns = {}", self.permutation_columns)?;
+        writeln!(f, "proof_size = {}", self.proof_size)?;
+        writeln!(f, "verify_gas = {}", self.verify_gas)
+    }
+}
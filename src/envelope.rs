@@ -0,0 +1,339 @@
+//! 저장, 전송용 proof 묶음: 회로 fingerprint, transcript 종류, k, public input, proof
+//!
+//! binary: magic(8) | version u32 | fingerprint 32B | transcript u8 | k u32
+//!         | column 수 u32 | (원소 수 u32 | 원소 32B LE)* | proof 길이 u32 | proof
+//! JSON: 같은 내용, field 원소와 바이트는 `0x` hex
+
+use std::fmt;
+
+use halo2::plonk::{Circuit, Error, ProvingKey, VerifyingKey};
+use halo2::poly::commitment::Params;
+use halo2::poly::kzg::commitment::ParamsKZG;
+use halo2curves::bn256::{Bn256, Fr, G1Affine};
+use halo2curves::ff::PrimeField;
+use serde::{Deserialize, Serialize};
+
+use crate::fingerprint::Fingerprint;
+use crate::input::{format_field, parse_field};
+use crate::prover;
+
+const MAGIC: [u8; 8] = *b"CLMPROOF";
+pub const ENVELOPE_VERSION: u32 = 1;
+
+/// proof를 만들 때 쓴 Fiat-Shamir transcript
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TranscriptKind {
+    /// `prover::prove`, Solidity verifier
+    Keccak256,
+    /// 재귀 검증용 proof. 형식만 정의되어 있고 이 crate의 verify는 지원하지 않음
+    Poseidon,
+}
+
+impl TranscriptKind {
+    fn name(self) -> &'static str {
+        match self {
+            TranscriptKind::Keccak256 => "keccak256",
+            TranscriptKind::Poseidon => "poseidon",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "keccak256" => Some(TranscriptKind::Keccak256),
+            "poseidon" => Some(TranscriptKind::Poseidon),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum EnvelopeError {
+    BadMagic,
+    UnsupportedVersion(u32),
+    /// 데이터가 중간에 끝남
+    Truncated,
+    /// proof 뒤에 바이트가 더 있음
+    TrailingBytes(usize),
+    UnknownTranscript(String),
+    InvalidField(String),
+    Json(String),
+    /// envelope가 다른 회로(또는 다른 vk)의 proof
+    FingerprintMismatch { expected: Fingerprint, found: Fingerprint },
+    KMismatch { expected: u32, found: u32 },
+    /// 이 crate에서 검증할 수 없는 transcript
+    UnsupportedTranscript(TranscriptKind),
+    Verification(Error),
+}
+
+impl fmt::Display for EnvelopeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnvelopeError::BadMagic => write!(f, "not a proof envelope"),
+            EnvelopeError::UnsupportedVersion(version) => write!(f, "unsupported proof envelope version {version}"),
+            EnvelopeError::Truncated => write!(f, "proof envelope is truncated"),
+            EnvelopeError::TrailingBytes(len) => write!(f, "{len} unexpected bytes after the proof"),
+            EnvelopeError::UnknownTranscript(name) => write!(f, "unknown transcript `{name}`"),
+            EnvelopeError::InvalidField(message) => write!(f, "{message}"),
+            EnvelopeError::Json(message) => write!(f, "malformed proof envelope JSON: {message}"),
+            EnvelopeError::FingerprintMismatch { expected, found } => {
+                write!(f, "proof is for circuit {found}, verifying key is {expected}")
+            }
+            EnvelopeError::KMismatch { expected, found } => write!(f, "proof has k = {found}, expected {expected}"),
+            EnvelopeError::UnsupportedTranscript(kind) => {
+                write!(f, "cannot verify {} transcript proofs", kind.name())
+            }
+            EnvelopeError::Verification(error) => write!(f, "proof verification failed: {error:?}"),
+        }
+    }
+}
+
+impl std::error::Error for EnvelopeError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofEnvelope {
+    pub fingerprint: Fingerprint,
+    pub transcript: TranscriptKind,
+    pub k: u32,
+    pub instances: Vec<Vec<Fr>>,
+    pub proof: Vec<u8>,
+}
+
+/// JSON 형식
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EnvelopeJson {
+    version: u32,
+    fingerprint: String,
+    transcript: String,
+    k: u32,
+    instances: Vec<Vec<String>>,
+    proof: String,
+}
+
+impl ProofEnvelope {
+    /// Keccak256 transcript로 증명해서 envelope로 묶음
+    pub fn prove<C: Circuit<Fr>>(
+        params: &ParamsKZG<Bn256>,
+        pk: &ProvingKey<G1Affine>,
+        circuit: C,
+        instances: Vec<Vec<Fr>>,
+    ) -> Result<Self, Error> {
+        let proof = prover::prove(params, pk, circuit, &instances)?;
+        Ok(Self {
+            fingerprint: Fingerprint::of(pk.get_vk()),
+            transcript: TranscriptKind::Keccak256,
+            k: pk.get_vk().get_domain().k(),
+            instances,
+            proof,
+        })
+    }
+
+    /// envelope에 든 instance로 검증. vk의 fingerprint와 k가 envelope와 같아야 함
+    pub fn verify(&self, params: &ParamsKZG<Bn256>, vk: &VerifyingKey<G1Affine>) -> Result<(), EnvelopeError> {
+        let expected = Fingerprint::of(vk);
+        if expected != self.fingerprint {
+            return Err(EnvelopeError::FingerprintMismatch { expected, found: self.fingerprint });
+        }
+        if params.k() != self.k {
+            return Err(EnvelopeError::KMismatch { expected: params.k(), found: self.k });
+        }
+        match self.transcript {
+            TranscriptKind::Keccak256 => {
+                prover::verify(params, vk, &self.instances, &self.proof).map_err(EnvelopeError::Verification)
+            }
+            kind => Err(EnvelopeError::UnsupportedTranscript(kind)),
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend(ENVELOPE_VERSION.to_le_bytes());
+        bytes.extend(self.fingerprint.0);
+        bytes.push(match self.transcript {
+            TranscriptKind::Keccak256 => 0,
+            TranscriptKind::Poseidon => 1,
+        });
+        bytes.extend(self.k.to_le_bytes());
+        bytes.extend((self.instances.len() as u32).to_le_bytes());
+        for column in &self.instances {
+            bytes.extend((column.len() as u32).to_le_bytes());
+            for value in column {
+                bytes.extend(value.to_repr().as_ref());
+            }
+        }
+        bytes.extend((self.proof.len() as u32).to_le_bytes());
+        bytes.extend(&self.proof);
+        bytes
+    }
+
+    pub fn decode(mut bytes: &[u8]) -> Result<Self, EnvelopeError> {
+        let reader = &mut bytes;
+        if take::<8>(reader)? != MAGIC {
+            return Err(EnvelopeError::BadMagic);
+        }
+        let version = u32::from_le_bytes(take(reader)?);
+        if version != ENVELOPE_VERSION {
+            return Err(EnvelopeError::UnsupportedVersion(version));
+        }
+        let fingerprint = Fingerprint(take(reader)?);
+        let transcript = match take::<1>(reader)?[0] {
+            0 => TranscriptKind::Keccak256,
+            1 => TranscriptKind::Poseidon,
+            other => return Err(EnvelopeError::UnknownTranscript(other.to_string())),
+        };
+        let k = u32::from_le_bytes(take(reader)?);
+        let columns = u32::from_le_bytes(take(reader)?);
+        let mut instances = vec![];
+        for column in 0..columns {
+            let len = u32::from_le_bytes(take(reader)?);
+            let values = (0..len)
+                .map(|row| {
+                    let mut repr = <Fr as PrimeField>::Repr::default();
+                    repr.as_mut().copy_from_slice(&take::<32>(reader)?);
+                    Option::from(Fr::from_repr(repr)).ok_or_else(|| {
+                        EnvelopeError::InvalidField(format!("instances[{column}][{row}] is not a field element"))
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            instances.push(values);
+        }
+        let len = u32::from_le_bytes(take(reader)?) as usize;
+        if reader.len() < len {
+            return Err(EnvelopeError::Truncated);
+        }
+        if reader.len() > len {
+            return Err(EnvelopeError::TrailingBytes(reader.len() - len));
+        }
+        let proof = reader.to_vec();
+        Ok(Self { fingerprint, transcript, k, instances, proof })
+    }
+
+    pub fn to_json(&self) -> String {
+        let json = EnvelopeJson {
+            version: ENVELOPE_VERSION,
+            fingerprint: self.fingerprint.to_string(),
+            transcript: self.transcript.name().to_string(),
+            k: self.k,
+            instances: self.instances.iter().map(|column| column.iter().map(format_field).collect()).collect(),
+            proof: to_hex(&self.proof),
+        };
+        serde_json::to_string_pretty(&json).expect("envelope is always serializable")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, EnvelopeError> {
+        let json: EnvelopeJson = serde_json::from_str(json).map_err(|error| EnvelopeError::Json(error.to_string()))?;
+        if json.version != ENVELOPE_VERSION {
+            return Err(EnvelopeError::UnsupportedVersion(json.version));
+        }
+        let fingerprint = from_hex(&json.fingerprint)
+            .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+            .map(Fingerprint)
+            .ok_or_else(|| EnvelopeError::InvalidField("fingerprint must be 32 bytes of hex".to_string()))?;
+        let transcript =
+            TranscriptKind::from_name(&json.transcript).ok_or(EnvelopeError::UnknownTranscript(json.transcript))?;
+        let instances = json
+            .instances
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                column
+                    .iter()
+                    .enumerate()
+                    .map(|(j, value)| parse_field(&format!("instances[{i}][{j}]"), value))
+                    .collect::<Result<Vec<Fr>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| EnvelopeError::InvalidField(error.to_string()))?;
+        let proof = from_hex(&json.proof).ok_or_else(|| EnvelopeError::InvalidField("proof is not hex".to_string()))?;
+        Ok(Self { fingerprint, transcript, k: json.k, instances, proof })
+    }
+}
+
+fn take<const N: usize>(reader: &mut &[u8]) -> Result<[u8; N], EnvelopeError> {
+    if reader.len() < N {
+        return Err(EnvelopeError::Truncated);
+    }
+    let (head, rest) = reader.split_at(N);
+    *reader = rest;
+    Ok(head.try_into().unwrap())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+    format!("0x{hex}")
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    let hex = hex.strip_prefix("0x")?;
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::range_check::tests::circuit;
+    use crate::prover::setup_params;
+    use crate::test_utils::random_fr;
+    use halo2::plonk::keygen_vk;
+
+    fn envelope() -> ProofEnvelope {
+        ProofEnvelope {
+            fingerprint: Fingerprint([7; 32]),
+            transcript: TranscriptKind::Keccak256,
+            k: 12,
+            instances: vec![vec![random_fr(), random_fr()]],
+            proof: vec![1, 2, 3, 255],
+        }
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let envelope = envelope();
+        assert_eq!(ProofEnvelope::decode(&envelope.encode()).unwrap(), envelope);
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let envelope = envelope();
+        assert_eq!(ProofEnvelope::from_json(&envelope.to_json()).unwrap(), envelope);
+    }
+
+    #[test]
+    fn rejects_truncated_envelope() {
+        let bytes = envelope().encode();
+        assert!(matches!(ProofEnvelope::decode(&bytes[..bytes.len() - 1]), Err(EnvelopeError::Truncated)));
+        assert!(matches!(ProofEnvelope::decode(b"not a proof"), Err(EnvelopeError::BadMagic)));
+    }
+
+    #[test]
+    fn rejects_trailing_bytes() {
+        let mut bytes = envelope().encode();
+        bytes.extend([0, 0]);
+        assert!(matches!(ProofEnvelope::decode(&bytes), Err(EnvelopeError::TrailingBytes(2))));
+    }
+
+    #[test]
+    fn verify_checks_envelope_before_proof() {
+        let params = setup_params(9);
+        let vk = keygen_vk(&params, &circuit(0, 0, 0)).unwrap();
+        let fingerprint = Fingerprint::of(&vk);
+
+        let other_circuit = envelope();
+        assert!(matches!(
+            other_circuit.verify(&params, &vk),
+            Err(EnvelopeError::FingerprintMismatch { expected, found }) if expected == fingerprint && found == other_circuit.fingerprint
+        ));
+
+        let other_k = ProofEnvelope { fingerprint, ..envelope() };
+        assert!(matches!(other_k.verify(&params, &vk), Err(EnvelopeError::KMismatch { expected: 9, found: 12 })));
+
+        let poseidon = ProofEnvelope { fingerprint, transcript: TranscriptKind::Poseidon, k: 9, ..envelope() };
+        assert!(matches!(
+            poseidon.verify(&params, &vk),
+            Err(EnvelopeError::UnsupportedTranscript(TranscriptKind::Poseidon))
+        ));
+    }
+}
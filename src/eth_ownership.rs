@@ -0,0 +1,233 @@
+use halo2curves::bn256::Fr;
+use halo2curves::secp256k1::{Fq, Secp256k1Affine};
+use halo2curves::CurveAffine;
+use poseidon::{Pow5Chip, Pow5Config, P128Pow5T3};
+use halo2::plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance};
+use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+use maingate::{MainGateInstructions, RegionCtx};
+use crate::gadgets::bitwise::{bytes_to_bits, pack_bits, pack_bytes};
+use crate::gadgets::eth_address::{address_to_field, eth_address, EthAddressChip, EthAddressConfig, Secp256k1SignatureChip};
+use crate::gadgets::poseidon::PoseidonGadget;
+use crate::gadgets::signature::SignatureConfig;
+
+/// address를 public input으로 어떻게 노출할지
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressVisibility {
+    /// address 자체를 노출
+    Public,
+    /// Poseidon(address, blinding)만 노출
+    Hidden,
+}
+
+/// Ethereum address 소유 증명
+/// address 키로 message에 personal_sign(EIP-191) 서명했음을 보이고
+/// address(또는 그 commitment)와 message를 public input으로 노출
+/// message 해시는 회로 안에서 keccak으로 계산하므로 msg_hash를 신뢰할 필요가 없음
+#[derive(Clone, Debug)]
+pub struct EthOwnershipCircuit {
+    pub visibility: AddressVisibility,
+    pub pk: Secp256k1Affine,
+    pub sig_r: Fq,
+    pub sig_s: Fq,
+    /// 서명된 메시지 (challenge 등). 길이는 회로 모양을 결정함
+    pub message: Vec<u8>,
+    pub blinding: Fr,
+}
+
+#[derive(Clone, Debug)]
+pub struct EthOwnershipConfig {
+    pub eth_address: EthAddressConfig,
+    pub signature: SignatureConfig,
+    pub poseidon: Pow5Config<Fr, 3, 2>,
+    pub blinding: Column<Advice>,
+    pub instance: Column<Instance>,
+}
+
+impl EthOwnershipCircuit {
+    /// 노출되는 public input 값
+    pub fn instances(&self) -> Vec<Vec<Fr>> {
+        let address = address_to_field::<Fr>(&eth_address(&self.pk));
+        let public = match self.visibility {
+            AddressVisibility::Public => address,
+            AddressVisibility::Hidden => PoseidonGadget::hash_native([address, self.blinding]),
+        };
+        let mut instances = vec![public];
+        instances.extend(pack_bytes::<Fr>(&self.message));
+        vec![instances]
+    }
+}
+
+impl Circuit<Fr> for EthOwnershipCircuit {
+    type Config = EthOwnershipConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            visibility: self.visibility,
+            pk: Secp256k1Affine::generator(),
+            sig_r: Fq::zero(),
+            sig_s: Fq::zero(),
+            message: vec![0; self.message.len()],
+            blinding: Fr::zero(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+        let eth_address = EthAddressChip::configure(meta);
+        let signature = Secp256k1SignatureChip::<Fr>::configure(meta);
+
+        let state = [meta.advice_column(), meta.advice_column(), meta.advice_column()];
+        let partial_sbox = meta.advice_column();
+        let rc_a = [meta.fixed_column(), meta.fixed_column(), meta.fixed_column()];
+        let rc_b = [meta.fixed_column(), meta.fixed_column(), meta.fixed_column()];
+        meta.enable_constant(rc_b[0]);
+        let poseidon = Pow5Chip::<Fr, 3, 2>::configure::<P128Pow5T3>(
+            meta,
+            state,
+            partial_sbox,
+            rc_a,
+            rc_b,
+        );
+
+        let blinding = meta.advice_column();
+        meta.enable_equality(blinding);
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        EthOwnershipConfig { eth_address, signature, poseidon, blinding, instance }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fr>,
+    ) -> Result<(), Error> {
+        config.signature.config_range(&mut layouter)?;
+        let eth_address_chip = EthAddressChip::<Fr>::construct(config.eth_address.clone());
+
+        let (address, message) = layouter.assign_region(
+            || "eth address ownership",
+            |region| {
+                let mut ctx = RegionCtx::new(region, 0);
+                let signature_chip = Secp256k1SignatureChip::<Fr>::construct(&mut ctx, &config.signature)?;
+
+                let coordinates = self.pk.coordinates().unwrap();
+                let assigned_pk = signature_chip.assign_public_key(&mut ctx, (*coordinates.x(), *coordinates.y()))?;
+                let assigned_sig = signature_chip.assign_signature(&mut ctx, (self.sig_r, self.sig_s))?;
+
+                let bitwise = eth_address_chip.keccak().bitwise();
+                let message = self
+                    .message
+                    .iter()
+                    .map(|byte| bitwise.main_gate().assign_value(&mut ctx, Value::known(Fr::from(*byte as u64))))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let message_bits = bytes_to_bits(bitwise, &mut ctx, &message)?;
+                let digest = eth_address_chip.keccak().hash_eip191(&mut ctx, &message_bits)?;
+
+                let address = eth_address_chip.verify_ownership(
+                    &mut ctx,
+                    &signature_chip,
+                    &assigned_sig,
+                    &assigned_pk,
+                    &digest,
+                )?;
+                Ok((address, pack_bits(bitwise, &mut ctx, &message_bits)?))
+            },
+        )?;
+
+        let public = match self.visibility {
+            AddressVisibility::Public => address,
+            AddressVisibility::Hidden => {
+                let blinding = layouter.assign_region(
+                    || "blinding",
+                    |mut region| {
+                        region.assign_advice(|| "blinding", config.blinding, 0, || Value::known(self.blinding))
+                    },
+                )?;
+                let chip = Pow5Chip::<Fr, 3, 2>::construct(config.poseidon.clone());
+                PoseidonGadget::hash::<2>(&chip, layouter.namespace(|| "address commitment"), [address, blinding])?
+            }
+        };
+        layouter.constrain_instance(public.cell(), config.instance, 0)?;
+        for (i, chunk) in message.iter().enumerate() {
+            layouter.constrain_instance(chunk.cell(), config.instance, 1 + i)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::audit;
+    use crate::gadgets::keccak::eip191_prefix;
+    use crate::test_utils::{
+        assert_constraint_failure, assert_permutation_failure, ecdsa_keypair, ecdsa_sign, mock_prove, random_fr,
+    };
+    use halo2curves::ff::Field;
+    use sha3::{Digest, Keccak256};
+
+    const MESSAGE: &[u8] = b"sign in to example.org: nonce 42";
+
+    /// personal_sign digest를 big-endian 정수로 보고 secp256k1 scalar로 reduce
+    fn msg_hash(message: &[u8]) -> Fq {
+        let mut input = eip191_prefix(message.len());
+        input.extend_from_slice(message);
+        Keccak256::digest(&input)
+            .iter()
+            .fold(Fq::ZERO, |acc, byte| acc * Fq::from(256) + Fq::from(*byte as u64))
+    }
+
+    fn valid_circuit(visibility: AddressVisibility) -> EthOwnershipCircuit {
+        let (sk, pk) = ecdsa_keypair::<Secp256k1Affine>();
+        let (sig_r, sig_s) = ecdsa_sign::<Secp256k1Affine>(sk, msg_hash(MESSAGE));
+        EthOwnershipCircuit { visibility, pk, sig_r, sig_s, message: MESSAGE.to_vec(), blinding: random_fr() }
+    }
+
+    #[test]
+    fn accepts_valid_signature() {
+        for visibility in [AddressVisibility::Public, AddressVisibility::Hidden] {
+            let circuit = valid_circuit(visibility);
+            assert_eq!(mock_prove(&circuit, circuit.instances()), Ok(()));
+        }
+    }
+
+    #[test]
+    fn rejects_tampered_signature() {
+        let mut circuit = valid_circuit(AddressVisibility::Public);
+        let instances = circuit.instances();
+        circuit.sig_s += Fq::ONE;
+        assert_constraint_failure(mock_prove(&circuit, instances));
+    }
+
+    #[test]
+    fn rejects_signature_over_other_message() {
+        // 다른 메시지에 대한 서명은 public message와 맞지 않음
+        let (sk, pk) = ecdsa_keypair::<Secp256k1Affine>();
+        let (sig_r, sig_s) = ecdsa_sign::<Secp256k1Affine>(sk, msg_hash(b"sign in to example.org: nonce 43"));
+        let circuit = EthOwnershipCircuit { pk, sig_r, sig_s, ..valid_circuit(AddressVisibility::Public) };
+        assert_constraint_failure(mock_prove(&circuit, circuit.instances()));
+    }
+
+    #[test]
+    fn rejects_wrong_address() {
+        let circuit = valid_circuit(AddressVisibility::Public);
+        let mut instances = circuit.instances();
+        instances[0][0] += Fr::one();
+        assert_permutation_failure(mock_prove(&circuit, instances));
+
+        let circuit = valid_circuit(AddressVisibility::Hidden);
+        let mut instances = circuit.instances();
+        instances[0][0] = address_to_field(&eth_address(&circuit.pk));
+        assert_permutation_failure(mock_prove(&circuit, instances));
+    }
+
+    #[test]
+    fn is_clean_under_audit() {
+        for visibility in [AddressVisibility::Public, AddressVisibility::Hidden] {
+            let report = audit(&valid_circuit(visibility)).unwrap();
+            assert!(report.is_clean(), "{report}");
+        }
+    }
+}
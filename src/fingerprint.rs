@@ -0,0 +1,113 @@
+use std::fmt;
+
+use halo2::plonk::VerifyingKey;
+use halo2curves::bn256::G1Affine;
+use halo2curves::group::GroupEncoding;
+use sha3::{Digest, Keccak256};
+
+/// 회로 식별자: vk의 ConstraintSystem(gate, column, lookup, permutation)과
+/// fixed/permutation column commitment의 Keccak256
+/// gadget이나 fixed 값, copy 배치가 바뀌면 달라지므로 예전 vk와 맞는지 바로 알 수 있음
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fingerprint(pub [u8; 32]);
+
+impl Fingerprint {
+    pub fn of(vk: &VerifyingKey<G1Affine>) -> Self {
+        let mut hasher = Keccak256::new();
+        hasher.update(vk.get_domain().k().to_le_bytes());
+        hasher.update(format!("{:?}", vk.cs().pinned()).as_bytes());
+        for commitment in vk.fixed_commitments() {
+            hasher.update(commitment.to_bytes());
+        }
+        for commitment in vk.permutation().commitments() {
+            hasher.update(commitment.to_bytes());
+        }
+        Self(hasher.finalize().into())
+    }
+
+    /// Solidity verifier 소스에 fingerprint를 주석과 상수로 넣음
+    /// 상수는 첫 contract 선언 안에 `CIRCUIT_FINGERPRINT`로 들어감
+    pub fn embed_in_solidity(&self, source: &str) -> String {
+        let mut out = format!("// circuit fingerprint: {self}\n");
+        let mut embedded = false;
+        for line in source.lines() {
+            out.push_str(line);
+            out.push('\n');
+            if !embedded && line.trim_start().starts_with("contract ") && line.trim_end().ends_with('{') {
+                out.push_str(&format!("    bytes32 public constant CIRCUIT_FINGERPRINT = {self};\n"));
+                embedded = true;
+            }
+        }
+        out
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x")?;
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::range_check::tests::{circuit, RangeCheckCircuit};
+    use crate::gadgets::range_check::RangeCheckConfig;
+    use crate::prover::setup_params;
+    use halo2::circuit::{Layouter, SimpleFloorPlanner};
+    use halo2::plonk::{keygen_vk, Circuit, ConstraintSystem, Error};
+    use halo2curves::bn256::Fr;
+
+    /// range check와 같고 configure에서 advice column 하나만 더 만드는 회로
+    #[derive(Clone, Default)]
+    struct ExtraColumn(RangeCheckCircuit);
+
+    impl Circuit<Fr> for ExtraColumn {
+        type Config = RangeCheckConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            let config = RangeCheckCircuit::configure(meta);
+            meta.advice_column();
+            config
+        }
+
+        fn synthesize(&self, config: Self::Config, layouter: impl Layouter<Fr>) -> Result<(), Error> {
+            self.0.synthesize(config, layouter)
+        }
+    }
+
+    #[test]
+    fn stable_across_keygens() {
+        let params = setup_params(9);
+        let first = keygen_vk(&params, &circuit(0, 0, 0)).unwrap();
+        let second = keygen_vk(&params, &circuit(30, 18, 65)).unwrap();
+        assert_eq!(Fingerprint::of(&first), Fingerprint::of(&second));
+    }
+
+    #[test]
+    fn changes_with_configure() {
+        let params = setup_params(9);
+        let base = keygen_vk(&params, &circuit(0, 0, 0)).unwrap();
+        let extra = keygen_vk(&params, &ExtraColumn(circuit(0, 0, 0))).unwrap();
+        assert_ne!(Fingerprint::of(&base), Fingerprint::of(&extra));
+    }
+
+    #[test]
+    fn embeds_in_first_contract() {
+        let fingerprint = Fingerprint([0xab; 32]);
+        let source = "pragma solidity ^0.8.0;\n\ncontract Halo2Verifier {\n    function verify() public {}\n}\n\ncontract Other {\n}\n";
+        let embedded = fingerprint.embed_in_solidity(source);
+        let lines: Vec<&str> = embedded.lines().collect();
+        assert_eq!(lines[0], format!("// circuit fingerprint: 0x{}", "ab".repeat(32)));
+        assert_eq!(lines[3], "contract Halo2Verifier {");
+        assert_eq!(lines[4], format!("    bytes32 public constant CIRCUIT_FINGERPRINT = 0x{};", "ab".repeat(32)));
+        assert_eq!(embedded.matches("CIRCUIT_FINGERPRINT").count(), 1);
+        assert_eq!(embedded.lines().skip(1).filter(|line| !line.contains("CIRCUIT_FINGERPRINT")).collect::<Vec<_>>(), source.lines().collect::<Vec<_>>());
+    }
+}
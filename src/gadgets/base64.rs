@@ -0,0 +1,235 @@
+use halo2::plonk::Error;
+use halo2curves::ff::PrimeField;
+use maingate::{AssignedValue, MainGateInstructions, RegionCtx, Term};
+
+use crate::gadgets::bitwise::{Bit, BitwiseChip};
+use crate::gadgets::comparison::ComparisonChip;
+
+/// 바이트 비교에 쓰는 비트 수
+const BYTE_BITS: usize = 8;
+
+/// base64url (padding 없음, JWT 인코딩) 디코더
+/// 문자마다 sextet을 witness로 받고 인코딩 결과가 원래 문자와 같은지 검사
+pub struct Base64Chip<'a, F: PrimeField> {
+    comparison: &'a ComparisonChip<F>,
+    bitwise: BitwiseChip<F>,
+}
+
+impl<'a, F: PrimeField> Base64Chip<'a, F> {
+    pub fn new(comparison: &'a ComparisonChip<F>) -> Self {
+        Self {
+            comparison,
+            bitwise: BitwiseChip::new(comparison.main_gate().clone()),
+        }
+    }
+
+    /// 4의 배수 길이의 문자들 → 3/4 길이의 바이트
+    pub fn decode(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        chars: &[AssignedValue<F>],
+    ) -> Result<Vec<AssignedValue<F>>, Error> {
+        assert_eq!(chars.len() % 4, 0, "base64 input must be a multiple of 4 characters");
+        let mut bytes = Vec::with_capacity(chars.len() / 4 * 3);
+        for group in chars.chunks(4) {
+            // 24비트 값의 little-endian bit: 마지막 문자의 sextet이 가장 아래
+            let mut bits: Vec<Bit<F>> = Vec::with_capacity(24);
+            for c in group.iter().rev() {
+                bits.extend(self.decode_char(ctx, c)?);
+            }
+            for byte in bits.chunks(8).rev() {
+                bytes.push(self.bitwise.compose(ctx, byte)?);
+            }
+        }
+        Ok(bytes)
+    }
+
+    /// 문자 하나 → sextet의 little-endian 6비트
+    fn decode_char(&self, ctx: &mut RegionCtx<'_, F>, c: &AssignedValue<F>) -> Result<Vec<Bit<F>>, Error> {
+        let main_gate = self.comparison.main_gate();
+        let sextet = c.value().map(|c| F::from(decode_native(byte_of(c)).unwrap_or(0) as u64));
+        let sextet = main_gate.assign_value(ctx, sextet)?;
+        let bits = self.bitwise.to_bits(ctx, &sextet, 6)?;
+
+        let c26 = main_gate.assign_constant(ctx, F::from(26))?;
+        let c52 = main_gate.assign_constant(ctx, F::from(52))?;
+        let c62 = main_gate.assign_constant(ctx, F::from(62))?;
+        let lt26 = self.comparison.is_less_than(ctx, &sextet, &c26, BYTE_BITS)?;
+        let lt52 = self.comparison.is_less_than(ctx, &sextet, &c52, BYTE_BITS)?;
+        let lt62 = self.comparison.is_less_than(ctx, &sextet, &c62, BYTE_BITS)?;
+        let eq62 = main_gate.is_equal(ctx, &sextet, &c62)?;
+        // sextet < 64 이므로 나머지는 63
+        let eq63 = main_gate.compose(
+            ctx,
+            &[Term::Assigned(&lt62, -F::ONE), Term::Assigned(&eq62, -F::ONE)],
+            F::ONE,
+        )?;
+
+        // 'A' + v (v < 26), 'a' + v - 26 (v < 52), '0' + v - 52 (v < 62), '-' (62), '_' (63)
+        let v_lt62 = main_gate.mul(ctx, &sextet, &lt62)?;
+        let encoded = main_gate.compose(
+            ctx,
+            &[
+                Term::Assigned(&v_lt62, F::ONE),
+                Term::Assigned(&lt26, -F::from(6)),
+                Term::Assigned(&lt52, F::from(75)),
+                Term::Assigned(&lt62, -F::from(4)),
+                Term::Assigned(&eq62, F::from(b'-' as u64)),
+                Term::Assigned(&eq63, F::from(b'_' as u64)),
+            ],
+            F::ZERO,
+        )?;
+        main_gate.assert_equal(ctx, &encoded, c)?;
+        Ok(bits)
+    }
+}
+
+/// base64url 문자 → sextet
+pub fn decode_native(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'-' => Some(62),
+        b'_' => Some(63),
+        _ => None,
+    }
+}
+
+/// 바이트 셀 값 → u8 (witness 계산용)
+pub fn byte_of<F: PrimeField>(value: &F) -> u8 {
+    value.to_repr().as_ref()[0]
+}
+
+/// base64url 문자열 → 바이트 (padding 없음, 마지막 그룹은 2~3 문자 가능)
+pub fn decode_bytes_native(chars: &[u8]) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(chars.len() / 4 * 3 + 2);
+    for group in chars.chunks(4) {
+        if group.len() == 1 {
+            return None;
+        }
+        let mut acc = 0u32;
+        for c in group {
+            acc = (acc << 6) | decode_native(*c)? as u32;
+        }
+        acc <<= 6 * (4 - group.len());
+        bytes.extend_from_slice(&acc.to_be_bytes()[1..group.len()]);
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::gadgets::comparison::ComparisonConfig;
+    use crate::test_utils::{assert_constraint_failure, assert_permutation_failure, mock_prove};
+    use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::dev::VerifyFailure;
+    use halo2::plonk::{Circuit, Column, ConstraintSystem, Instance};
+    use halo2curves::bn256::Fr;
+
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+    /// base64url 인코딩 (padding 없음). JWT 테스트 입력용
+    pub(crate) fn encode_native(bytes: &[u8]) -> String {
+        let mut out = String::with_capacity((bytes.len() * 4 + 2) / 3);
+        for chunk in bytes.chunks(3) {
+            let acc = chunk.iter().enumerate().fold(0u32, |acc, (i, b)| acc | ((*b as u32) << (16 - 8 * i)));
+            for i in 0..=chunk.len() {
+                out.push(ALPHABET[(acc >> (18 - 6 * i)) as usize & 63] as char);
+            }
+        }
+        out
+    }
+
+    /// 문자들을 디코딩하고 바이트를 public input으로 노출
+    #[derive(Clone, Default)]
+    struct DecodeCircuit {
+        chars: Vec<u8>,
+    }
+
+    impl Circuit<Fr> for DecodeCircuit {
+        type Config = (ComparisonConfig, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self { chars: vec![b'A'; self.chars.len()] }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+            (ComparisonChip::configure(meta), instance)
+        }
+
+        fn synthesize(&self, (config, instance): Self::Config, mut layouter: impl Layouter<Fr>) -> Result<(), Error> {
+            let chip = ComparisonChip::construct(config);
+            chip.load_table(&mut layouter)?;
+            let bytes = layouter.assign_region(
+                || "base64",
+                |region| {
+                    let mut ctx = RegionCtx::new(region, 0);
+                    let chars = self
+                        .chars
+                        .iter()
+                        .map(|c| chip.main_gate().assign_value(&mut ctx, Value::known(Fr::from(*c as u64))))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Base64Chip::new(&chip).decode(&mut ctx, &chars)
+                },
+            )?;
+            for (i, byte) in bytes.iter().enumerate() {
+                layouter.constrain_instance(byte.cell(), instance, i)?;
+            }
+            Ok(())
+        }
+    }
+
+    fn decode(chars: &[u8], expected: &[u8]) -> Result<(), Vec<VerifyFailure>> {
+        let circuit = DecodeCircuit { chars: chars.to_vec() };
+        mock_prove(&circuit, vec![expected.iter().map(|b| Fr::from(*b as u64)).collect()])
+    }
+
+    #[test]
+    fn decodes_url_safe_alphabet() {
+        assert_eq!(decode(b"-_-_", &[0xfb, 0xff, 0xbf]), Ok(()));
+
+        let bytes = decode_bytes_native(ALPHABET).unwrap();
+        assert_eq!(encode_native(&bytes).as_bytes(), ALPHABET);
+        assert_eq!(decode(ALPHABET, &bytes), Ok(()));
+    }
+
+    #[test]
+    fn rejects_standard_alphabet_and_padding() {
+        // '+', '/', '='는 base64url 문자가 아님. sextet witness가 0('A')이 되어 인코딩 검사에서 걸림
+        for chars in [b"QUI=", b"+/+/", b"QU+A"] {
+            let substituted: Vec<u8> = chars.iter().map(|c| if decode_native(*c).is_some() { *c } else { b'A' }).collect();
+            let expected = decode_bytes_native(&substituted).unwrap();
+            assert_constraint_failure(decode(chars, &expected));
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_bytes() {
+        assert_eq!(decode(b"QUJD", b"ABC"), Ok(()));
+        assert_permutation_failure(decode(b"QUJD", b"ABD"));
+    }
+
+    #[test]
+    fn native_padding_edge_cases() {
+        assert_eq!(decode_bytes_native(b""), Some(vec![]));
+        assert_eq!(decode_bytes_native(b"QQ"), Some(b"A".to_vec()));
+        assert_eq!(decode_bytes_native(b"QUI"), Some(b"AB".to_vec()));
+        assert_eq!(decode_bytes_native(b"QUJD"), Some(b"ABC".to_vec()));
+        assert_eq!(decode_bytes_native(b"QUJDRA"), Some(b"ABCD".to_vec()));
+        // 남는 문자 하나로는 바이트를 만들 수 없음
+        assert_eq!(decode_bytes_native(b"Q"), None);
+        assert_eq!(decode_bytes_native(b"QUJDR"), None);
+        // padding 문자는 받지 않음
+        assert_eq!(decode_bytes_native(b"QQ=="), None);
+
+        for len in 0..8 {
+            let bytes: Vec<u8> = (0..len).map(|i| 0xf0 | i as u8).collect();
+            assert_eq!(decode_bytes_native(encode_native(&bytes).as_bytes()), Some(bytes));
+        }
+    }
+}
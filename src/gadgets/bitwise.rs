@@ -0,0 +1,291 @@
+use halo2::circuit::Value;
+use halo2::plonk::Error;
+use halo2curves::ff::PrimeField;
+use maingate::{
+    AssignedCondition, AssignedValue, MainGate, MainGateInstructions, RegionCtx, Term,
+};
+
+/// 해시 gadget(keccak 등)에서 쓰는 bit. 상수 bit은 셀을 만들지 않고 접어서(fold) 처리
+#[derive(Clone, Debug)]
+pub enum Bit<F: PrimeField> {
+    Constant(bool),
+    Assigned(AssignedCondition<F>),
+}
+
+impl<F: PrimeField> Bit<F> {
+    pub fn value(&self) -> Value<bool> {
+        match self {
+            Bit::Constant(b) => Value::known(*b),
+            Bit::Assigned(cell) => cell.value().map(|v| *v == F::ONE),
+        }
+    }
+}
+
+/// MainGate 위의 bit 연산. 입력 셀은 모두 boolean으로 제약되어 있다고 가정
+#[derive(Clone, Debug)]
+pub struct BitwiseChip<F: PrimeField> {
+    main_gate: MainGate<F>,
+}
+
+impl<F: PrimeField> BitwiseChip<F> {
+    pub fn new(main_gate: MainGate<F>) -> Self {
+        Self { main_gate }
+    }
+
+    pub fn main_gate(&self) -> &MainGate<F> {
+        &self.main_gate
+    }
+
+    pub fn not(&self, ctx: &mut RegionCtx<'_, F>, a: &Bit<F>) -> Result<Bit<F>, Error> {
+        Ok(match a {
+            Bit::Constant(a) => Bit::Constant(!a),
+            Bit::Assigned(a) => Bit::Assigned(self.main_gate.not(ctx, a)?),
+        })
+    }
+
+    /// a ^ b = a + b - 2ab
+    pub fn xor(&self, ctx: &mut RegionCtx<'_, F>, a: &Bit<F>, b: &Bit<F>) -> Result<Bit<F>, Error> {
+        Ok(match (a, b) {
+            (Bit::Constant(a), Bit::Constant(b)) => Bit::Constant(a ^ b),
+            (Bit::Constant(false), x) | (x, Bit::Constant(false)) => x.clone(),
+            (Bit::Constant(true), x) | (x, Bit::Constant(true)) => self.not(ctx, x)?,
+            (Bit::Assigned(a), Bit::Assigned(b)) => {
+                let ab = self.main_gate.mul(ctx, a, b)?;
+                Bit::Assigned(self.main_gate.compose(
+                    ctx,
+                    &[
+                        Term::Assigned(a, F::ONE),
+                        Term::Assigned(b, F::ONE),
+                        Term::Assigned(&ab, -F::from(2)),
+                    ],
+                    F::ZERO,
+                )?)
+            }
+        })
+    }
+
+    pub fn and(&self, ctx: &mut RegionCtx<'_, F>, a: &Bit<F>, b: &Bit<F>) -> Result<Bit<F>, Error> {
+        Ok(match (a, b) {
+            (Bit::Constant(a), Bit::Constant(b)) => Bit::Constant(a & b),
+            (Bit::Constant(false), _) | (_, Bit::Constant(false)) => Bit::Constant(false),
+            (Bit::Constant(true), x) | (x, Bit::Constant(true)) => x.clone(),
+            (Bit::Assigned(a), Bit::Assigned(b)) => Bit::Assigned(self.main_gate.mul(ctx, a, b)?),
+        })
+    }
+
+    /// !a & b = b - ab
+    pub fn and_not(&self, ctx: &mut RegionCtx<'_, F>, a: &Bit<F>, b: &Bit<F>) -> Result<Bit<F>, Error> {
+        Ok(match (a, b) {
+            (Bit::Constant(a), b) => {
+                if *a {
+                    Bit::Constant(false)
+                } else {
+                    b.clone()
+                }
+            }
+            (a, Bit::Constant(b)) => {
+                if *b {
+                    self.not(ctx, a)?
+                } else {
+                    Bit::Constant(false)
+                }
+            }
+            (Bit::Assigned(a), Bit::Assigned(b)) => {
+                let ab = self.main_gate.mul(ctx, a, b)?;
+                Bit::Assigned(self.main_gate.compose(
+                    ctx,
+                    &[Term::Assigned(b, F::ONE), Term::Assigned(&ab, -F::ONE)],
+                    F::ZERO,
+                )?)
+            }
+        })
+    }
+
+    /// 상수 bit도 셀로 만들어서 돌려줌
+    pub fn assign(&self, ctx: &mut RegionCtx<'_, F>, a: &Bit<F>) -> Result<AssignedCondition<F>, Error> {
+        match a {
+            Bit::Constant(b) => self.main_gate.assign_constant(ctx, if *b { F::ONE } else { F::ZERO }),
+            Bit::Assigned(cell) => Ok(cell.clone()),
+        }
+    }
+
+    /// value를 little-endian `number_of_bits` 비트로 분해 (각 bit은 boolean 제약)
+    pub fn to_bits(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        value: &AssignedValue<F>,
+        number_of_bits: usize,
+    ) -> Result<Vec<Bit<F>>, Error> {
+        Ok(self
+            .main_gate
+            .to_bits(ctx, value, number_of_bits)?
+            .into_iter()
+            .map(Bit::Assigned)
+            .collect())
+    }
+
+    /// little-endian bit들을 하나의 값으로 합침
+    pub fn compose(&self, ctx: &mut RegionCtx<'_, F>, bits: &[Bit<F>]) -> Result<AssignedValue<F>, Error> {
+        let mut constant = F::ZERO;
+        let mut base = F::ONE;
+        let mut assigned = vec![];
+        for bit in bits {
+            match bit {
+                Bit::Constant(true) => constant += base,
+                Bit::Constant(false) => {}
+                Bit::Assigned(cell) => assigned.push((cell, base)),
+            }
+            base = base.double();
+        }
+        if assigned.is_empty() {
+            return self.main_gate.assign_constant(ctx, constant);
+        }
+        let terms: Vec<Term<F>> = assigned.into_iter().map(|(cell, base)| Term::Assigned(cell, base)).collect();
+        self.main_gate.compose(ctx, &terms, constant)
+    }
+}
+
+/// 바이트 순서대로 나열된 값들을 bit stream(각 바이트 LSB 먼저)으로 변환
+pub fn bytes_to_bits<F: PrimeField>(
+    chip: &BitwiseChip<F>,
+    ctx: &mut RegionCtx<'_, F>,
+    bytes: &[AssignedValue<F>],
+) -> Result<Vec<Bit<F>>, Error> {
+    let mut bits = Vec::with_capacity(bytes.len() * 8);
+    for byte in bytes {
+        bits.extend(chip.to_bits(ctx, byte, 8)?);
+    }
+    Ok(bits)
+}
+
+/// public input으로 노출할 때 한 field element에 담는 바이트 수
+pub const PACK_BYTES: usize = 31;
+
+/// bit stream을 PACK_BYTES 바이트씩 little-endian 정수로 묶음
+pub fn pack_bits<F: PrimeField>(
+    chip: &BitwiseChip<F>,
+    ctx: &mut RegionCtx<'_, F>,
+    bits: &[Bit<F>],
+) -> Result<Vec<AssignedValue<F>>, Error> {
+    bits.chunks(PACK_BYTES * 8).map(|chunk| chip.compose(ctx, chunk)).collect()
+}
+
+/// `pack_bits`의 native 버전
+pub fn pack_bytes<F: PrimeField>(bytes: &[u8]) -> Vec<F> {
+    bytes
+        .chunks(PACK_BYTES)
+        .map(|chunk| chunk.iter().rev().fold(F::ZERO, |acc, byte| acc * F::from(256) + F::from(*byte as u64)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_permutation_failure, mock_prove};
+    use halo2::circuit::{Layouter, SimpleFloorPlanner};
+    use halo2::plonk::{Circuit, Column, ConstraintSystem, Instance};
+    use halo2curves::bn256::Fr;
+    use maingate::MainGateConfig;
+
+    /// (a가 상수인지, a, b가 상수인지, b) 모든 조합
+    fn operands() -> Vec<(bool, bool, bool, bool)> {
+        (0..16).map(|i| (i & 1 == 1, i & 2 == 2, i & 4 == 4, i & 8 == 8)).collect()
+    }
+
+    /// 조합마다 [xor, and, and_not, not a]
+    fn expected_bits() -> Vec<bool> {
+        operands().into_iter().flat_map(|(_, a, _, b)| [a ^ b, a & b, !a & b, !a]).collect()
+    }
+
+    /// 연산 결과 bit들을 합성해 public input 0에, bytes의 pack_bits를 그 뒤에 노출
+    #[derive(Clone, Default)]
+    struct BitwiseCircuit {
+        bytes: Vec<u8>,
+    }
+
+    impl Circuit<Fr> for BitwiseCircuit {
+        type Config = (MainGateConfig, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self { bytes: vec![0; self.bytes.len()] }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+            (MainGate::configure(meta), instance)
+        }
+
+        fn synthesize(&self, (config, instance): Self::Config, mut layouter: impl Layouter<Fr>) -> Result<(), Error> {
+            let chip = BitwiseChip::new(MainGate::new(config));
+            let outputs = layouter.assign_region(
+                || "bitwise",
+                |region| {
+                    let mut ctx = RegionCtx::new(region, 0);
+                    let bit = |ctx: &mut RegionCtx<'_, Fr>, constant: bool, value: bool| -> Result<Bit<Fr>, Error> {
+                        Ok(if constant {
+                            Bit::Constant(value)
+                        } else {
+                            Bit::Assigned(chip.main_gate().assign_bit(ctx, Value::known(Fr::from(value as u64)))?)
+                        })
+                    };
+                    let mut results = vec![];
+                    for (a_constant, a, b_constant, b) in operands() {
+                        let a = bit(&mut ctx, a_constant, a)?;
+                        let b = bit(&mut ctx, b_constant, b)?;
+                        results.push(chip.xor(&mut ctx, &a, &b)?);
+                        results.push(chip.and(&mut ctx, &a, &b)?);
+                        results.push(chip.and_not(&mut ctx, &a, &b)?);
+                        results.push(chip.not(&mut ctx, &a)?);
+                    }
+                    let mut outputs = vec![chip.compose(&mut ctx, &results)?];
+
+                    let bytes = self
+                        .bytes
+                        .iter()
+                        .map(|b| chip.main_gate().assign_value(&mut ctx, Value::known(Fr::from(*b as u64))))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let bits = bytes_to_bits(&chip, &mut ctx, &bytes)?;
+                    outputs.extend(pack_bits(&chip, &mut ctx, &bits)?);
+                    Ok(outputs)
+                },
+            )?;
+            for (i, cell) in outputs.iter().enumerate() {
+                layouter.constrain_instance(cell.cell(), instance, i)?;
+            }
+            Ok(())
+        }
+    }
+
+    fn instances(bytes: &[u8]) -> Vec<Vec<Fr>> {
+        let results = expected_bits().iter().rev().fold(0u128, |acc, bit| (acc << 1) | *bit as u128);
+        let mut row = vec![Fr::from_u128(results)];
+        row.extend(pack_bytes::<Fr>(bytes));
+        vec![row]
+    }
+
+    #[test]
+    fn matches_native_operations() {
+        let bytes: Vec<u8> = (0..40).map(|i| i * 7 + 3).collect();
+        let circuit = BitwiseCircuit { bytes: bytes.clone() };
+        assert_eq!(mock_prove(&circuit, instances(&bytes)), Ok(()));
+    }
+
+    #[test]
+    fn rejects_wrong_packing() {
+        let bytes: Vec<u8> = (0..40).collect();
+        let circuit = BitwiseCircuit { bytes: bytes.clone() };
+        let mut instances = instances(&bytes);
+        // 바이트 순서가 뒤집힌 packing
+        instances[0][1] = pack_bytes::<Fr>(&bytes[..PACK_BYTES].iter().rev().copied().collect::<Vec<_>>())[0];
+        assert_permutation_failure(mock_prove(&circuit, instances));
+    }
+
+    #[test]
+    fn packs_little_endian_chunks() {
+        let packed = pack_bytes::<Fr>(&[1, 2]);
+        assert_eq!(packed, vec![Fr::from(0x0201)]);
+        assert_eq!(pack_bytes::<Fr>(&[0xff; PACK_BYTES + 1]).len(), 2);
+    }
+}
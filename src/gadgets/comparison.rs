@@ -0,0 +1,208 @@
+use std::cmp::Ordering;
+
+use halo2::circuit::{Layouter, Value};
+use halo2::plonk::{ConstraintSystem, Error};
+use halo2curves::ff::PrimeField;
+use maingate::{
+    AssignedCondition, AssignedValue, MainGate, MainGateConfig, MainGateInstructions, RangeChip,
+    RangeConfig, RangeInstructions, RegionCtx,
+};
+
+/// 비교 대상 값의 기본 비트 수 (attribute 값은 u64 범위로 가정)
+pub const COMPARISON_BITS: usize = 64;
+/// RangeChip lookup 테이블의 limb 비트 수. 비교 비트 수는 이 값의 배수여야 함
+pub const LIMB_BITS: usize = 8;
+
+#[derive(Clone, Debug)]
+pub struct ComparisonConfig {
+    pub main_gate: MainGateConfig,
+    pub range: RangeConfig,
+}
+
+/// 비교/집합 소속 결과를 boolean 셀(AssignedCondition)로 돌려주는 chip
+/// RangeCheckChip과 달리 조건을 assert하지 않고 값으로 만들어서 AND/OR/NOT 조합이 가능
+pub struct ComparisonChip<F: PrimeField> {
+    main_gate: MainGate<F>,
+    range: RangeChip<F>,
+}
+
+impl<F: PrimeField> ComparisonChip<F> {
+    pub fn construct(config: ComparisonConfig) -> Self {
+        Self {
+            main_gate: MainGate::new(config.main_gate),
+            range: RangeChip::new(config.range),
+        }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> ComparisonConfig {
+        let main_gate = MainGate::configure(meta);
+        let range = RangeChip::configure(meta, &main_gate, vec![LIMB_BITS], vec![]);
+        ComparisonConfig { main_gate, range }
+    }
+
+    /// range lookup 테이블 로드. synthesize에서 한 번 호출해야 함
+    pub fn load_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.range.load_table(layouter)
+    }
+
+    pub fn main_gate(&self) -> &MainGate<F> {
+        &self.main_gate
+    }
+
+    /// value를 `bits` 비트 이내로 제한해서 할당
+    pub fn assign_bounded(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        value: Value<F>,
+        bits: usize,
+    ) -> Result<AssignedValue<F>, Error> {
+        assert_eq!(bits % LIMB_BITS, 0, "bits must be a multiple of LIMB_BITS");
+        self.range.assign(ctx, value, LIMB_BITS, bits)
+    }
+
+    /// a >= b 이면 1. a, b 모두 `bits` 비트 이내여야 함
+    pub fn is_greater_or_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        b: &AssignedValue<F>,
+        bits: usize,
+    ) -> Result<AssignedCondition<F>, Error> {
+        let main_gate = &self.main_gate;
+        let ge = a
+            .value()
+            .zip(b.value())
+            .map(|(a, b)| if cmp_native(a, b) != Ordering::Less { F::ONE } else { F::ZERO });
+        let ge = main_gate.assign_bit(ctx, ge)?;
+
+        // ge = 1 → d = a - b, ge = 0 → d = b - a - 1
+        // 둘 중 하나만 bits 비트 안에 들어가므로 d의 range check가 ge를 강제함
+        let pos = main_gate.sub(ctx, a, b)?;
+        let neg = main_gate.neg_with_constant(ctx, &pos, -F::ONE)?;
+        let diff = main_gate.select(ctx, &pos, &neg, &ge)?;
+        let bounded = self.assign_bounded(ctx, diff.value().copied(), bits)?;
+        main_gate.assert_equal(ctx, &diff, &bounded)?;
+
+        Ok(ge)
+    }
+
+    /// a <= b 이면 1
+    pub fn is_less_or_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        b: &AssignedValue<F>,
+        bits: usize,
+    ) -> Result<AssignedCondition<F>, Error> {
+        self.is_greater_or_equal(ctx, b, a, bits)
+    }
+
+    /// a < b 이면 1
+    pub fn is_less_than(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        b: &AssignedValue<F>,
+        bits: usize,
+    ) -> Result<AssignedCondition<F>, Error> {
+        let ge = self.is_greater_or_equal(ctx, a, b, bits)?;
+        self.main_gate.not(ctx, &ge)
+    }
+
+    /// a가 set 안에 있으면 1: prod(a - c_i) == 0
+    pub fn is_in_set(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        set: &[F],
+    ) -> Result<AssignedCondition<F>, Error> {
+        let main_gate = &self.main_gate;
+        let Some((first, rest)) = set.split_first() else {
+            return main_gate.assign_constant(ctx, F::ZERO);
+        };
+        let mut prod = main_gate.sub_with_constant(ctx, a, *first)?;
+        for c in rest {
+            let term = main_gate.sub_with_constant(ctx, a, *c)?;
+            prod = main_gate.mul(ctx, &prod, &term)?;
+        }
+        main_gate.is_zero(ctx, &prod)
+    }
+}
+
+/// little-endian repr을 정수로 보고 비교
+pub fn cmp_native<F: PrimeField>(a: &F, b: &F) -> Ordering {
+    let (a, b) = (a.to_repr(), b.to_repr());
+    a.as_ref().iter().rev().cmp(b.as_ref().iter().rev())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_constraint_failure, assert_permutation_failure, mock_prove};
+    use halo2::circuit::SimpleFloorPlanner;
+    use halo2::plonk::{Circuit, Column, Instance};
+    use halo2curves::bn256::Fr;
+
+    /// is_greater_or_equal(a, b) 결과를 public input 0에 노출
+    #[derive(Clone, Default)]
+    struct GreaterOrEqualCircuit {
+        a: Fr,
+        b: Fr,
+    }
+
+    impl Circuit<Fr> for GreaterOrEqualCircuit {
+        type Config = (ComparisonConfig, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+            (ComparisonChip::configure(meta), instance)
+        }
+
+        fn synthesize(&self, (config, instance): Self::Config, mut layouter: impl Layouter<Fr>) -> Result<(), Error> {
+            let chip = ComparisonChip::construct(config);
+            chip.load_table(&mut layouter)?;
+            let ge = layouter.assign_region(
+                || "ge",
+                |region| {
+                    let mut ctx = RegionCtx::new(region, 0);
+                    let a = chip.main_gate().assign_value(&mut ctx, Value::known(self.a))?;
+                    let b = chip.main_gate().assign_value(&mut ctx, Value::known(self.b))?;
+                    chip.is_greater_or_equal(&mut ctx, &a, &b, COMPARISON_BITS)
+                },
+            )?;
+            layouter.constrain_instance(ge.cell(), instance, 0)
+        }
+    }
+
+    fn ge(a: u64, b: u64) -> Result<(), Vec<halo2::dev::VerifyFailure>> {
+        let circuit = GreaterOrEqualCircuit { a: Fr::from(a), b: Fr::from(b) };
+        mock_prove(&circuit, vec![vec![Fr::from((a >= b) as u64)]])
+    }
+
+    #[test]
+    fn compares_u64_values() {
+        for (a, b) in [(0, 0), (7, 7), (8, 7), (7, 8), (u64::MAX, u64::MAX), (u64::MAX, 0), (0, u64::MAX), (u64::MAX, u64::MAX - 1)] {
+            assert_eq!(ge(a, b), Ok(()), "{a} >= {b}");
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_result() {
+        // 같은 값이면 1이어야 함
+        let circuit = GreaterOrEqualCircuit { a: Fr::from(u64::MAX), b: Fr::from(u64::MAX) };
+        assert_permutation_failure(mock_prove(&circuit, vec![vec![Fr::zero()]]));
+    }
+
+    #[test]
+    fn rejects_operand_out_of_range() {
+        // 2^64은 COMPARISON_BITS를 넘으므로 어느 쪽 차이도 limb 합성과 맞지 않음
+        let circuit = GreaterOrEqualCircuit { a: Fr::from_u128(1 << 64), b: Fr::zero() };
+        assert_constraint_failure(mock_prove(&circuit, vec![vec![Fr::one()]]));
+    }
+}
@@ -0,0 +1,149 @@
+use halo2::plonk::{ConstraintSystem, Error};
+use halo2curves::ff::PrimeField;
+use halo2curves::secp256k1::{Fp, Fq, Secp256k1Affine};
+use halo2curves::CurveAffine;
+use ecdsa::ecdsa::{AssignedEcdsaSig, AssignedPublicKey};
+use integer::AssignedInteger;
+use maingate::{AssignedValue, RegionCtx};
+use sha3::{Digest, Keccak256};
+
+use crate::gadgets::bitwise::Bit;
+use crate::gadgets::keccak::{KeccakChip, KeccakConfig};
+use crate::gadgets::signature::SignatureChip;
+
+pub const LIMBS: usize = 4;
+pub const BITS: usize = 68;
+/// secp256k1 좌표 비트 수
+const COORDINATE_BITS: usize = 256;
+
+/// secp256k1 ECDSA (Ethereum 계정 키)
+pub type Secp256k1SignatureChip<F> = SignatureChip<Secp256k1Affine, F, LIMBS, BITS>;
+
+#[derive(Clone, Debug)]
+pub struct EthAddressConfig {
+    pub keccak: KeccakConfig,
+}
+
+/// 서명 검증 + 공개키로부터 Ethereum address 유도
+/// address = keccak256(x || y)[12..32], x, y는 32바이트 big-endian
+pub struct EthAddressChip<F: PrimeField> {
+    keccak: KeccakChip<F>,
+}
+
+impl<F: PrimeField> EthAddressChip<F> {
+    pub fn construct(config: EthAddressConfig) -> Self {
+        Self { keccak: KeccakChip::construct(config.keccak) }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> EthAddressConfig {
+        EthAddressConfig { keccak: KeccakChip::configure(meta) }
+    }
+
+    pub fn keccak(&self) -> &KeccakChip<F> {
+        &self.keccak
+    }
+
+    /// address 키로 keccak digest에 대한 서명을 검증하고 address(160비트 정수) 셀을 돌려줌
+    /// msg_hash는 digest bit에서 직접 만들므로 서명 대상이 회로 밖의 값일 수 없음
+    pub fn verify_ownership(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        signature_chip: &Secp256k1SignatureChip<F>,
+        sig: &AssignedEcdsaSig<Fq, F, LIMBS, BITS>,
+        pk: &AssignedPublicKey<Fp, F, LIMBS, BITS>,
+        digest: &[Bit<F>],
+    ) -> Result<AssignedValue<F>, Error> {
+        let msg_hash = signature_chip.digest_to_msg_hash(ctx, self.keccak.bitwise(), digest)?;
+        signature_chip.verify(ctx, sig, pk, &msg_hash)?;
+        self.derive_address(ctx, pk)
+    }
+
+    /// 비압축 공개키 (x, y) → address
+    pub fn derive_address(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        pk: &AssignedPublicKey<Fp, F, LIMBS, BITS>,
+    ) -> Result<AssignedValue<F>, Error> {
+        let mut input = Vec::with_capacity(2 * COORDINATE_BITS);
+        for coordinate in [pk.point.x(), pk.point.y()] {
+            let bits = self.coordinate_bits(ctx, coordinate)?;
+            // big-endian 바이트 순서, 바이트 안에서는 LSB 먼저
+            for byte in (0..COORDINATE_BITS / 8).rev() {
+                input.extend_from_slice(&bits[byte * 8..byte * 8 + 8]);
+            }
+        }
+        let digest = self.keccak.hash_bits(ctx, &input)?;
+
+        // digest의 마지막 20바이트를 big-endian 정수로
+        let mut address = Vec::with_capacity(160);
+        for byte in (12..32).rev() {
+            address.extend_from_slice(&digest[byte * 8..byte * 8 + 8]);
+        }
+        self.keccak.bitwise().compose(ctx, &address)
+    }
+
+    /// RNS limb → little-endian 256 bit
+    /// assign_public_key가 Range::Remainder로 할당하므로 마지막 limb은 52비트 이내
+    fn coordinate_bits(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        coordinate: &AssignedInteger<Fp, F, LIMBS, BITS>,
+    ) -> Result<Vec<Bit<F>>, Error> {
+        let mut bits = Vec::with_capacity(COORDINATE_BITS);
+        for i in 0..LIMBS {
+            let number_of_bits = BITS.min(COORDINATE_BITS - i * BITS);
+            bits.extend(self.keccak.bitwise().to_bits(ctx, &coordinate.limb(i), number_of_bits)?);
+        }
+        Ok(bits)
+    }
+}
+
+/// native address 계산
+pub fn eth_address(pk: &Secp256k1Affine) -> [u8; 20] {
+    let coordinates = pk.coordinates().unwrap();
+    let mut encoded = Vec::with_capacity(64);
+    for coordinate in [coordinates.x(), coordinates.y()] {
+        let mut bytes = coordinate.to_repr();
+        bytes.reverse();
+        encoded.extend_from_slice(bytes.as_ref());
+    }
+    let digest = Keccak256::digest(&encoded);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&digest[12..]);
+    address
+}
+
+/// address 바이트 → 회로에서 쓰는 field element (big-endian 정수)
+pub fn address_to_field<F: PrimeField>(address: &[u8; 20]) -> F {
+    address
+        .iter()
+        .fold(F::ZERO, |acc, byte| acc * F::from(256) + F::from(*byte as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2curves::group::Curve;
+
+    fn address_of(sk: u64) -> [u8; 20] {
+        eth_address(&(Secp256k1Affine::generator() * Fq::from(sk)).to_affine())
+    }
+
+    fn hex(address: &[u8; 20]) -> String {
+        address.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn derives_known_addresses() {
+        assert_eq!(hex(&address_of(1)), "7e5f4552091a69125d5dfcb7b8c2659029395bdf");
+        assert_eq!(hex(&address_of(2)), "2b5ad5c4795c026514f8317c7a215e218dccd6cf");
+    }
+
+    #[test]
+    fn address_field_is_big_endian() {
+        let mut address = [0u8; 20];
+        address[19] = 1;
+        address[18] = 2;
+        assert_eq!(address_to_field::<halo2curves::bn256::Fr>(&address), halo2curves::bn256::Fr::from(0x0201));
+    }
+}
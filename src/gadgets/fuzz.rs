@@ -0,0 +1,88 @@
+//! gadget 건전성 fuzzing
+//! 임의의 witness로 MockProver 결과가 native 구현과 같은지 보고,
+//! advice 셀 하나만 바꿔도 증명이 깨지는지(제약되지 않은 셀이 없는지) 확인
+
+use halo2curves::bn256::Fr;
+use proptest::prelude::*;
+
+use crate::gadgets::merkle::tests::{MerkleCircuit, DEPTH};
+use crate::gadgets::merkle::MerkleGadget;
+use crate::gadgets::poseidon::tests::HashChainCircuit;
+use crate::gadgets::poseidon::PoseidonGadget;
+use crate::gadgets::range_check::tests::circuit as range_circuit;
+use crate::test_utils::{find_unconstrained_cells, index_bits, merkle_proof, mock_prove, random_fr};
+
+/// MockProver가 느려서 case 수를 줄임
+const CASES: u32 = 16;
+
+fn fr() -> impl Strategy<Value = Fr> {
+    any::<[u64; 4]>().prop_map(Fr::from_raw)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(CASES))]
+
+    #[test]
+    fn range_check_matches_native(value in any::<u64>(), min in any::<u64>(), max in any::<u64>()) {
+        let accepted = mock_prove(&range_circuit(value, min, max), vec![]).is_ok();
+        prop_assert_eq!(accepted, min <= value && value <= max);
+    }
+
+    #[test]
+    fn range_check_accepts_bounds(a in any::<u64>(), b in any::<u64>(), c in any::<u64>()) {
+        // 정렬해서 항상 min <= value <= max 인 경우만 생성
+        let mut sorted = [a, b, c];
+        sorted.sort_unstable();
+        let [min, value, max] = sorted;
+        prop_assert_eq!(mock_prove(&range_circuit(value, min, max), vec![]), Ok(()));
+        prop_assert_eq!(mock_prove(&range_circuit(min, min, max), vec![]), Ok(()));
+        prop_assert_eq!(mock_prove(&range_circuit(max, min, max), vec![]), Ok(()));
+    }
+
+    #[test]
+    fn merkle_root_matches_native(leaf in fr(), path in prop::collection::vec(fr(), DEPTH), leaf_index in 0..1usize << DEPTH) {
+        let indices = index_bits(leaf_index, DEPTH);
+        let root = MerkleGadget::compute_root_native(leaf, &path, &indices);
+        let circuit = MerkleCircuit { leaf, path, indices };
+        prop_assert_eq!(mock_prove(&circuit, vec![vec![root]]), Ok(()));
+        prop_assert!(mock_prove(&circuit, vec![vec![root + Fr::one()]]).is_err());
+    }
+
+    #[test]
+    fn merkle_rejects_tampered_sibling(leaf in fr(), leaf_index in 0..1usize << DEPTH, level in 0..DEPTH) {
+        let (mut path, root) = merkle_proof(leaf, DEPTH, leaf_index);
+        path[level] += Fr::one();
+        let circuit = MerkleCircuit { leaf, path, indices: index_bits(leaf_index, DEPTH) };
+        prop_assert!(mock_prove(&circuit, vec![vec![root]]).is_err());
+    }
+
+    #[test]
+    fn hash_chain_matches_native(inputs in prop::collection::vec(fr(), 1..4)) {
+        let digest = PoseidonGadget::hash_chain_native(&inputs);
+        let circuit = HashChainCircuit { inputs };
+        prop_assert_eq!(mock_prove(&circuit, vec![vec![digest]]), Ok(()));
+        prop_assert!(mock_prove(&circuit, vec![vec![digest + Fr::one()]]).is_err());
+    }
+}
+
+#[test]
+fn range_check_has_no_unconstrained_cells() {
+    assert_eq!(find_unconstrained_cells(&range_circuit(30, 18, 65), vec![]), Vec::<usize>::new());
+}
+
+#[test]
+fn merkle_has_no_unconstrained_cells() {
+    // 셀마다 MockProver를 돌리므로 얕은 트리로 충분
+    let leaf = random_fr();
+    let (path, root) = merkle_proof(leaf, 2, 1);
+    let circuit = MerkleCircuit { leaf, path, indices: index_bits(1, 2) };
+    assert_eq!(find_unconstrained_cells(&circuit, vec![vec![root]]), Vec::<usize>::new());
+}
+
+#[test]
+fn poseidon_has_no_unconstrained_cells() {
+    let inputs = vec![random_fr()];
+    let digest = PoseidonGadget::hash_chain_native(&inputs);
+    let circuit = HashChainCircuit { inputs };
+    assert_eq!(find_unconstrained_cells(&circuit, vec![vec![digest]]), Vec::<usize>::new());
+}
@@ -0,0 +1,450 @@
+use halo2::plonk::{ConstraintSystem, Error};
+use halo2curves::ff::PrimeField;
+use maingate::{AssignedValue, MainGate, MainGateConfig, MainGateInstructions, RegionCtx, Term};
+
+use crate::gadgets::bitwise::{Bit, BitwiseChip};
+
+/// keccak-f[1600] 라운드 수
+const ROUNDS: usize = 24;
+/// Keccak-256 rate (bit 단위)
+pub const RATE_BITS: usize = 1088;
+/// digest 길이 (bit 단위)
+pub const DIGEST_BITS: usize = 256;
+/// rate (바이트 단위)
+pub const RATE_BYTES: usize = RATE_BITS / 8;
+/// EIP-191 personal_sign prefix (뒤에 메시지 길이의 10진 문자열이 붙음)
+pub const EIP191_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n";
+
+const ROUND_CONSTANTS: [u64; ROUNDS] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808A, 0x8000000080008000,
+    0x000000000000808B, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008A, 0x0000000000000088, 0x0000000080008009, 0x000000008000000A,
+    0x000000008000808B, 0x800000000000008B, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800A, 0x800000008000000A,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+/// rho 단계 회전량 [x][y]
+const ROTATIONS: [[usize; 5]; 5] = [
+    [0, 36, 3, 41, 18],
+    [1, 44, 10, 45, 2],
+    [62, 6, 43, 15, 61],
+    [28, 55, 25, 21, 56],
+    [27, 20, 39, 8, 14],
+];
+
+type Lane<F> = Vec<Bit<F>>;
+type State<F> = [[Lane<F>; 5]; 5];
+
+#[derive(Clone, Debug)]
+pub struct KeccakConfig {
+    pub main_gate: MainGateConfig,
+}
+
+/// bit 단위 Keccak-256 (Ethereum keccak, padding 0x01 ... 0x80)
+/// 입출력은 바이트 순서의 bit stream (각 바이트 LSB 먼저)
+/// 블록당 대략 30만 row가 필요하므로 k는 19 이상으로 잡아야 함
+#[derive(Clone, Debug)]
+pub struct KeccakChip<F: PrimeField> {
+    bitwise: BitwiseChip<F>,
+}
+
+impl<F: PrimeField> KeccakChip<F> {
+    pub fn construct(config: KeccakConfig) -> Self {
+        Self { bitwise: BitwiseChip::new(MainGate::new(config.main_gate)) }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> KeccakConfig {
+        KeccakConfig { main_gate: MainGate::configure(meta) }
+    }
+
+    pub fn bitwise(&self) -> &BitwiseChip<F> {
+        &self.bitwise
+    }
+
+    /// 고정 길이(바이트 단위) 입력의 Keccak-256. 256개의 digest bit 반환
+    pub fn hash_bits(&self, ctx: &mut RegionCtx<'_, F>, input: &[Bit<F>]) -> Result<Vec<Bit<F>>, Error> {
+        assert_eq!(input.len() % 8, 0, "keccak input must be byte aligned");
+        let padded = pad(input);
+
+        let mut state = empty_state();
+        for block in padded.chunks(RATE_BITS) {
+            self.absorb(ctx, &mut state, block)?;
+        }
+        Ok(squeeze(&state))
+    }
+
+    /// 가변 길이 입력의 Keccak-256
+    /// bytes: 최대 길이만큼 할당된 바이트 셀 (len 이후 값은 무시됨), len: 실제 바이트 길이
+    /// 블록 수는 bytes.len()으로 정해지는 최대값만큼 항상 계산하고 마지막 블록의 state를 선택
+    pub fn hash_var_bytes(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        bytes: &[AssignedValue<F>],
+        len: &AssignedValue<F>,
+    ) -> Result<Vec<Bit<F>>, Error> {
+        let main_gate = self.bitwise.main_gate();
+        let max_len = bytes.len();
+        let max_blocks = max_len / RATE_BYTES + 1;
+
+        // len의 one-hot 표현: is_len[j] = (len == j), 합이 1이므로 len <= max_len
+        let mut is_len = Vec::with_capacity(max_len + 1);
+        for j in 0..=max_len {
+            let j = main_gate.assign_constant(ctx, F::from(j as u64))?;
+            is_len.push(main_gate.is_equal(ctx, len, &j)?);
+        }
+        let terms: Vec<Term<F>> = is_len.iter().map(|c| Term::Assigned(c, F::ONE)).collect();
+        let sum = main_gate.compose(ctx, &terms, F::ZERO)?;
+        main_gate.assert_one(ctx, &sum)?;
+
+        // is_final[b]: b번째 블록이 마지막 블록인지 (len / RATE_BYTES == b)
+        let mut is_final = Vec::with_capacity(max_blocks);
+        for b in 0..max_blocks {
+            let range = b * RATE_BYTES..((b + 1) * RATE_BYTES).min(max_len + 1);
+            let terms: Vec<Term<F>> = is_len[range].iter().map(|c| Term::Assigned(c, F::ONE)).collect();
+            is_final.push(main_gate.compose(ctx, &terms, F::ZERO)?);
+        }
+
+        // padding 적용: byte_i = (i < len) * m_i + (i == len) * 0x01 + (블록 끝 && 마지막 블록) * 0x80
+        let mut after_len = main_gate.assign_constant(ctx, F::ZERO)?;
+        let mut padded = Vec::with_capacity(max_blocks * RATE_BITS);
+        for i in 0..max_blocks * RATE_BYTES {
+            // after_len = (i >= len) = sum_{j <= i} is_len[j]
+            if i <= max_len {
+                after_len = main_gate.add(ctx, &after_len, &is_len[i])?;
+            }
+            let mut terms = vec![];
+            let masked;
+            if i < max_len {
+                let before_len = main_gate.not(ctx, &after_len)?;
+                masked = main_gate.mul(ctx, &before_len, &bytes[i])?;
+                terms.push(Term::Assigned(&masked, F::ONE));
+            }
+            if i <= max_len {
+                terms.push(Term::Assigned(&is_len[i], F::ONE));
+            }
+            if i % RATE_BYTES == RATE_BYTES - 1 {
+                terms.push(Term::Assigned(&is_final[i / RATE_BYTES], F::from(0x80)));
+            }
+            let byte = if terms.is_empty() {
+                main_gate.assign_constant(ctx, F::ZERO)?
+            } else {
+                main_gate.compose(ctx, &terms, F::ZERO)?
+            };
+            padded.extend(self.bitwise.to_bits(ctx, &byte, 8)?);
+        }
+
+        // 모든 블록을 흡수하면서 각 블록 직후의 digest를 is_final로 선택
+        let mut state = empty_state();
+        let mut digest: Vec<Bit<F>> = vec![Bit::Constant(false); DIGEST_BITS];
+        for (b, block) in padded.chunks(RATE_BITS).enumerate() {
+            self.absorb(ctx, &mut state, block)?;
+            let candidate = squeeze(&state);
+            let selected = Bit::Assigned(is_final[b].clone());
+            for (d, c) in digest.iter_mut().zip(candidate.iter()) {
+                // 선택되는 블록은 하나뿐이므로 xor로 누적해도 선택과 같음
+                let picked = self.bitwise.and(ctx, &selected, c)?;
+                *d = self.bitwise.xor(ctx, d, &picked)?;
+            }
+        }
+        Ok(digest)
+    }
+
+    /// EIP-191 personal_sign 메시지 해시 (고정 길이 메시지)
+    /// keccak256("\x19Ethereum Signed Message:\n" || len || message)
+    pub fn hash_eip191(&self, ctx: &mut RegionCtx<'_, F>, message: &[Bit<F>]) -> Result<Vec<Bit<F>>, Error> {
+        assert_eq!(message.len() % 8, 0, "message must be byte aligned");
+        let mut input = constant_bits(&eip191_prefix(message.len() / 8));
+        input.extend_from_slice(message);
+        self.hash_bits(ctx, &input)
+    }
+
+    /// EIP-712 typed data 해시: keccak256(0x19 0x01 || domainSeparator || hashStruct(message))
+    pub fn hash_eip712(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        domain_separator: &[Bit<F>],
+        struct_hash: &[Bit<F>],
+    ) -> Result<Vec<Bit<F>>, Error> {
+        assert_eq!(domain_separator.len(), DIGEST_BITS);
+        assert_eq!(struct_hash.len(), DIGEST_BITS);
+        let mut input = constant_bits(&[0x19, 0x01]);
+        input.extend_from_slice(domain_separator);
+        input.extend_from_slice(struct_hash);
+        self.hash_bits(ctx, &input)
+    }
+
+    fn absorb(&self, ctx: &mut RegionCtx<'_, F>, state: &mut State<F>, block: &[Bit<F>]) -> Result<(), Error> {
+        for (i, lane_bits) in block.chunks(64).enumerate() {
+            let (x, y) = (i % 5, i / 5);
+            for (z, bit) in lane_bits.iter().enumerate() {
+                state[x][y][z] = self.bitwise.xor(ctx, &state[x][y][z], bit)?;
+            }
+        }
+        self.permute(ctx, state)
+    }
+
+    fn permute(&self, ctx: &mut RegionCtx<'_, F>, state: &mut State<F>) -> Result<(), Error> {
+        let bitwise = &self.bitwise;
+        for rc in ROUND_CONSTANTS {
+            // theta
+            let mut c: Vec<Lane<F>> = Vec::with_capacity(5);
+            for x in 0..5 {
+                let mut lane = state[x][0].clone();
+                for y in 1..5 {
+                    lane = xor_lane(bitwise, ctx, &lane, &state[x][y])?;
+                }
+                c.push(lane);
+            }
+            for x in 0..5 {
+                let d = xor_lane(bitwise, ctx, &c[(x + 4) % 5], &rotate(&c[(x + 1) % 5], 1))?;
+                for y in 0..5 {
+                    state[x][y] = xor_lane(bitwise, ctx, &state[x][y], &d)?;
+                }
+            }
+
+            // rho + pi: B[y][2x + 3y] = rot(A[x][y], r[x][y])
+            let mut b = empty_state();
+            for x in 0..5 {
+                for y in 0..5 {
+                    b[y][(2 * x + 3 * y) % 5] = rotate(&state[x][y], ROTATIONS[x][y]);
+                }
+            }
+
+            // chi: A[x][y] = B[x][y] ^ (!B[x+1][y] & B[x+2][y])
+            for x in 0..5 {
+                for y in 0..5 {
+                    let mut lane = Vec::with_capacity(64);
+                    for z in 0..64 {
+                        let t = bitwise.and_not(ctx, &b[(x + 1) % 5][y][z], &b[(x + 2) % 5][y][z])?;
+                        lane.push(bitwise.xor(ctx, &b[x][y][z], &t)?);
+                    }
+                    state[x][y] = lane;
+                }
+            }
+
+            // iota
+            for z in 0..64 {
+                if (rc >> z) & 1 == 1 {
+                    state[0][0][z] = bitwise.not(ctx, &state[0][0][z])?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn xor_lane<F: PrimeField>(
+    chip: &BitwiseChip<F>,
+    ctx: &mut RegionCtx<'_, F>,
+    a: &[Bit<F>],
+    b: &[Bit<F>],
+) -> Result<Lane<F>, Error> {
+    a.iter().zip(b.iter()).map(|(a, b)| chip.xor(ctx, a, b)).collect()
+}
+
+/// lane을 왼쪽으로 n비트 회전 (z는 little-endian bit index)
+fn rotate<F: PrimeField>(lane: &[Bit<F>], n: usize) -> Lane<F> {
+    (0..64).map(|z| lane[(z + 64 - n) % 64].clone()).collect()
+}
+
+fn empty_state<F: PrimeField>() -> State<F> {
+    std::array::from_fn(|_| std::array::from_fn(|_| vec![Bit::Constant(false); 64]))
+}
+
+/// pad10*1 (keccak 도메인: 0x01 ... 0x80). padding bit은 모두 상수
+pub fn pad<F: PrimeField>(input: &[Bit<F>]) -> Vec<Bit<F>> {
+    let mut padded = input.to_vec();
+    padded.push(Bit::Constant(true));
+    while padded.len() % RATE_BITS != RATE_BITS - 1 {
+        padded.push(Bit::Constant(false));
+    }
+    padded.push(Bit::Constant(true));
+    padded
+}
+
+fn squeeze<F: PrimeField>(state: &State<F>) -> Vec<Bit<F>> {
+    (0..DIGEST_BITS / 64)
+        .flat_map(|i| state[i % 5][i / 5].clone())
+        .collect()
+}
+
+/// 바이트 상수를 bit stream으로
+pub fn constant_bits<F: PrimeField>(bytes: &[u8]) -> Vec<Bit<F>> {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| Bit::Constant((byte >> i) & 1 == 1)))
+        .collect()
+}
+
+/// EIP-191 prefix || 메시지 길이(10진)
+pub fn eip191_prefix(message_len: usize) -> Vec<u8> {
+    let mut prefix = EIP191_PREFIX.to_vec();
+    prefix.extend_from_slice(message_len.to_string().as_bytes());
+    prefix
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::gadgets::bitwise::{bytes_to_bits, pack_bits, pack_bytes};
+    use crate::test_utils::{assert_permutation_failure, mock_prove};
+    use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::plonk::{Circuit, Column, Instance};
+    use halo2curves::bn256::Fr;
+    use sha3::{Digest, Keccak256};
+
+    /// 어떤 해시 함수를 쓸지
+    #[derive(Clone, Copy, Default)]
+    pub(crate) enum Mode {
+        /// hash_bits
+        #[default]
+        Fixed,
+        /// hash_var_bytes. max_len까지 0xaa로 채우고 실제 길이는 input 길이
+        Var { max_len: usize },
+        /// hash_eip191
+        Eip191,
+        /// hash_eip712. input = domainSeparator || hashStruct
+        Eip712,
+    }
+
+    /// input의 digest를 pack_bits로 묶어 public input에 노출
+    #[derive(Clone, Default)]
+    pub(crate) struct KeccakCircuit {
+        pub(crate) input: Vec<u8>,
+        pub(crate) mode: Mode,
+    }
+
+    impl Circuit<Fr> for KeccakCircuit {
+        type Config = (KeccakConfig, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self { input: vec![0; self.input.len()], mode: self.mode }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+            (KeccakChip::configure(meta), instance)
+        }
+
+        fn synthesize(&self, (config, instance): Self::Config, mut layouter: impl Layouter<Fr>) -> Result<(), Error> {
+            let chip = KeccakChip::construct(config);
+            let digest = layouter.assign_region(
+                || "keccak",
+                |region| {
+                    let mut ctx = RegionCtx::new(region, 0);
+                    let bitwise = chip.bitwise();
+                    let mut input = self.input.clone();
+                    if let Mode::Var { max_len } = self.mode {
+                        input.resize(max_len, 0xaa);
+                    }
+                    let bytes = input
+                        .iter()
+                        .map(|b| bitwise.main_gate().assign_value(&mut ctx, Value::known(Fr::from(*b as u64))))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let digest = match self.mode {
+                        Mode::Fixed => chip.hash_bits(&mut ctx, &bytes_to_bits(bitwise, &mut ctx, &bytes)?)?,
+                        Mode::Var { .. } => {
+                            let len = Value::known(Fr::from(self.input.len() as u64));
+                            let len = bitwise.main_gate().assign_value(&mut ctx, len)?;
+                            chip.hash_var_bytes(&mut ctx, &bytes, &len)?
+                        }
+                        Mode::Eip191 => chip.hash_eip191(&mut ctx, &bytes_to_bits(bitwise, &mut ctx, &bytes)?)?,
+                        Mode::Eip712 => {
+                            let bits = bytes_to_bits(bitwise, &mut ctx, &bytes)?;
+                            let (domain_separator, struct_hash) = bits.split_at(DIGEST_BITS);
+                            chip.hash_eip712(&mut ctx, domain_separator, struct_hash)?
+                        }
+                    };
+                    pack_bits(bitwise, &mut ctx, &digest)
+                },
+            )?;
+            for (i, cell) in digest.iter().enumerate() {
+                layouter.constrain_instance(cell.cell(), instance, i)?;
+            }
+            Ok(())
+        }
+    }
+
+    pub(crate) fn digest_instances(digest: &[u8]) -> Vec<Vec<Fr>> {
+        vec![pack_bytes(digest)]
+    }
+
+    #[test]
+    fn matches_keccak256() {
+        for input in [vec![], (0..64).collect::<Vec<u8>>()] {
+            let expected = Keccak256::digest(&input);
+            let circuit = KeccakCircuit { input, mode: Mode::Fixed };
+            assert_eq!(mock_prove(&circuit, digest_instances(&expected)), Ok(()));
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_digest() {
+        let circuit = KeccakCircuit { input: b"abc".to_vec(), mode: Mode::Fixed };
+        let mut digest = Keccak256::digest(b"abc");
+        digest[0] ^= 1;
+        assert_permutation_failure(mock_prove(&circuit, digest_instances(&digest)));
+    }
+
+    #[test]
+    fn padding_is_keccak_not_sha3() {
+        // 0x01 ... 0x80 padding: 한 블록을 정확히 채우면 padding 블록이 하나 더 붙음
+        let padded = pad(&constant_bits::<Fr>(&[0u8; RATE_BYTES]));
+        assert_eq!(padded.len(), 2 * RATE_BITS);
+        assert!(matches!(padded[RATE_BITS], Bit::Constant(true)));
+        assert!(matches!(padded[2 * RATE_BITS - 1], Bit::Constant(true)));
+
+        // 블록에 한 바이트가 남으면 0x01과 0x80이 같은 바이트(0x81)에 들어감
+        let padded = pad(&constant_bits::<Fr>(&[0u8; RATE_BYTES - 1]));
+        assert_eq!(padded.len(), RATE_BITS);
+        assert!(matches!(padded[RATE_BITS - 8], Bit::Constant(true)));
+        assert!(matches!(padded[RATE_BITS - 1], Bit::Constant(true)));
+    }
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+    }
+
+    #[test]
+    fn var_length_matches_keccak256_at_block_boundaries() {
+        // 135: 0x01과 0x80이 한 바이트, 136: padding 블록 추가, 137: 두 번째 블록에 데이터
+        let max_len = RATE_BYTES + 1;
+        for len in [0, RATE_BYTES - 1, RATE_BYTES, RATE_BYTES + 1] {
+            let input: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let expected = Keccak256::digest(&input);
+            let circuit = KeccakCircuit { input, mode: Mode::Var { max_len } };
+            assert_eq!(mock_prove(&circuit, digest_instances(&expected)), Ok(()), "len {len}");
+        }
+    }
+
+    #[test]
+    fn var_length_rejects_digest_of_other_length() {
+        // 길이 셀이 padding 위치를 정하므로 뒤의 바이트를 포함한 digest와는 맞지 않음
+        let input: Vec<u8> = vec![0xaa; RATE_BYTES];
+        let expected = Keccak256::digest([0xaa; RATE_BYTES + 1]);
+        let circuit = KeccakCircuit { input, mode: Mode::Var { max_len: RATE_BYTES + 1 } };
+        assert_permutation_failure(mock_prove(&circuit, digest_instances(&expected)));
+    }
+
+    #[test]
+    fn personal_sign_digest() {
+        // ethers `hashMessage("Hello World")`
+        let expected = from_hex("a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2");
+        let circuit = KeccakCircuit { input: b"Hello World".to_vec(), mode: Mode::Eip191 };
+        assert_eq!(mock_prove(&circuit, digest_instances(&expected)), Ok(()));
+        assert_eq!(eip191_prefix(11), b"\x19Ethereum Signed Message:\n11");
+    }
+
+    #[test]
+    fn eip712_digest() {
+        // EIP-712 명세의 Mail 예제
+        let mut input = from_hex("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f");
+        input.extend(from_hex("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"));
+        let expected = from_hex("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2");
+        let circuit = KeccakCircuit { input, mode: Mode::Eip712 };
+        assert_eq!(mock_prove(&circuit, digest_instances(&expected)), Ok(()));
+    }
+}
@@ -1,40 +1,177 @@
 use halo2::{
-    circuit::{AssignedCell, Region, Value, Layouter},
-    plonk::Error,
+    circuit::{AssignedCell, Layouter, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
 };
 use halo2curves::bn256::Fr;
 use crate::gadgets::poseidon::PoseidonGadget;
 use poseidon::Pow5Chip;
 
+/// 한 단계의 좌우 swap. 0행: [cur, sibling, bit], 1행: [left, right]
+#[derive(Clone, Debug)]
+pub struct MerkleConfig {
+    pub advice: [Column<Advice>; 3],
+    pub swap: Selector,
+}
 
 pub struct MerkleGadget;
 
 impl MerkleGadget {
+    /// bit는 boolean, bit = 0이면 (left, right) = (cur, sibling), 1이면 (sibling, cur)
+    pub fn configure(meta: &mut ConstraintSystem<Fr>) -> MerkleConfig {
+        let advice = [meta.advice_column(), meta.advice_column(), meta.advice_column()];
+        meta.enable_equality(advice[0]);
+        meta.enable_equality(advice[1]);
+        let swap = meta.selector();
+
+        meta.create_gate("merkle swap", |meta| {
+            let s = meta.query_selector(swap);
+            let cur = meta.query_advice(advice[0], Rotation::cur());
+            let sibling = meta.query_advice(advice[1], Rotation::cur());
+            let bit = meta.query_advice(advice[2], Rotation::cur());
+            let left = meta.query_advice(advice[0], Rotation::next());
+            let right = meta.query_advice(advice[1], Rotation::next());
+            let one = Expression::Constant(Fr::one());
+            vec![
+                s.clone() * bit.clone() * (one - bit.clone()),
+                s.clone() * (left - (cur.clone() + bit.clone() * (sibling.clone() - cur.clone()))),
+                s * (right - (sibling.clone() + bit * (cur - sibling))),
+            ]
+        });
+
+        MerkleConfig { advice, swap }
+    }
+
     /// in-circuit Merkle root 계산
-    /// leaf: AssignedCell<Fr, Fr>
-    /// path: Vec<AssignedCell<Fr, Fr>>
-    /// indices: &[bool]
+    /// indices(leaf 위치의 bit, 0: 왼쪽, 1: 오른쪽)는 witness라 leaf 위치가 달라도 회로 모양은 같음
     pub fn compute_root(
+        config: &MerkleConfig,
         chip: &Pow5Chip<Fr, 3, 2>,
         layouter: &mut impl Layouter<Fr>,
         mut hash: AssignedCell<Fr, Fr>,
         path: &[AssignedCell<Fr, Fr>],
         indices: &[bool],
     ) -> Result<AssignedCell<Fr, Fr>, Error> {
-        
-        for (i, sibling) in path.iter().enumerate() {
-           let (left, right) = if indices[i] {
-            (sibling.clone(), hash)
-           } else {
-                (hash, sibling.clone())
-           };
-           // PoseidonGadget의 in-circuit 해시 API 사용
-           hash = PoseidonGadget::hash::<2>(
-            chip,
-            layouter.namespace(|| format!("merkle_hash_{i}")),
-            [left, right],
-        )?;
-    }
-    Ok(hash)
+        assert_eq!(path.len(), indices.len(), "merkle path and index bits differ in length");
+        for (i, (sibling, index)) in path.iter().zip(indices).enumerate() {
+            let (left, right) = layouter.assign_region(
+                || format!("merkle_swap_{i}"),
+                |mut region| {
+                    config.swap.enable(&mut region, 0)?;
+                    let cur = hash.copy_advice(|| "cur", &mut region, config.advice[0], 0)?;
+                    let sibling = sibling.copy_advice(|| "sibling", &mut region, config.advice[1], 0)?;
+                    let bit = if *index { Fr::one() } else { Fr::zero() };
+                    region.assign_advice(|| "index bit", config.advice[2], 0, || Value::known(bit))?;
+                    let (left, right) = if *index {
+                        (sibling.value().copied(), cur.value().copied())
+                    } else {
+                        (cur.value().copied(), sibling.value().copied())
+                    };
+                    let left = region.assign_advice(|| "left", config.advice[0], 1, || left)?;
+                    let right = region.assign_advice(|| "right", config.advice[1], 1, || right)?;
+                    Ok((left, right))
+                },
+            )?;
+            // PoseidonGadget의 in-circuit 해시 API 사용
+            hash = PoseidonGadget::hash::<2>(
+                chip,
+                layouter.namespace(|| format!("merkle_hash_{i}")),
+                [left, right],
+            )?;
+        }
+        Ok(hash)
+    }
+
+    /// `compute_root`의 native 버전
+    pub fn compute_root_native(leaf: Fr, path: &[Fr], indices: &[bool]) -> Fr {
+        path.iter().zip(indices.iter()).fold(leaf, |hash, (sibling, index)| {
+            if *index {
+                PoseidonGadget::hash_native([*sibling, hash])
+            } else {
+                PoseidonGadget::hash_native([hash, *sibling])
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::test_utils::{assert_permutation_failure, configure_poseidon, index_bits, merkle_proof, mock_prove, random_fr};
+    use halo2::circuit::SimpleFloorPlanner;
+    use halo2::plonk::{Circuit, Instance};
+    use poseidon::Pow5Config;
+
+    pub(crate) const DEPTH: usize = 4;
+
+    #[derive(Clone, Default)]
+    pub(crate) struct MerkleCircuit {
+        pub(crate) leaf: Fr,
+        pub(crate) path: Vec<Fr>,
+        pub(crate) indices: Vec<bool>,
+    }
+
+    impl Circuit<Fr> for MerkleCircuit {
+        type Config = (Column<Advice>, Pow5Config<Fr, 3, 2>, MerkleConfig, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self { leaf: Fr::zero(), path: vec![Fr::zero(); self.path.len()], indices: vec![false; self.indices.len()] }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            let witness = meta.advice_column();
+            meta.enable_equality(witness);
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+            (witness, configure_poseidon(meta), MerkleGadget::configure(meta), instance)
+        }
+
+        fn synthesize(&self, (witness, poseidon, merkle, instance): Self::Config, mut layouter: impl Layouter<Fr>) -> Result<(), Error> {
+            let (leaf, path) = layouter.assign_region(
+                || "witness",
+                |mut region| {
+                    let leaf = region.assign_advice(|| "leaf", witness, 0, || Value::known(self.leaf))?;
+                    let path = self
+                        .path
+                        .iter()
+                        .enumerate()
+                        .map(|(i, v)| region.assign_advice(|| "sibling", witness, i + 1, || Value::known(*v)))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok((leaf, path))
+                },
+            )?;
+            let chip = Pow5Chip::construct(poseidon);
+            let root = MerkleGadget::compute_root(&merkle, &chip, &mut layouter, leaf, &path, &self.indices)?;
+            layouter.constrain_instance(root.cell(), instance, 0)
+        }
+    }
+
+    pub(crate) fn valid(leaf_index: usize) -> (MerkleCircuit, Fr) {
+        let leaf = random_fr();
+        let (path, root) = merkle_proof(leaf, DEPTH, leaf_index);
+        (MerkleCircuit { leaf, path, indices: index_bits(leaf_index, DEPTH) }, root)
+    }
+
+    #[test]
+    fn accepts_valid_proofs() {
+        for leaf_index in [0, 5, (1 << DEPTH) - 1] {
+            let (circuit, root) = valid(leaf_index);
+            assert_eq!(mock_prove(&circuit, vec![vec![root]]), Ok(()));
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_sibling() {
+        let (mut circuit, root) = valid(3);
+        circuit.path[1] += Fr::one();
+        assert_permutation_failure(mock_prove(&circuit, vec![vec![root]]));
+    }
+
+    #[test]
+    fn rejects_wrong_leaf_position() {
+        let (mut circuit, root) = valid(3);
+        circuit.indices[0] = !circuit.indices[0];
+        assert_permutation_failure(mock_prove(&circuit, vec![vec![root]]));
     }
 }
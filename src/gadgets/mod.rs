@@ -2,3 +2,12 @@ pub mod poseidon;
 pub mod merkle;
 pub mod range_check;
 pub mod signature;
+pub mod comparison;
+pub mod bitwise;
+pub mod keccak;
+pub mod eth_address;
+pub mod sha256;
+pub mod rsa;
+pub mod base64;
+#[cfg(test)]
+mod fuzz;
@@ -3,7 +3,8 @@ use halo2::{
     circuit::{AssignedCell, Region, Value, Layouter},
     plonk::Error,
 };
-use poseidon::{Pow5Chip, Spec, ConstantLength, Hash};
+use poseidon::{Pow5Chip, Spec, ConstantLength, Hash, P128Pow5T3};
+use poseidon::primitives::{ConstantLength as NativeLength, Hash as NativeHash};
 
 const WIDTH: usize = 3;
 const RATE: usize = 2;
@@ -26,4 +27,116 @@ impl PoseidonGadget {
         >::init(chip, layouter.namespace(|| "init"))?;
         hasher.hash(layouter, inputs)
     }
-}
\ No newline at end of file
+
+    /// 가변 길이 입력 해시: h = length, h = H(h, x_i) 순서로 접는다
+    /// length: 입력 개수를 담은 셀 (보통 constant로 할당)
+    pub fn hash_chain(
+        chip: &Pow5Chip<Fr, WIDTH, RATE>,
+        mut layouter: impl Layouter<Fr>,
+        length: AssignedCell<Fr, Fr>,
+        inputs: &[AssignedCell<Fr, Fr>],
+    ) -> Result<AssignedCell<Fr, Fr>, Error> {
+        let mut acc = length;
+        for (i, input) in inputs.iter().enumerate() {
+            acc = Self::hash::<2>(
+                chip,
+                layouter.namespace(|| format!("chain_{i}")),
+                [acc, input.clone()],
+            )?;
+        }
+        Ok(acc)
+    }
+
+    /// 회로 밖(native) Poseidon 해시. `hash`와 같은 spec 사용
+    pub fn hash_native<const L: usize>(inputs: [Fr; L]) -> Fr {
+        NativeHash::<Fr, P128Pow5T3, NativeLength<L>, WIDTH, RATE>::init().hash(inputs)
+    }
+
+    /// `hash_chain`의 native 버전
+    pub fn hash_chain_native(inputs: &[Fr]) -> Fr {
+        inputs
+            .iter()
+            .fold(Fr::from(inputs.len() as u64), |acc, x| Self::hash_native([acc, *x]))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::test_utils::{assert_permutation_failure, configure_poseidon, mock_prove, random_fr};
+    use halo2::circuit::SimpleFloorPlanner;
+    use halo2::plonk::{Advice, Circuit, Column, ConstraintSystem, Instance};
+    use poseidon::Pow5Config;
+
+    /// inputs를 hash_chain으로 해시해서 결과를 public input 0에 노출
+    #[derive(Clone, Default)]
+    pub(crate) struct HashChainCircuit {
+        pub(crate) inputs: Vec<Fr>,
+    }
+
+    impl Circuit<Fr> for HashChainCircuit {
+        type Config = (Column<Advice>, Pow5Config<Fr, WIDTH, RATE>, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self { inputs: vec![Fr::zero(); self.inputs.len()] }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            let witness = meta.advice_column();
+            meta.enable_equality(witness);
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+            (witness, configure_poseidon(meta), instance)
+        }
+
+        fn synthesize(&self, (witness, poseidon, instance): Self::Config, mut layouter: impl Layouter<Fr>) -> Result<(), Error> {
+            let (length, inputs) = layouter.assign_region(
+                || "inputs",
+                |mut region| {
+                    let length = region.assign_advice_from_constant(
+                        || "length",
+                        witness,
+                        0,
+                        Fr::from(self.inputs.len() as u64),
+                    )?;
+                    let inputs = self
+                        .inputs
+                        .iter()
+                        .enumerate()
+                        .map(|(i, v)| region.assign_advice(|| "input", witness, i + 1, || Value::known(*v)))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok((length, inputs))
+                },
+            )?;
+            let chip = Pow5Chip::construct(poseidon);
+            let hash = PoseidonGadget::hash_chain(&chip, layouter.namespace(|| "chain"), length, &inputs)?;
+            layouter.constrain_instance(hash.cell(), instance, 0)
+        }
+    }
+
+    #[test]
+    fn hash_chain_matches_native() {
+        let inputs: Vec<Fr> = (0..3).map(|_| random_fr()).collect();
+        let expected = PoseidonGadget::hash_chain_native(&inputs);
+        let circuit = HashChainCircuit { inputs };
+        assert_eq!(mock_prove(&circuit, vec![vec![expected]]), Ok(()));
+    }
+
+    #[test]
+    fn hash_chain_of_single_input_is_hash_of_length_and_input() {
+        let x = random_fr();
+        assert_eq!(
+            PoseidonGadget::hash_chain_native(&[x]),
+            PoseidonGadget::hash_native([Fr::one(), x])
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_digest() {
+        let inputs: Vec<Fr> = (0..2).map(|_| random_fr()).collect();
+        let wrong = PoseidonGadget::hash_chain_native(&inputs) + Fr::one();
+        let circuit = HashChainCircuit { inputs };
+        assert_permutation_failure(mock_prove(&circuit, vec![vec![wrong]]));
+    }
+}
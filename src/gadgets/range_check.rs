@@ -1,28 +1,37 @@
 use halo2::{
     circuit::{Layouter, Value},
-    plonk::{Advice, Column, ConstraintSystem, Error, Selector},
-   
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector, TableColumn},
+
 };
-use halo2curves::ff::{Field, PrimeField};
+use halo2curves::ff::PrimeField;
 use halo2::poly::Rotation;
 use halo2::circuit::AssignedCell;
-use integer::IntegerInstructions;
+
+/// value - min, max - value를 나누는 바이트 수 (64비트 범위)
+pub const RANGE_BYTES: usize = 8;
 
 #[derive(Clone, Debug)]
 pub struct RangeCheckConfig {
     pub value: Column<Advice>,
     pub min: Column<Advice>,
     pub max: Column<Advice>,
+    /// 0행: value - min, 1행: max - value 의 little-endian 바이트
+    pub bytes: [Column<Advice>; RANGE_BYTES],
+    pub table: TableColumn,
     pub selector: Selector,
+    pub lookup_selector: Selector,
 }
 
-pub struct RangeCheckChip<F: Field> {
+/// min <= value <= max 검사
+/// 두 차이를 바이트로 분해하고 각 바이트를 0..256 테이블로 lookup
+/// 차이가 음수면 field에서 2^64 이상이 되어 분해할 수 없음
+pub struct RangeCheckChip<F: PrimeField> {
     config: RangeCheckConfig,
     _marker: std::marker::PhantomData<F>,
 }
 
 
-impl<F: Field> RangeCheckChip<F> {
+impl<F: PrimeField> RangeCheckChip<F> {
     pub fn construct(config: RangeCheckConfig) -> Self {
         Self { config, _marker: std::marker::PhantomData }
     }
@@ -31,39 +40,197 @@ impl<F: Field> RangeCheckChip<F> {
         let value = meta.advice_column();
         let min = meta.advice_column();
         let max = meta.advice_column();
+        let bytes = [(); RANGE_BYTES].map(|_| meta.advice_column());
+        let table = meta.lookup_table_column();
         let selector = meta.selector();
+        let lookup_selector = meta.complex_selector();
+        meta.enable_equality(value);
+        meta.enable_equality(min);
+        meta.enable_equality(max);
 
         meta.create_gate("range check", |meta| {
             let s = meta.query_selector(selector);
             let value = meta.query_advice(value, Rotation::cur());
             let min = meta.query_advice(min, Rotation::cur());
             let max = meta.query_advice(max, Rotation::cur());
+            let compose = |meta: &mut halo2::plonk::VirtualCells<'_, F>, rotation: Rotation| {
+                bytes.iter().rev().fold(Expression::Constant(F::ZERO), |acc, column| {
+                    acc * Expression::Constant(F::from(256)) + meta.query_advice(*column, rotation)
+                })
+            };
+            let lower = compose(meta, Rotation::cur());
+            let upper = compose(meta, Rotation::next());
 
             vec![
-                s.clone() * (value.clone() - min.clone()), // value >= min
-                s * (max - value),                         // value <= max
+                s.clone() * (value.clone() - min - lower), // value >= min
+                s * (max - value - upper),                 // value <= max
             ]
         });
 
-        RangeCheckConfig { value, min, max, selector }
+        for column in bytes {
+            meta.lookup("range check byte", |meta| {
+                let s = meta.query_selector(lookup_selector);
+                let byte = meta.query_advice(column, Rotation::cur());
+                vec![(s * byte, table)]
+            });
+        }
+
+        RangeCheckConfig { value, min, max, bytes, table, selector, lookup_selector }
+    }
+
+    /// 바이트 테이블 로드. synthesize에서 한 번 호출해야 함
+    pub fn load_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        layouter.assign_table(
+            || "range check bytes",
+            |mut table| {
+                for i in 0..256 {
+                    table.assign_cell(|| "byte", self.config.table, i, || Value::known(F::from(i as u64)))?;
+                }
+                Ok(())
+            },
+        )
     }
 
     pub fn range_check(
         &self,
         layouter: &mut impl Layouter<F>,
         assigned_value: &AssignedCell<F, F>,
-        min: F,
-        max: F,
+        min: &AssignedCell<F, F>,
+        max: &AssignedCell<F, F>,
     ) -> Result<(), Error> {
         layouter.assign_region(
             || "range check",
             |mut region| {
                 self.config.selector.enable(&mut region, 0)?;
-                assigned_value.constrain_advice(region, self.config.value, 0)?;
-                region.assign_advice(|| "min", self.config.min, 0, || Value::known(min))?;
-                region.assign_advice(|| "max", self.config.max, 0, || Value::known(max))?;
+                self.config.lookup_selector.enable(&mut region, 0)?;
+                self.config.lookup_selector.enable(&mut region, 1)?;
+                let value = assigned_value.copy_advice(|| "value", &mut region, self.config.value, 0)?;
+                let min = min.copy_advice(|| "min", &mut region, self.config.min, 0)?;
+                let max = max.copy_advice(|| "max", &mut region, self.config.max, 0)?;
+
+                let lower = value.value().zip(min.value()).map(|(v, m)| *v - *m);
+                let upper = max.value().zip(value.value()).map(|(m, v)| *m - *v);
+                for (offset, diff) in [lower, upper].into_iter().enumerate() {
+                    for (i, column) in self.config.bytes.iter().enumerate() {
+                        let byte = diff.map(|d| F::from(d.to_repr().as_ref()[i] as u64));
+                        region.assign_advice(|| format!("byte_{i}"), *column, offset, || byte)?;
+                    }
+                }
                 Ok(())
             },
         )
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::test_utils::{assert_gate_failure, assert_lookup_failure, mock_prove};
+    use halo2::circuit::SimpleFloorPlanner;
+    use halo2::plonk::Circuit;
+    use halo2curves::bn256::Fr;
+    use halo2curves::ff::Field;
+
+    #[derive(Clone, Default)]
+    pub(crate) struct RangeCheckCircuit {
+        pub(crate) value: Fr,
+        pub(crate) min: Fr,
+        pub(crate) max: Fr,
+    }
+
+    impl Circuit<Fr> for RangeCheckCircuit {
+        type Config = RangeCheckConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            RangeCheckChip::configure(meta)
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<Fr>) -> Result<(), Error> {
+            let chip = RangeCheckChip::<Fr>::construct(config.clone());
+            chip.load_table(&mut layouter)?;
+            let (value, min, max) = layouter.assign_region(
+                || "inputs",
+                |mut region| {
+                    let value = region.assign_advice(|| "value", config.value, 0, || Value::known(self.value))?;
+                    let min = region.assign_advice(|| "min", config.min, 0, || Value::known(self.min))?;
+                    let max = region.assign_advice(|| "max", config.max, 0, || Value::known(self.max))?;
+                    Ok((value, min, max))
+                },
+            )?;
+            chip.range_check(&mut layouter, &value, &min, &max)
+        }
+    }
+
+    pub(crate) fn circuit(value: u64, min: u64, max: u64) -> RangeCheckCircuit {
+        RangeCheckCircuit { value: Fr::from(value), min: Fr::from(min), max: Fr::from(max) }
+    }
+
+    #[test]
+    fn accepts_values_in_range() {
+        for value in [18, 30, 65] {
+            assert_eq!(mock_prove(&circuit(value, 18, 65), vec![]), Ok(()));
+        }
+    }
+
+    #[test]
+    fn rejects_value_below_min() {
+        assert_gate_failure(mock_prove(&circuit(17, 18, 65), vec![]), "range check");
+    }
+
+    #[test]
+    fn rejects_value_above_max() {
+        assert_gate_failure(mock_prove(&circuit(66, 18, 65), vec![]), "range check");
+    }
+
+    #[test]
+    fn rejects_forged_decomposition() {
+        // value < min 인데 gate를 맞추려면 바이트가 256 이상이어야 함
+        assert_lookup_failure(mock_prove(&ForgedRangeCheck(circuit(17, 18, 65)), vec![]));
+    }
+
+    /// 가장 높은 바이트에 (value - min) 전체를 넣어 gate는 만족시키는 악의적인 prover
+    #[derive(Clone, Default)]
+    struct ForgedRangeCheck(RangeCheckCircuit);
+
+    impl Circuit<Fr> for ForgedRangeCheck {
+        type Config = RangeCheckConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            RangeCheckChip::configure(meta)
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<Fr>) -> Result<(), Error> {
+            RangeCheckChip::<Fr>::construct(config.clone()).load_table(&mut layouter)?;
+            let RangeCheckCircuit { value, min, max } = self.0.clone();
+            layouter.assign_region(
+                || "forged range check",
+                |mut region| {
+                    config.selector.enable(&mut region, 0)?;
+                    config.lookup_selector.enable(&mut region, 0)?;
+                    config.lookup_selector.enable(&mut region, 1)?;
+                    region.assign_advice(|| "value", config.value, 0, || Value::known(value))?;
+                    region.assign_advice(|| "min", config.min, 0, || Value::known(min))?;
+                    region.assign_advice(|| "max", config.max, 0, || Value::known(max))?;
+                    let shift = Fr::from(256).pow_vartime([(RANGE_BYTES - 1) as u64]);
+                    for (offset, diff) in [value - min, max - value].into_iter().enumerate() {
+                        for (i, column) in config.bytes.iter().enumerate() {
+                            let byte = if i == RANGE_BYTES - 1 { diff * shift.invert().unwrap() } else { Fr::zero() };
+                            region.assign_advice(|| "byte", *column, offset, || Value::known(byte))?;
+                        }
+                    }
+                    Ok(())
+                },
+            )
+        }
+    }
+}
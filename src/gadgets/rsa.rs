@@ -0,0 +1,426 @@
+use halo2::circuit::{Layouter, Value};
+use halo2::plonk::{ConstraintSystem, Error};
+use halo2curves::ff::PrimeField;
+use maingate::{
+    AssignedValue, MainGate, MainGateConfig, MainGateInstructions, RangeChip, RangeConfig,
+    RangeInstructions, RegionCtx, Term,
+};
+use num_bigint::{BigInt, BigUint, Sign};
+
+use crate::gadgets::bitwise::{Bit, BitwiseChip};
+
+/// big integer limb 비트 수
+pub const LIMB_BITS: usize = 64;
+/// RangeChip lookup 테이블 limb 비트 수
+const RANGE_LIMB_BITS: usize = 8;
+/// RSA 공개 지수 (e = 65537 = 2^16 + 1)
+pub const PUBLIC_EXPONENT: u64 = 65537;
+
+/// SHA-256 DigestInfo DER prefix (RFC 8017 9.2)
+const SHA256_DIGEST_INFO: [u8; 19] = [
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05,
+    0x00, 0x04, 0x20,
+];
+
+/// little-endian 64비트 limb들로 표현한 큰 정수. 각 limb은 range check됨
+#[derive(Clone, Debug)]
+pub struct AssignedBigUint<F: PrimeField> {
+    pub limbs: Vec<AssignedValue<F>>,
+    value: Value<BigUint>,
+}
+
+impl<F: PrimeField> AssignedBigUint<F> {
+    pub fn value(&self) -> Value<BigUint> {
+        self.value.clone()
+    }
+
+    pub fn num_limbs(&self) -> usize {
+        self.limbs.len()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RsaConfig {
+    pub main_gate: MainGateConfig,
+    pub range: RangeConfig,
+}
+
+/// RSA PKCS#1 v1.5 서명 검증 (RS256, e-passport)
+/// RSA-2048은 32 limb, RSA-4096은 64 limb
+/// 모듈러 곱셈은 a * b = q * n + r 을 limb 다항식으로 펼치고 carry로 검사
+pub struct RsaChip<F: PrimeField> {
+    main_gate: MainGate<F>,
+    range: RangeChip<F>,
+}
+
+impl<F: PrimeField> RsaChip<F> {
+    pub fn construct(config: RsaConfig) -> Self {
+        Self {
+            main_gate: MainGate::new(config.main_gate),
+            range: RangeChip::new(config.range),
+        }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> RsaConfig {
+        let main_gate = MainGate::configure(meta);
+        let range = RangeChip::configure(meta, &main_gate, vec![RANGE_LIMB_BITS], vec![]);
+        RsaConfig { main_gate, range }
+    }
+
+    /// range lookup 테이블 로드. synthesize에서 한 번 호출해야 함
+    pub fn load_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.range.load_table(layouter)
+    }
+
+    pub fn main_gate(&self) -> &MainGate<F> {
+        &self.main_gate
+    }
+
+    /// num_limbs개의 64비트 limb으로 할당
+    pub fn assign_biguint(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        value: Value<BigUint>,
+        num_limbs: usize,
+    ) -> Result<AssignedBigUint<F>, Error> {
+        let limbs = (0..num_limbs)
+            .map(|i| {
+                let limb = value.as_ref().map(|v| biguint_to_fe::<F>(&((v >> (i * LIMB_BITS)) & limb_mask())));
+                self.range.assign(ctx, limb, RANGE_LIMB_BITS, LIMB_BITS)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(AssignedBigUint { limbs, value })
+    }
+
+    /// a * b mod n
+    /// q, r을 witness로 받고 a * b - q * n - r = 0 을 limb 단위 carry로 검사
+    ///
+    /// r < n은 검사하지 않음. 악의적인 prover는 r + n 같은 non-canonical 나머지를 쓸 수 있고
+    /// 이 값도 n에 대해 합동이라 다음 곱셈에서는 문제가 없음
+    /// 안전한 이유는 오직 `verify_pkcs1v15_sha256`이 마지막 결과의 모든 limb을
+    /// n보다 작은 EM과 정확히 비교하기 때문임 (r == EM < n 이면 r이 곧 s^e mod n)
+    /// 이 비교를 합동 비교나 일부 limb 비교로 바꾸면 위조가 가능해짐 (tests 참고)
+    pub fn mul_mod(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedBigUint<F>,
+        b: &AssignedBigUint<F>,
+        n: &AssignedBigUint<F>,
+    ) -> Result<AssignedBigUint<F>, Error> {
+        let product = a.value().zip(b.value());
+        let q_value = product.clone().zip(n.value()).map(|((a, b), n)| (a * b) / n);
+        let r_value = product.zip(n.value()).map(|((a, b), n)| (a * b) % n);
+        self.mul_mod_with(ctx, a, b, n, q_value, r_value)
+    }
+
+    /// 주어진 q, r witness로 a * b == q * n + r 을 검사하고 r을 돌려줌
+    fn mul_mod_with(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedBigUint<F>,
+        b: &AssignedBigUint<F>,
+        n: &AssignedBigUint<F>,
+        q_value: Value<BigUint>,
+        r_value: Value<BigUint>,
+    ) -> Result<AssignedBigUint<F>, Error> {
+        let num_limbs = n.num_limbs();
+        assert_eq!(a.num_limbs(), num_limbs);
+        assert_eq!(b.num_limbs(), num_limbs);
+
+        let q = self.assign_biguint(ctx, q_value, num_limbs)?;
+        let r = self.assign_biguint(ctx, r_value, num_limbs)?;
+
+        self.assert_product_equal(ctx, a, b, &q, n, &r)?;
+        Ok(r)
+    }
+
+    /// a * b == q * n + r 을 다항식 계수 + carry로 검사
+    fn assert_product_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedBigUint<F>,
+        b: &AssignedBigUint<F>,
+        q: &AssignedBigUint<F>,
+        n: &AssignedBigUint<F>,
+        r: &AssignedBigUint<F>,
+    ) -> Result<(), Error> {
+        let main_gate = &self.main_gate;
+        let num_limbs = n.num_limbs();
+        let num_coeffs = 2 * num_limbs - 1;
+        let carry_bits = carry_bits(num_limbs);
+        let carry_offset = BigInt::from(1) << (carry_bits - 1);
+        let carry_offset_fe = biguint_to_fe::<F>(carry_offset.magnitude());
+        let base = biguint_to_fe::<F>(&(BigUint::from(1u64) << LIMB_BITS));
+
+        // 계수별 곱 셀
+        let mut ab = vec![vec![]; num_coeffs];
+        let mut qn = vec![vec![]; num_coeffs];
+        for i in 0..num_limbs {
+            for j in 0..num_limbs {
+                ab[i + j].push(main_gate.mul(ctx, &a.limbs[i], &b.limbs[j])?);
+                qn[i + j].push(main_gate.mul(ctx, &q.limbs[i], &n.limbs[j])?);
+            }
+        }
+
+        // carry_k = (diff_k + carry_{k-1}) / 2^64, 음수일 수 있으므로 offset을 더해서 range check
+        let mut carry = Value::known(BigInt::from(0));
+        let mut prev_shifted: Option<AssignedValue<F>> = None;
+        for k in 0..num_coeffs {
+            let diff = coefficient_value(&ab[k]) - coefficient_value(&qn[k])
+                - if k < num_limbs { r.limbs[k].value().map(|v| fe_to_bigint(v)) } else { Value::known(BigInt::from(0)) };
+            let next = diff.zip(carry).map(|(d, c)| (d + c) >> LIMB_BITS);
+            carry = next.clone();
+
+            let mut terms: Vec<Term<F>> = vec![];
+            terms.extend(ab[k].iter().map(|c| Term::Assigned(c, F::ONE)));
+            terms.extend(qn[k].iter().map(|c| Term::Assigned(c, -F::ONE)));
+            if k < num_limbs {
+                terms.push(Term::Assigned(&r.limbs[k], -F::ONE));
+            }
+            // prev carry = prev_shifted - offset
+            let mut constant = F::ZERO;
+            if let Some(prev) = prev_shifted.as_ref() {
+                terms.push(Term::Assigned(prev, F::ONE));
+                constant -= carry_offset_fe;
+            }
+
+            let shifted;
+            if k + 1 < num_coeffs {
+                // carry = shifted - offset
+                let shifted_value = next.map(|c| biguint_to_fe::<F>((c + &carry_offset).magnitude()));
+                shifted = self.range.assign(ctx, shifted_value, RANGE_LIMB_BITS, carry_bits)?;
+                terms.push(Term::Assigned(&shifted, -base));
+                constant += carry_offset_fe * base;
+                let sum = main_gate.compose(ctx, &terms, constant)?;
+                main_gate.assert_zero(ctx, &sum)?;
+                prev_shifted = Some(shifted.clone());
+            } else {
+                // 마지막 carry는 0이어야 함
+                let sum = main_gate.compose(ctx, &terms, constant)?;
+                main_gate.assert_zero(ctx, &sum)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// s^65537 mod n (16번 제곱 + 1번 곱)
+    pub fn pow_public_exponent(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        s: &AssignedBigUint<F>,
+        n: &AssignedBigUint<F>,
+    ) -> Result<AssignedBigUint<F>, Error> {
+        let mut acc = s.clone();
+        for _ in 0..16 {
+            acc = self.mul_mod(ctx, &acc, &acc, n)?;
+        }
+        self.mul_mod(ctx, &acc, s, n)
+    }
+
+    /// RSASSA-PKCS1-v1_5 + SHA-256 검증
+    /// digest: SHA-256 gadget의 출력 (바이트 순서 bit stream)
+    pub fn verify_pkcs1v15_sha256(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        sig: &AssignedBigUint<F>,
+        n: &AssignedBigUint<F>,
+        digest: &[Bit<F>],
+    ) -> Result<(), Error> {
+        let em = self.pow_public_exponent(ctx, sig, n)?;
+        self.assert_encoded_message(ctx, &em, digest)
+    }
+
+    /// em의 모든 limb이 PKCS#1 v1.5 EM과 정확히 같은지 검사 (mul_mod의 soundness가 여기에 달려 있음)
+    fn assert_encoded_message(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        em: &AssignedBigUint<F>,
+        digest: &[Bit<F>],
+    ) -> Result<(), Error> {
+        assert_eq!(digest.len(), 256);
+        let num_limbs = em.num_limbs();
+
+        // EM의 하위 4 limb = digest (big-endian 정수)
+        let bitwise = BitwiseChip::new(self.main_gate.clone());
+        let digest_le: Vec<Bit<F>> = digest.chunks(8).rev().flat_map(|byte| byte.iter().cloned()).collect();
+        for (i, limb_bits) in digest_le.chunks(LIMB_BITS).enumerate() {
+            let composed = bitwise.compose(ctx, limb_bits)?;
+            self.main_gate.assert_equal(ctx, &em.limbs[i], &composed)?;
+        }
+
+        // 나머지 limb은 padding + DigestInfo 상수
+        let expected = pkcs1v15_prefix(num_limbs * LIMB_BITS / 8);
+        for i in 4..num_limbs {
+            let limb = (&expected >> (i * LIMB_BITS)) & limb_mask();
+            self.main_gate.assert_equal_to_constant(ctx, &em.limbs[i], biguint_to_fe(&limb))?;
+        }
+        Ok(())
+    }
+}
+
+/// EM = 0x00 || 0x01 || 0xff.. || 0x00 || DigestInfo || H 에서 H 자리를 0으로 둔 값
+pub fn pkcs1v15_prefix(modulus_bytes: usize) -> BigUint {
+    let mut em = vec![0x00, 0x01];
+    em.resize(modulus_bytes - SHA256_DIGEST_INFO.len() - 32 - 1, 0xff);
+    em.push(0x00);
+    em.extend_from_slice(&SHA256_DIGEST_INFO);
+    em.resize(modulus_bytes, 0x00);
+    BigUint::from_bytes_be(&em)
+}
+
+/// native PKCS#1 v1.5 인코딩 (테스트/witness 검증용)
+pub fn pkcs1v15_sha256_encode(digest: &[u8; 32], modulus_bytes: usize) -> BigUint {
+    pkcs1v15_prefix(modulus_bytes) + BigUint::from_bytes_be(digest)
+}
+
+/// carry range check 비트 수: 계수 하나는 num_limbs * 2^128 미만
+fn carry_bits(num_limbs: usize) -> usize {
+    let bits = LIMB_BITS + (usize::BITS - num_limbs.leading_zeros()) as usize + 2;
+    (bits + RANGE_LIMB_BITS - 1) / RANGE_LIMB_BITS * RANGE_LIMB_BITS
+}
+
+fn coefficient_value<F: PrimeField>(cells: &[AssignedValue<F>]) -> Value<BigInt> {
+    cells
+        .iter()
+        .fold(Value::known(BigInt::from(0)), |acc, c| acc.zip(c.value()).map(|(acc, v)| acc + fe_to_bigint(v)))
+}
+
+fn limb_mask() -> BigUint {
+    (BigUint::from(1u64) << LIMB_BITS) - 1u64
+}
+
+pub fn biguint_to_fe<F: PrimeField>(value: &BigUint) -> F {
+    let mut repr = F::Repr::default();
+    let bytes = value.to_bytes_le();
+    repr.as_mut()[..bytes.len()].copy_from_slice(&bytes);
+    F::from_repr(repr).unwrap()
+}
+
+fn fe_to_bigint<F: PrimeField>(value: &F) -> BigInt {
+    BigInt::from_bytes_le(Sign::Plus, value.to_repr().as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::bitwise::bytes_to_bits;
+    use crate::test_utils::{assert_constraint_failure, mock_prove};
+    use halo2::circuit::SimpleFloorPlanner;
+    use halo2::plonk::Circuit;
+    use halo2curves::bn256::Fr;
+    use rand_core::OsRng;
+    use rsa::traits::PublicKeyParts;
+    use rsa::{Pkcs1v15Sign, RsaPrivateKey};
+    use sha2::{Digest, Sha256};
+
+    const MODULUS_BITS: usize = 2048;
+    const NUM_LIMBS: usize = MODULUS_BITS / LIMB_BITS;
+
+    /// RS256 서명 검증. non_canonical이면 마지막 곱셈의 나머지를 r + n으로 둠
+    #[derive(Clone)]
+    struct RsaCircuit {
+        digest: [u8; 32],
+        signature: BigUint,
+        modulus: BigUint,
+        non_canonical: bool,
+    }
+
+    impl Circuit<Fr> for RsaCircuit {
+        type Config = RsaConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            RsaChip::configure(meta)
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<Fr>) -> Result<(), Error> {
+            let chip = RsaChip::construct(config);
+            chip.load_table(&mut layouter)?;
+            layouter.assign_region(
+                || "rs256",
+                |region| {
+                    let mut ctx = RegionCtx::new(region, 0);
+                    let n = chip.assign_biguint(&mut ctx, Value::known(self.modulus.clone()), NUM_LIMBS)?;
+                    let s = chip.assign_biguint(&mut ctx, Value::known(self.signature.clone()), NUM_LIMBS)?;
+                    let bytes = self
+                        .digest
+                        .iter()
+                        .map(|b| chip.main_gate().assign_value(&mut ctx, Value::known(Fr::from(*b as u64))))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let digest = bytes_to_bits(&BitwiseChip::new(chip.main_gate().clone()), &mut ctx, &bytes)?;
+
+                    if !self.non_canonical {
+                        return chip.verify_pkcs1v15_sha256(&mut ctx, &s, &n, &digest);
+                    }
+                    let mut acc = s.clone();
+                    for _ in 0..16 {
+                        acc = chip.mul_mod(&mut ctx, &acc, &acc, &n)?;
+                    }
+                    let product = acc.value().zip(s.value()).map(|(a, b)| a * b);
+                    let q = product.clone().map(|p| p / &self.modulus - 1u64);
+                    let r = product.map(|p| p % &self.modulus + &self.modulus);
+                    let em = chip.mul_mod_with(&mut ctx, &acc, &s, &n, q, r)?;
+                    chip.assert_encoded_message(&mut ctx, &em, &digest)
+                },
+            )
+        }
+    }
+
+    fn keypair() -> (RsaPrivateKey, BigUint) {
+        let key = RsaPrivateKey::new(&mut OsRng, MODULUS_BITS).unwrap();
+        let modulus = BigUint::from_bytes_be(&key.n().to_bytes_be());
+        (key, modulus)
+    }
+
+    fn sign(key: &RsaPrivateKey, message: &[u8]) -> ([u8; 32], BigUint) {
+        let digest: [u8; 32] = Sha256::digest(message).into();
+        let signature = key.sign(Pkcs1v15Sign::new::<Sha256>(), &digest).unwrap();
+        (digest, BigUint::from_bytes_be(&signature))
+    }
+
+    #[test]
+    fn accepts_rs256_signature() {
+        let (key, modulus) = keypair();
+        let (digest, signature) = sign(&key, b"header.payload");
+        assert_eq!(signature.modpow(&BigUint::from(PUBLIC_EXPONENT), &modulus), pkcs1v15_sha256_encode(&digest, 256));
+        let circuit = RsaCircuit { digest, signature, modulus, non_canonical: false };
+        assert_eq!(mock_prove(&circuit, vec![]), Ok(()));
+    }
+
+    #[test]
+    fn rejects_tampered_signature() {
+        let (key, modulus) = keypair();
+        let (digest, signature) = sign(&key, b"header.payload");
+        let circuit = RsaCircuit { digest, signature: &signature + 1u64, modulus, non_canonical: false };
+        assert_constraint_failure(mock_prove(&circuit, vec![]));
+
+        // 다른 메시지의 digest
+        let (other_digest, _) = sign(&key, b"header.other");
+        let circuit = RsaCircuit { digest: other_digest, signature, ..circuit };
+        assert_constraint_failure(mock_prove(&circuit, vec![]));
+    }
+
+    #[test]
+    fn rejects_wrong_modulus() {
+        let (key, _) = keypair();
+        let (_, other_modulus) = keypair();
+        let (digest, signature) = sign(&key, b"header.payload");
+        let circuit = RsaCircuit { digest, signature, modulus: other_modulus, non_canonical: false };
+        assert_constraint_failure(mock_prove(&circuit, vec![]));
+    }
+
+    #[test]
+    fn rejects_non_canonical_result() {
+        // mul_mod 자체는 r + n을 받아들이므로 마지막 EM 비교가 이를 막아야 함
+        let (key, modulus) = keypair();
+        let (digest, signature) = sign(&key, b"header.payload");
+        let em = pkcs1v15_sha256_encode(&digest, 256);
+        assert!((&em + &modulus).bits() as usize <= MODULUS_BITS, "r + n must fit the limbs for this test");
+        let circuit = RsaCircuit { digest, signature, modulus, non_canonical: true };
+        assert_constraint_failure(mock_prove(&circuit, vec![]));
+    }
+}
@@ -0,0 +1,399 @@
+use halo2::plonk::{ConstraintSystem, Error};
+use halo2curves::ff::PrimeField;
+use maingate::{AssignedValue, MainGate, MainGateConfig, MainGateInstructions, RegionCtx, Term};
+
+use crate::gadgets::bitwise::{Bit, BitwiseChip};
+use crate::gadgets::keccak::constant_bits;
+
+/// 블록 크기 (바이트 단위)
+pub const BLOCK_BYTES: usize = 64;
+/// digest 길이 (bit 단위)
+pub const DIGEST_BITS: usize = 256;
+/// 마지막 블록 끝에 붙는 메시지 bit 길이 필드 (바이트 단위)
+const LENGTH_BYTES: usize = 8;
+
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const INITIAL_STATE: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// 32비트 word, little-endian bit 순서
+type Word<F> = Vec<Bit<F>>;
+
+#[derive(Clone, Debug)]
+pub struct Sha256Config {
+    pub main_gate: MainGateConfig,
+}
+
+/// bit 단위 SHA-256
+/// 입출력은 keccak gadget과 같은 바이트 순서 bit stream (각 바이트 LSB 먼저)이라서
+/// digest를 그대로 `SignatureChip::digest_to_msg_hash`에 넘길 수 있음
+#[derive(Clone, Debug)]
+pub struct Sha256Chip<F: PrimeField> {
+    bitwise: BitwiseChip<F>,
+}
+
+impl<F: PrimeField> Sha256Chip<F> {
+    pub fn construct(config: Sha256Config) -> Self {
+        Self { bitwise: BitwiseChip::new(MainGate::new(config.main_gate)) }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> Sha256Config {
+        Sha256Config { main_gate: MainGate::configure(meta) }
+    }
+
+    pub fn bitwise(&self) -> &BitwiseChip<F> {
+        &self.bitwise
+    }
+
+    /// 고정 길이 입력의 SHA-256. padding은 상수 bit으로 들어감
+    pub fn hash_bits(&self, ctx: &mut RegionCtx<'_, F>, input: &[Bit<F>]) -> Result<Vec<Bit<F>>, Error> {
+        assert_eq!(input.len() % 8, 0, "sha256 input must be byte aligned");
+        let mut padded = input.to_vec();
+        padded.extend(constant_bits(&padding(input.len() / 8)));
+
+        let mut state = initial_state();
+        for block in padded.chunks(BLOCK_BYTES * 8) {
+            state = self.compress(ctx, &state, block)?;
+        }
+        Ok(state_to_digest(&state))
+    }
+
+    /// 가변 길이 입력의 SHA-256 (JWT, passport data group 등)
+    /// bytes: 최대 길이만큼 할당된 바이트 셀 (len 이후 값은 무시됨), len: 실제 바이트 길이
+    pub fn hash_var_bytes(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        bytes: &[AssignedValue<F>],
+        len: &AssignedValue<F>,
+    ) -> Result<Vec<Bit<F>>, Error> {
+        let main_gate = self.bitwise.main_gate();
+        let max_len = bytes.len();
+        let max_blocks = (max_len + LENGTH_BYTES) / BLOCK_BYTES + 1;
+
+        // len의 one-hot 표현: is_len[j] = (len == j), 합이 1이므로 len <= max_len
+        let mut is_len = Vec::with_capacity(max_len + 1);
+        for j in 0..=max_len {
+            let j = main_gate.assign_constant(ctx, F::from(j as u64))?;
+            is_len.push(main_gate.is_equal(ctx, len, &j)?);
+        }
+        let terms: Vec<Term<F>> = is_len.iter().map(|c| Term::Assigned(c, F::ONE)).collect();
+        let sum = main_gate.compose(ctx, &terms, F::ZERO)?;
+        main_gate.assert_one(ctx, &sum)?;
+
+        // is_final[b]: 마지막 블록 = (len + 8) / 64
+        let mut is_final = Vec::with_capacity(max_blocks);
+        for b in 0..max_blocks {
+            let terms: Vec<Term<F>> = (0..=max_len)
+                .filter(|j| (j + LENGTH_BYTES) / BLOCK_BYTES == b)
+                .map(|j| Term::Assigned(&is_len[j], F::ONE))
+                .collect();
+            is_final.push(if terms.is_empty() {
+                main_gate.assign_constant(ctx, F::ZERO)?
+            } else {
+                main_gate.compose(ctx, &terms, F::ZERO)?
+            });
+        }
+
+        // bit 길이 (len * 8)의 big-endian 바이트
+        let bit_len = main_gate.compose(ctx, &[Term::Assigned(len, F::from(8))], F::ZERO)?;
+        let bit_len_bits = self.bitwise.to_bits(ctx, &bit_len, LENGTH_BYTES * 8)?;
+        let length_bytes = bit_len_bits
+            .chunks(8)
+            .rev()
+            .map(|byte| self.bitwise.compose(ctx, byte))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // padding: byte_i = (i < len) * m_i + (i == len) * 0x80 + (마지막 블록의 길이 필드)
+        let mut after_len = main_gate.assign_constant(ctx, F::ZERO)?;
+        let mut padded = Vec::with_capacity(max_blocks * BLOCK_BYTES * 8);
+        for i in 0..max_blocks * BLOCK_BYTES {
+            if i <= max_len {
+                after_len = main_gate.add(ctx, &after_len, &is_len[i])?;
+            }
+            let mut terms = vec![];
+            let masked;
+            if i < max_len {
+                let before_len = main_gate.not(ctx, &after_len)?;
+                masked = main_gate.mul(ctx, &before_len, &bytes[i])?;
+                terms.push(Term::Assigned(&masked, F::ONE));
+            }
+            if i <= max_len {
+                terms.push(Term::Assigned(&is_len[i], F::from(0x80)));
+            }
+            let length_byte;
+            let offset = i % BLOCK_BYTES;
+            if offset >= BLOCK_BYTES - LENGTH_BYTES {
+                let k = offset - (BLOCK_BYTES - LENGTH_BYTES);
+                length_byte = main_gate.mul(ctx, &is_final[i / BLOCK_BYTES], &length_bytes[k])?;
+                terms.push(Term::Assigned(&length_byte, F::ONE));
+            }
+            let byte = if terms.is_empty() {
+                main_gate.assign_constant(ctx, F::ZERO)?
+            } else {
+                main_gate.compose(ctx, &terms, F::ZERO)?
+            };
+            padded.extend(self.bitwise.to_bits(ctx, &byte, 8)?);
+        }
+
+        // 모든 블록을 압축하면서 마지막 블록 직후의 digest를 선택
+        let mut state = initial_state();
+        let mut digest: Vec<Bit<F>> = vec![Bit::Constant(false); DIGEST_BITS];
+        for (b, block) in padded.chunks(BLOCK_BYTES * 8).enumerate() {
+            state = self.compress(ctx, &state, block)?;
+            let candidate = state_to_digest(&state);
+            let selected = Bit::Assigned(is_final[b].clone());
+            for (d, c) in digest.iter_mut().zip(candidate.iter()) {
+                // 선택되는 블록은 하나뿐이므로 xor로 누적해도 선택과 같음
+                let picked = self.bitwise.and(ctx, &selected, c)?;
+                *d = self.bitwise.xor(ctx, d, &picked)?;
+            }
+        }
+        Ok(digest)
+    }
+
+    /// 한 블록 압축 함수
+    fn compress(&self, ctx: &mut RegionCtx<'_, F>, state: &[Word<F>], block: &[Bit<F>]) -> Result<Vec<Word<F>>, Error> {
+        // message schedule
+        let mut w: Vec<Word<F>> = block.chunks(32).map(be_bytes_to_word).collect();
+        for t in 16..64 {
+            let s0 = self.xor3(ctx, &rotr(&w[t - 15], 7), &rotr(&w[t - 15], 18), &shr(&w[t - 15], 3))?;
+            let s1 = self.xor3(ctx, &rotr(&w[t - 2], 17), &rotr(&w[t - 2], 19), &shr(&w[t - 2], 10))?;
+            let next = self.add(ctx, &[&s1, &w[t - 7], &s0, &w[t - 16]], 0)?;
+            w.push(next);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h]: [Word<F>; 8] =
+            state.to_vec().try_into().unwrap();
+        for t in 0..64 {
+            let big_s1 = self.xor3(ctx, &rotr(&e, 6), &rotr(&e, 11), &rotr(&e, 25))?;
+            let ch = self.ch(ctx, &e, &f, &g)?;
+            let t1 = self.add(ctx, &[&h, &big_s1, &ch, &w[t]], ROUND_CONSTANTS[t])?;
+            let big_s0 = self.xor3(ctx, &rotr(&a, 2), &rotr(&a, 13), &rotr(&a, 22))?;
+            let maj = self.maj(ctx, &a, &b, &c)?;
+
+            h = g;
+            g = f;
+            f = e;
+            e = self.add(ctx, &[&d, &t1], 0)?;
+            d = c;
+            c = b;
+            b = a;
+            a = self.add(ctx, &[&t1, &big_s0, &maj], 0)?;
+        }
+
+        let out = [a, b, c, d, e, f, g, h];
+        state
+            .iter()
+            .zip(out.iter())
+            .map(|(prev, cur)| self.add(ctx, &[prev, cur], 0))
+            .collect()
+    }
+
+    fn xor3(&self, ctx: &mut RegionCtx<'_, F>, x: &[Bit<F>], y: &[Bit<F>], z: &[Bit<F>]) -> Result<Word<F>, Error> {
+        let mut out = Vec::with_capacity(32);
+        for i in 0..32 {
+            let t = self.bitwise.xor(ctx, &x[i], &y[i])?;
+            out.push(self.bitwise.xor(ctx, &t, &z[i])?);
+        }
+        Ok(out)
+    }
+
+    /// ch(e, f, g) = (e & f) ^ (!e & g)
+    fn ch(&self, ctx: &mut RegionCtx<'_, F>, e: &[Bit<F>], f: &[Bit<F>], g: &[Bit<F>]) -> Result<Word<F>, Error> {
+        let mut out = Vec::with_capacity(32);
+        for i in 0..32 {
+            let ef = self.bitwise.and(ctx, &e[i], &f[i])?;
+            let eg = self.bitwise.and_not(ctx, &e[i], &g[i])?;
+            out.push(self.bitwise.xor(ctx, &ef, &eg)?);
+        }
+        Ok(out)
+    }
+
+    /// maj(a, b, c) = (a & b) ^ (a & c) ^ (b & c)
+    fn maj(&self, ctx: &mut RegionCtx<'_, F>, a: &[Bit<F>], b: &[Bit<F>], c: &[Bit<F>]) -> Result<Word<F>, Error> {
+        let mut out = Vec::with_capacity(32);
+        for i in 0..32 {
+            let ab = self.bitwise.and(ctx, &a[i], &b[i])?;
+            let ac = self.bitwise.and(ctx, &a[i], &c[i])?;
+            let bc = self.bitwise.and(ctx, &b[i], &c[i])?;
+            let t = self.bitwise.xor(ctx, &ab, &ac)?;
+            out.push(self.bitwise.xor(ctx, &t, &bc)?);
+        }
+        Ok(out)
+    }
+
+    /// (sum(words) + constant) mod 2^32
+    /// 합을 32 + carry 비트로 분해하고 하위 32비트만 사용
+    fn add(&self, ctx: &mut RegionCtx<'_, F>, words: &[&Word<F>], constant: u32) -> Result<Word<F>, Error> {
+        let values = words
+            .iter()
+            .map(|w| self.bitwise.compose(ctx, w))
+            .collect::<Result<Vec<_>, _>>()?;
+        let terms: Vec<Term<F>> = values.iter().map(|v| Term::Assigned(v, F::ONE)).collect();
+        let sum = self.bitwise.main_gate().compose(ctx, &terms, F::from(constant as u64))?;
+        let carry_bits = usize::BITS as usize - words.len().leading_zeros() as usize;
+        let mut bits = self.bitwise.to_bits(ctx, &sum, 32 + carry_bits)?;
+        bits.truncate(32);
+        Ok(bits)
+    }
+}
+
+fn rotr<F: PrimeField>(x: &[Bit<F>], n: usize) -> Word<F> {
+    (0..32).map(|i| x[(i + n) % 32].clone()).collect()
+}
+
+fn shr<F: PrimeField>(x: &[Bit<F>], n: usize) -> Word<F> {
+    (0..32)
+        .map(|i| if i + n < 32 { x[i + n].clone() } else { Bit::Constant(false) })
+        .collect()
+}
+
+fn constant_word<F: PrimeField>(value: u32) -> Word<F> {
+    (0..32).map(|i| Bit::Constant((value >> i) & 1 == 1)).collect()
+}
+
+fn initial_state<F: PrimeField>() -> Vec<Word<F>> {
+    INITIAL_STATE.iter().map(|v| constant_word(*v)).collect()
+}
+
+/// 4바이트(bit stream) → big-endian word
+fn be_bytes_to_word<F: PrimeField>(bits: &[Bit<F>]) -> Word<F> {
+    bits.chunks(8).rev().flat_map(|byte| byte.iter().cloned()).collect()
+}
+
+/// state word들을 big-endian 바이트 순서의 bit stream으로
+fn state_to_digest<F: PrimeField>(state: &[Word<F>]) -> Vec<Bit<F>> {
+    state.iter().flat_map(|word| be_bytes_to_word(word)).collect()
+}
+
+/// 고정 길이 메시지의 padding 바이트: 0x80 || 0x00* || bit 길이(64비트 big-endian)
+pub fn padding(message_len: usize) -> Vec<u8> {
+    let mut pad = vec![0x80];
+    while (message_len + pad.len()) % BLOCK_BYTES != BLOCK_BYTES - LENGTH_BYTES {
+        pad.push(0);
+    }
+    pad.extend_from_slice(&((message_len as u64) * 8).to_be_bytes());
+    pad
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::bitwise::{bytes_to_bits, pack_bits, pack_bytes};
+    use crate::test_utils::{assert_permutation_failure, mock_prove};
+    use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::plonk::{Circuit, Column, Instance};
+    use halo2curves::bn256::Fr;
+    use sha2::{Digest, Sha256};
+
+    /// NIST FIPS 180-2 예제 메시지
+    const NIST_448: &[u8] = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+    const NIST_896: &[u8] = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+
+    /// input의 SHA-256 digest를 pack_bits로 묶어 public input에 노출
+    /// max_len이 있으면 hash_var_bytes (max_len까지 0xaa로 채움), 없으면 hash_bits
+    #[derive(Clone, Default)]
+    struct Sha256Circuit {
+        input: Vec<u8>,
+        max_len: Option<usize>,
+    }
+
+    impl Circuit<Fr> for Sha256Circuit {
+        type Config = (Sha256Config, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self { input: vec![0; self.input.len()], max_len: self.max_len }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+            (Sha256Chip::configure(meta), instance)
+        }
+
+        fn synthesize(&self, (config, instance): Self::Config, mut layouter: impl Layouter<Fr>) -> Result<(), Error> {
+            let chip = Sha256Chip::construct(config);
+            let digest = layouter.assign_region(
+                || "sha256",
+                |region| {
+                    let mut ctx = RegionCtx::new(region, 0);
+                    let bitwise = chip.bitwise();
+                    let mut input = self.input.clone();
+                    if let Some(max_len) = self.max_len {
+                        input.resize(max_len, 0xaa);
+                    }
+                    let bytes = input
+                        .iter()
+                        .map(|b| bitwise.main_gate().assign_value(&mut ctx, Value::known(Fr::from(*b as u64))))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let digest = match self.max_len {
+                        None => chip.hash_bits(&mut ctx, &bytes_to_bits(bitwise, &mut ctx, &bytes)?)?,
+                        Some(_) => {
+                            let len = Value::known(Fr::from(self.input.len() as u64));
+                            let len = bitwise.main_gate().assign_value(&mut ctx, len)?;
+                            chip.hash_var_bytes(&mut ctx, &bytes, &len)?
+                        }
+                    };
+                    pack_bits(bitwise, &mut ctx, &digest)
+                },
+            )?;
+            for (i, cell) in digest.iter().enumerate() {
+                layouter.constrain_instance(cell.cell(), instance, i)?;
+            }
+            Ok(())
+        }
+    }
+
+    fn digest_instances(input: &[u8]) -> Vec<Vec<Fr>> {
+        vec![pack_bytes(&Sha256::digest(input))]
+    }
+
+    #[test]
+    fn matches_nist_vectors() {
+        for input in [&b""[..], b"abc", NIST_448, NIST_896] {
+            let circuit = Sha256Circuit { input: input.to_vec(), max_len: None };
+            assert_eq!(mock_prove(&circuit, digest_instances(input)), Ok(()), "{:?}", String::from_utf8_lossy(input));
+        }
+    }
+
+    #[test]
+    fn var_length_matches_sha256_at_padding_boundaries() {
+        // 55: 길이 필드까지 한 블록, 56: 길이 필드가 다음 블록으로, 64: 블록을 꽉 채움
+        for len in [0, 55, 56, 64] {
+            let input: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let circuit = Sha256Circuit { input: input.clone(), max_len: Some(BLOCK_BYTES) };
+            assert_eq!(mock_prove(&circuit, digest_instances(&input)), Ok(()), "len {len}");
+        }
+    }
+
+    #[test]
+    fn fixed_padding_boundaries() {
+        for (len, blocks) in [(0, 1), (55, 1), (56, 2), (64, 2), (119, 2), (120, 3)] {
+            assert_eq!((len + padding(len).len()) / BLOCK_BYTES, blocks, "len {len}");
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_digest() {
+        let circuit = Sha256Circuit { input: b"abc".to_vec(), max_len: None };
+        let mut digest = Sha256::digest(b"abc");
+        digest[31] ^= 0x80;
+        assert_permutation_failure(mock_prove(&circuit, vec![pack_bytes(&digest)]));
+
+        // 가변 길이: max_len 안의 다른 메시지 digest와는 맞지 않음
+        let circuit = Sha256Circuit { input: b"abc".to_vec(), max_len: Some(BLOCK_BYTES) };
+        assert_permutation_failure(mock_prove(&circuit, digest_instances(b"ab")));
+    }
+}
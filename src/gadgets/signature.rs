@@ -12,22 +12,57 @@ use ecc::maingate::RegionCtx;
 use integer::Range;
 use integer::UnassignedInteger;
 use ecc::AssignedPoint;
+use halo2curves::group::{Curve, Group};
+use maingate::{AssignedCondition, AssignedValue, MainGateInstructions, Term};
+use num_bigint::BigUint;
+use crate::gadgets::bitwise::{Bit, BitwiseChip};
 
 
 
 /// SignatureConfig: ECDSA gadget의 config를 그대로 사용
 pub type SignatureConfig = EcdsaConfig;
 
+/// ES256 (P-256 ECDSA). JWT/OIDC issuer 서명 검증용
+pub type Es256SignatureChip<F> = SignatureChip<halo2curves::secp256r1::Secp256r1Affine, F, 4, 68>;
+
+/// scalar mul 윈도우 크기
+pub const WINDOW_SIZE: usize = 4;
+/// aux generator = G * AUX_GENERATOR_SEED
+const AUX_GENERATOR_SEED: u64 = 0x5eed;
+/// 고정 base 테이블의 윈도우 k 항목에 더하는 offset = G * TABLE_OFFSET_SEED * (k + 1)
+/// 테이블 항목과 누적값이 무한원점이 되지 않게 함
+const TABLE_OFFSET_SEED: u64 = 0x7ab1e;
+
 /// SignatureChip: 내부적으로 ecdsa의 EcdsaChip을 래핑
 pub struct SignatureChip<E: halo2curves::CurveAffine, F: PrimeField, const LIMBS: usize, const BITS: usize> {
     chip: EcdsaChip<E, F, LIMBS, BITS>,
 }
 
-impl<E: halo2curves::CurveAffine<ScalarExt = F>, F: PrimeField, const LIMBS: usize, const BITS: usize> SignatureChip<E, F, LIMBS, BITS> {
+/// E: 서명 곡선 (BN254 G1, secp256k1 등), F: 회로의 native field
+impl<E: halo2curves::CurveAffine, F: PrimeField, const LIMBS: usize, const BITS: usize> SignatureChip<E, F, LIMBS, BITS> {
     pub fn new(chip: EcdsaChip<E, F, LIMBS, BITS>) -> Self {
         Self { chip }
     }
 
+    /// aux generator / window 테이블을 region에 할당하고 chip 생성
+    /// EcdsaChip::verify 내부의 scalar mul이 aux 값을 사용하므로 verify 전에 필요
+    pub fn construct(
+        ctx: &mut RegionCtx<'_, F>,
+        config: &SignatureConfig,
+    ) -> Result<Self, Error> {
+        let mut ecc_chip = GeneralEccChip::<E, F, LIMBS, BITS>::new(config.ecc_chip_config());
+        ecc_chip.assign_aux_generator(ctx, Value::known(Self::aux_generator()))?;
+        ecc_chip.assign_aux(ctx, WINDOW_SIZE, 2)?;
+        // batch_validity의 P 하나짜리 scalar mul용
+        ecc_chip.assign_aux(ctx, WINDOW_SIZE, 1)?;
+        Ok(Self::new(EcdsaChip::new(ecc_chip)))
+    }
+
+    /// 고정된 aux generator (witness라서 랜덤일 필요는 없음)
+    pub fn aux_generator() -> E {
+        (E::CurveExt::generator() * E::Scalar::from(AUX_GENERATOR_SEED)).to_affine()
+    }
+
     pub fn configure(meta: &mut ConstraintSystem<F>) -> SignatureConfig {
         // MainGateConfig, RangeConfig 등은 회로에서 생성해서 넘겨줘야 함
         let main_gate_config = MainGate::configure(meta);
@@ -70,16 +105,112 @@ impl<E: halo2curves::CurveAffine<ScalarExt = F>, F: PrimeField, const LIMBS: usi
         Ok(AssignedPublicKey { point })
     }
 
+    /// 서명 region 안에서 보조 값을 할당할 때 쓰는 main gate
+    pub fn main_gate(&self) -> MainGate<F> {
+        self.chip.ecc_chip().main_gate()
+    }
+
+    /// issuer key hash 입력 셀: x limb, y limb 순서 (`issuer::ecdsa_key_limbs`와 같은 값)
+    pub fn public_key_limbs(pk: &AssignedPublicKey<E::Base, F, LIMBS, BITS>) -> Vec<AssignedValue<F>> {
+        let (x, y) = (pk.point.x(), pk.point.y());
+        (0..LIMBS).map(|i| x.limb(i)).chain((0..LIMBS).map(|i| y.limb(i))).collect()
+    }
+
     pub fn assign_integer(
         &self,
         ctx: &mut RegionCtx<'_, F>,
-        value: F,
+        value: E::Scalar,
     ) -> Result<AssignedInteger<E::Scalar, F, LIMBS, BITS>, Error> {
         let scalar_chip = self.chip.scalar_field_chip();
         let rns = scalar_chip.rns(); // 또는 self.chip.range(), config.range 등
         scalar_chip.assign_integer(ctx, UnassignedInteger::from(Value::known(Integer::from_fe(value, rns.clone()))), Range::Operand)
     }
 
+    /// 해시 digest (바이트 순서 bit stream, 각 바이트 LSB 먼저) → msg_hash
+    /// ECDSA 규약대로 digest를 big-endian 정수로 해석하고 scalar field로 reduce
+    /// digest가 Range::Remainder 범위에 들어가야 하므로 256비트 scalar field 곡선만 지원
+    pub fn digest_to_msg_hash(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        bitwise: &BitwiseChip<F>,
+        digest: &[Bit<F>],
+    ) -> Result<AssignedInteger<E::Scalar, F, LIMBS, BITS>, Error> {
+        assert!(E::Scalar::NUM_BITS as usize >= digest.len(), "digest does not fit the scalar field");
+        // little-endian bit 순서로 변환
+        let bits: Vec<Bit<F>> = digest
+            .chunks(8)
+            .rev()
+            .flat_map(|byte| byte.iter().cloned())
+            .collect();
+
+        let value = bits.iter().rev().fold(Value::known(BigUint::from(0u64)), |acc, bit| {
+            acc.zip(bit.value()).map(|(acc, bit)| (acc << 1) + BigUint::from(bit as u64))
+        });
+        let scalar_chip = self.chip.scalar_field_chip();
+        let rns = scalar_chip.rns();
+        let z = scalar_chip.assign_integer(
+            ctx,
+            UnassignedInteger::from(value.map(|v| Integer::from_big(v, rns.clone()))),
+            Range::Remainder,
+        )?;
+
+        // limb들이 digest bit의 합성과 같아야 함
+        for (i, limb_bits) in bits.chunks(BITS).enumerate() {
+            let composed = bitwise.compose(ctx, limb_bits)?;
+            bitwise.main_gate().assert_equal(ctx, &z.limb(i), &composed)?;
+        }
+        for i in (bits.len() + BITS - 1) / BITS..LIMBS {
+            bitwise.main_gate().assert_zero(ctx, &z.limb(i))?;
+        }
+        scalar_chip.reduce(ctx, &z)
+    }
+
+    /// native field 값 → msg_hash. 정수 값이 같도록 limb를 value의 canonical bit 분해로 고정
+    /// native 값만 비교하면 value + k * |F| 같은 정수도 통과하므로 bit 열이 |F| - 1 이하임을 확인함
+    /// scalar field가 native field보다 작지 않은 곡선만 지원
+    pub fn native_to_msg_hash(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        value: &AssignedValue<F>,
+    ) -> Result<AssignedInteger<E::Scalar, F, LIMBS, BITS>, Error> {
+        assert!(E::Scalar::NUM_BITS >= F::NUM_BITS, "native field does not fit the scalar field");
+        let main_gate = self.chip.ecc_chip().main_gate();
+        let num_bits = F::NUM_BITS as usize;
+        let bits = main_gate.to_bits(ctx, value, num_bits)?;
+
+        // 위 bit부터 보면서 지금까지 |F| - 1과 같은지(eq) 추적
+        // |F| - 1의 bit이 0인 자리에서 eq인 채로 1이 나오면 |F| - 1보다 큼
+        let max = (-F::ONE).to_repr();
+        let max_bit = |i: usize| (max.as_ref()[i / 8] >> (i % 8)) & 1 == 1;
+        let mut eq = main_gate.assign_constant(ctx, F::ONE)?;
+        for i in (0..num_bits).rev() {
+            if max_bit(i) {
+                eq = main_gate.and(ctx, &eq, &bits[i])?;
+            } else {
+                let exceeds = main_gate.and(ctx, &eq, &bits[i])?;
+                main_gate.assert_zero(ctx, &exceeds)?;
+            }
+        }
+
+        let scalar_chip = self.chip.scalar_field_chip();
+        let rns = scalar_chip.rns();
+        let integer = value.value().map(|v| Integer::from_big(BigUint::from_bytes_le(v.to_repr().as_ref()), rns.clone()));
+        let z = scalar_chip.assign_integer(ctx, UnassignedInteger::from(integer), Range::Remainder)?;
+        for (i, limb_bits) in bits.chunks(BITS).enumerate() {
+            let terms: Vec<Term<F>> = limb_bits
+                .iter()
+                .enumerate()
+                .map(|(j, bit)| Term::Assigned(bit, F::from(2).pow_vartime([j as u64])))
+                .collect();
+            let composed = main_gate.compose(ctx, &terms, F::ZERO)?;
+            main_gate.assert_equal(ctx, &z.limb(i), &composed)?;
+        }
+        for i in (num_bits + BITS - 1) / BITS..LIMBS {
+            main_gate.assert_zero(ctx, &z.limb(i))?;
+        }
+        Ok(z)
+    }
+
     pub fn verify(
         &self,
         ctx: &mut RegionCtx<'_, F>,
@@ -89,8 +220,182 @@ impl<E: halo2curves::CurveAffine<ScalarExt = F>, F: PrimeField, const LIMBS: usi
     ) -> Result<(), Error> {
         self.chip.verify(ctx, sig, pk, msg_hash)
     }
+
+    /// 여러 issuer 서명을 한 region에서 모두 검증 (multi-attestation)
+    /// u1 * G는 모든 서명이 공유하는 상수 테이블로 계산하므로 서명마다 generator 테이블을 만들지 않음
+    pub fn verify_batch(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        items: &[SignatureItem<E, F, LIMBS, BITS>],
+    ) -> Result<(), Error> {
+        let main_gate = self.chip.ecc_chip().main_gate();
+        for valid in self.batch_validity(ctx, items)? {
+            main_gate.assert_one(ctx, &valid)?;
+        }
+        Ok(())
+    }
+
+    /// n개 중 적어도 threshold개의 서명이 유효함을 검증 (k-of-n)
+    /// 서명하지 않은 issuer 자리에는 임의의 (r, s)를 넣으면 됨
+    /// 같은 키로 여러 자리를 채울 수 없도록 공개키는 서로 달라야 함
+    /// 서명별 유효 bit을 돌려줌
+    pub fn verify_threshold(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        items: &[SignatureItem<E, F, LIMBS, BITS>],
+        threshold: usize,
+    ) -> Result<Vec<AssignedCondition<F>>, Error> {
+        // 만족할 수 없는 threshold
+        if threshold > items.len() {
+            return Err(Error::Synthesis);
+        }
+        let main_gate = self.chip.ecc_chip().main_gate();
+        self.assert_distinct_keys(ctx, items)?;
+        let validity = self.batch_validity(ctx, items)?;
+
+        // count ∈ [0, n] 이므로 count ∉ {0, .., threshold - 1} 이면 count >= threshold
+        let terms: Vec<Term<F>> = validity.iter().map(|v| Term::Assigned(v, F::ONE)).collect();
+        let count = main_gate.compose(ctx, &terms, F::ZERO)?;
+        let mut product = main_gate.assign_constant(ctx, F::ONE)?;
+        for j in 0..threshold {
+            let diff = main_gate.sub_with_constant(ctx, &count, F::from(j as u64))?;
+            product = main_gate.mul(ctx, &product, &diff)?;
+        }
+        main_gate.assert_not_zero(ctx, &product)?;
+        Ok(validity)
+    }
+
+    /// 공개키 (x, y)가 쌍마다 다름
+    /// 좌표를 field 범위로 제한해야 같은 점의 다른 limb 표현으로 우회할 수 없음
+    fn assert_distinct_keys(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        items: &[SignatureItem<E, F, LIMBS, BITS>],
+    ) -> Result<(), Error> {
+        let ecc_chip = self.chip.ecc_chip();
+        let base_chip = ecc_chip.base_field_chip();
+        let main_gate = ecc_chip.main_gate();
+        for (_, pk, _) in items {
+            base_chip.assert_in_field(ctx, pk.point.x())?;
+            base_chip.assert_in_field(ctx, pk.point.y())?;
+        }
+        for (i, (_, a, _)) in items.iter().enumerate() {
+            for (_, b, _) in &items[i + 1..] {
+                let same_x = base_chip.is_strict_equal(ctx, a.point.x(), b.point.x())?;
+                let same_y = base_chip.is_strict_equal(ctx, a.point.y(), b.point.y())?;
+                let same = main_gate.and(ctx, &same_x, &same_y)?;
+                main_gate.assert_zero(ctx, &same)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 서명마다 R = u1 * G + u2 * P 를 계산하고 R.x == r 이면 1
+    fn batch_validity(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        items: &[SignatureItem<E, F, LIMBS, BITS>],
+    ) -> Result<Vec<AssignedCondition<F>>, Error> {
+        let ecc_chip = self.chip.ecc_chip();
+        let scalar_chip = ecc_chip.scalar_field_chip();
+        let base_chip = ecc_chip.base_field_chip();
+        let main_gate = ecc_chip.main_gate();
+        let table = self.assign_generator_table(ctx)?;
+
+        let mut validity = Vec::with_capacity(items.len());
+        for (sig, pk, msg_hash) in items {
+            // s = 0 이면 역원이 없으므로 invalid로 처리
+            let (s_inv, s_is_zero) = scalar_chip.invert(ctx, &sig.s)?;
+            let r_is_zero = scalar_chip.is_zero(ctx, &sig.r)?;
+            let u1 = scalar_chip.mul(ctx, msg_hash, &s_inv)?;
+            let u2 = scalar_chip.mul(ctx, &sig.r, &s_inv)?;
+
+            let u1_g = self.fixed_base_mul(ctx, &table, &u1)?;
+            let u2_p = ecc_chip.mul(ctx, &pk.point, &u2, WINDOW_SIZE)?;
+            let q = ecc_chip.add(ctx, &u1_g, &u2_p)?;
+            let q_x = base_chip.reduce(ctx, q.x())?;
+            let q_x = scalar_chip.reduce_external(ctx, &q_x)?;
+            let matches = scalar_chip.is_strict_equal(ctx, &q_x, &sig.r)?;
+
+            let zero = main_gate.or(ctx, &s_is_zero, &r_is_zero)?;
+            let nonzero = main_gate.not(ctx, &zero)?;
+            validity.push(main_gate.and(ctx, &matches, &nonzero)?);
+        }
+        Ok(validity)
+    }
+
+    /// 고정 base 테이블을 상수로 할당 (batch 전체에서 한 번)
+    /// windows[k][j] = (j * 2^(WINDOW_SIZE * k) + TABLE_OFFSET_SEED * (k + 1)) * G
+    fn assign_generator_table(&self, ctx: &mut RegionCtx<'_, F>) -> Result<GeneratorTable<E, F, LIMBS, BITS>, Error> {
+        let ecc_chip = self.chip.ecc_chip();
+        let seed = E::Scalar::from(TABLE_OFFSET_SEED);
+        let num_bits = E::Scalar::NUM_BITS as usize;
+
+        let mut windows = Vec::with_capacity((num_bits + WINDOW_SIZE - 1) / WINDOW_SIZE);
+        let mut base = E::Scalar::ONE;
+        let mut offset = E::Scalar::ZERO;
+        for (k, start) in (0..num_bits).step_by(WINDOW_SIZE).enumerate() {
+            let window_bits = WINDOW_SIZE.min(num_bits - start);
+            let window_offset = seed * E::Scalar::from(k as u64 + 1);
+            let entries = (0..1u64 << window_bits)
+                .map(|j| ecc_chip.assign_constant(ctx, (E::generator() * (base * E::Scalar::from(j) + window_offset)).to_affine()))
+                .collect::<Result<Vec<_>, _>>()?;
+            windows.push(entries);
+            offset += window_offset;
+            base *= E::Scalar::from(1 << WINDOW_SIZE);
+        }
+        let correction = ecc_chip.assign_constant(ctx, (E::generator() * -offset).to_affine())?;
+        Ok(GeneratorTable { windows, correction })
+    }
+
+    /// scalar * G. 윈도우마다 상수 테이블에서 고르고 더하기만 함 (doubling 없음)
+    /// scalar는 mul 결과처럼 reduce된 값이어야 함 (최상위 limb가 scalar field 비트 수 안)
+    fn fixed_base_mul(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        table: &GeneratorTable<E, F, LIMBS, BITS>,
+        scalar: &AssignedInteger<E::Scalar, F, LIMBS, BITS>,
+    ) -> Result<AssignedPoint<E::Base, F, LIMBS, BITS>, Error> {
+        let ecc_chip = self.chip.ecc_chip();
+        let main_gate = ecc_chip.main_gate();
+        let num_bits = E::Scalar::NUM_BITS as usize;
+
+        let mut bits = Vec::with_capacity(num_bits);
+        for i in 0..LIMBS {
+            let limb_bits = BITS.min(num_bits - i * BITS);
+            bits.extend(main_gate.to_bits(ctx, &scalar.limb(i), limb_bits)?);
+        }
+
+        let mut acc = table.correction.clone();
+        for (window, entries) in bits.chunks(WINDOW_SIZE).zip(table.windows.iter()) {
+            // 아래 bit부터 반씩 고름
+            let mut candidates = entries.clone();
+            for bit in window {
+                candidates = candidates
+                    .chunks(2)
+                    .map(|pair| ecc_chip.select(ctx, bit, &pair[1], &pair[0]))
+                    .collect::<Result<Vec<_>, _>>()?;
+            }
+            acc = ecc_chip.add(ctx, &acc, &candidates[0])?;
+        }
+        Ok(acc)
+    }
 }
 
+/// batch 검증이 공유하는 u1 * G 상수 테이블
+struct GeneratorTable<E: halo2curves::CurveAffine, F: PrimeField, const LIMBS: usize, const BITS: usize> {
+    windows: Vec<Vec<AssignedPoint<E::Base, F, LIMBS, BITS>>>,
+    /// -(테이블 offset의 합) * G
+    correction: AssignedPoint<E::Base, F, LIMBS, BITS>,
+}
+
+/// verify_batch 입력: (서명, 공개키, msg_hash)
+pub type SignatureItem<E, F, const LIMBS: usize, const BITS: usize> = (
+    AssignedEcdsaSig<<E as halo2curves::CurveAffine>::ScalarExt, F, LIMBS, BITS>,
+    AssignedPublicKey<<E as halo2curves::CurveAffine>::Base, F, LIMBS, BITS>,
+    AssignedInteger<<E as halo2curves::CurveAffine>::ScalarExt, F, LIMBS, BITS>,
+);
+
 // ECDSA 검증은 아래 수학적 검증을 회로로 표현
 // Given:
 //   Signature: (r, s)
@@ -123,3 +428,160 @@ impl<E: halo2curves::CurveAffine<ScalarExt = F>, F: PrimeField, const LIMBS: usi
 //   - 모든 연산은 RNS 기반 limb 연산
 //   - scalar 연산은 IntegerConfig / IntegerInstructions trait 기반
 //   - ECC 연산은 EccChip 기반 (일반적으로 elliptic curve group addition, scalar mul 포함)
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sizing::estimate;
+    use crate::test_utils::{assert_constraint_failure, ecdsa_keypair, ecdsa_sign, mock_prove};
+    use halo2::circuit::SimpleFloorPlanner;
+    use halo2::plonk::Circuit;
+    use halo2curves::bn256::{Fr, G1Affine};
+    use halo2curves::ff::Field;
+    use halo2curves::CurveAffine;
+    use rand_core::OsRng;
+
+    type Bn254SignatureChip = SignatureChip<G1Affine, Fr, 4, 68>;
+
+    /// (r, s, pk, msg_hash)
+    type Signed = (Fr, Fr, G1Affine, Fr);
+
+    #[derive(Clone, Copy, Debug)]
+    enum Mode {
+        /// 서명마다 verify
+        Separate,
+        Batch,
+        Threshold(usize),
+    }
+
+    #[derive(Clone)]
+    struct SignatureCircuit {
+        signatures: Vec<Signed>,
+        mode: Mode,
+    }
+
+    impl Circuit<Fr> for SignatureCircuit {
+        type Config = SignatureConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            Bn254SignatureChip::configure(meta)
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<Fr>) -> Result<(), Error> {
+            config.config_range(&mut layouter)?;
+            layouter.assign_region(
+                || "ecdsa",
+                |region| {
+                    let mut ctx = RegionCtx::new(region, 0);
+                    let chip = Bn254SignatureChip::construct(&mut ctx, &config)?;
+                    let mut items = Vec::with_capacity(self.signatures.len());
+                    for (r, s, pk, msg_hash) in &self.signatures {
+                        let coordinates = pk.coordinates().unwrap();
+                        let sig = chip.assign_signature(&mut ctx, (*r, *s))?;
+                        let pk = chip.assign_public_key(&mut ctx, (*coordinates.x(), *coordinates.y()))?;
+                        let msg_hash = chip.assign_integer(&mut ctx, *msg_hash)?;
+                        items.push((sig, pk, msg_hash));
+                    }
+                    match self.mode {
+                        Mode::Separate => {
+                            for (sig, pk, msg_hash) in &items {
+                                chip.verify(&mut ctx, sig, pk, msg_hash)?;
+                            }
+                            Ok(())
+                        }
+                        Mode::Batch => chip.verify_batch(&mut ctx, &items),
+                        Mode::Threshold(threshold) => chip.verify_threshold(&mut ctx, &items, threshold).map(|_| ()),
+                    }
+                },
+            )
+        }
+    }
+
+    fn signed() -> Signed {
+        let (sk, pk) = ecdsa_keypair::<G1Affine>();
+        let msg_hash = Fr::random(OsRng);
+        let (r, s) = ecdsa_sign::<G1Affine>(sk, msg_hash);
+        (r, s, pk, msg_hash)
+    }
+
+    fn forged() -> Signed {
+        let (r, s, pk, msg_hash) = signed();
+        (r, s + Fr::one(), pk, msg_hash)
+    }
+
+    #[test]
+    fn accepts_valid_signature() {
+        let circuit = SignatureCircuit { signatures: vec![signed()], mode: Mode::Separate };
+        assert_eq!(mock_prove(&circuit, vec![]), Ok(()));
+    }
+
+    #[test]
+    fn rejects_wrong_signature() {
+        let circuit = SignatureCircuit { signatures: vec![forged()], mode: Mode::Separate };
+        assert_constraint_failure(mock_prove(&circuit, vec![]));
+    }
+
+    #[test]
+    fn rejects_signature_over_other_message() {
+        let (r, s, pk, msg_hash) = signed();
+        let circuit = SignatureCircuit { signatures: vec![(r, s, pk, msg_hash + Fr::one())], mode: Mode::Separate };
+        assert_constraint_failure(mock_prove(&circuit, vec![]));
+    }
+
+    #[test]
+    fn batch_rejects_one_bad_signature() {
+        let circuit = SignatureCircuit { signatures: vec![signed(), signed()], mode: Mode::Batch };
+        assert_eq!(mock_prove(&circuit, vec![]), Ok(()));
+
+        let circuit = SignatureCircuit { signatures: vec![signed(), forged()], mode: Mode::Batch };
+        assert_constraint_failure(mock_prove(&circuit, vec![]));
+    }
+
+    #[test]
+    fn threshold_counts_valid_signatures() {
+        let circuit = SignatureCircuit { signatures: vec![signed(), forged(), signed()], mode: Mode::Threshold(2) };
+        assert_eq!(mock_prove(&circuit, vec![]), Ok(()));
+
+        let circuit = SignatureCircuit { signatures: vec![signed(), forged(), forged()], mode: Mode::Threshold(2) };
+        assert_constraint_failure(mock_prove(&circuit, vec![]));
+    }
+
+    #[test]
+    fn threshold_rejects_repeated_key() {
+        // 한 issuer가 두 메시지에 서명해도 두 자리를 채울 수 없음
+        let (sk, pk) = ecdsa_keypair::<G1Affine>();
+        let signatures = (0..2)
+            .map(|_| {
+                let msg_hash = Fr::random(OsRng);
+                let (r, s) = ecdsa_sign::<G1Affine>(sk, msg_hash);
+                (r, s, pk, msg_hash)
+            })
+            .collect::<Vec<_>>();
+        let circuit = SignatureCircuit { signatures: vec![signatures[0], signatures[1], forged()], mode: Mode::Threshold(2) };
+        assert_constraint_failure(mock_prove(&circuit, vec![]));
+    }
+
+    #[test]
+    fn threshold_above_signature_count_fails_synthesis() {
+        let circuit = SignatureCircuit { signatures: vec![signed(), signed()], mode: Mode::Threshold(3) };
+        let result = halo2::dev::MockProver::run(18, &circuit, vec![]);
+        assert!(matches!(result, Err(Error::Synthesis)));
+    }
+
+    #[test]
+    fn batch_costs_less_per_signature_than_verify() {
+        // 공유 테이블은 한 번만 드므로 서명 하나를 더할 때 늘어나는 행 수를 비교
+        let rows = |mode, n| {
+            let circuit = SignatureCircuit { signatures: (0..n).map(|_| signed()).collect(), mode };
+            estimate(&circuit).unwrap().rows
+        };
+        let separate = rows(Mode::Separate, 3) - rows(Mode::Separate, 2);
+        let batch = rows(Mode::Batch, 3) - rows(Mode::Batch, 2);
+        assert!(batch < separate, "batch adds {batch} rows per signature, verify adds {separate}");
+    }
+}
@@ -1,20 +1,22 @@
 use halo2wrong::curves::bn256::Fr;
 use halo2::{
-    circuit::{Layouter, SimpleFloorPlanner, Value, Region},
-    plonk::{Circuit, ConstraintSystem, Error, Advice, Column, Instance, Selector},
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{Circuit, ConstraintSystem, Error, Advice, Column, Instance},
 };
-use halo2_solidity_verifier::SolidityGenerator;
+use poseidon::{Pow5Chip, Pow5Config, P128Pow5T3};
+use crate::gadgets::merkle::{MerkleConfig, MerkleGadget};
 
 /// Merkle proof 회로의 Config 구조체
 #[derive(Clone, Debug)]
 pub struct GroupAccessConfig {
-    pub leaf: Column<Advice>,
-    pub path_elements: Vec<Column<Advice>>,
-    pub path_indices: Vec<Column<Advice>>,
+    /// leaf와 sibling을 할당하는 컬럼 (Poseidon 입력으로 copy됨)
+    pub witness: Column<Advice>,
+    pub poseidon: Pow5Config<Fr, 3, 2>,
+    pub merkle: MerkleConfig,
     pub root: Column<Instance>,
-    pub selector: Selector,
 }
 
+/// leaf가 root 아래의 그룹 멤버임을 증명. public input: [root]
 #[derive(Clone, Debug)]
 pub struct GroupAccessCircuit {
     pub leaf: Fr,
@@ -23,6 +25,12 @@ pub struct GroupAccessCircuit {
     pub root: Fr,
 }
 
+impl GroupAccessCircuit {
+    pub fn instances(&self) -> Vec<Vec<Fr>> {
+        vec![vec![self.root]]
+    }
+}
+
 impl Circuit<Fr> for GroupAccessCircuit {
     type Config = GroupAccessConfig;
     type FloorPlanner = SimpleFloorPlanner;
@@ -37,25 +45,27 @@ impl Circuit<Fr> for GroupAccessCircuit {
     }
 
     fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
-        // Merkle proof depth (예: 20)
-        let depth = 20;
-        let leaf = meta.advice_column();
-        let mut path_elements = Vec::new();
-        let mut path_indices = Vec::new();
-        for _ in 0..depth {
-            path_elements.push(meta.advice_column());
-            path_indices.push(meta.advice_column());
-        }
+        let witness = meta.advice_column();
+        meta.enable_equality(witness);
+
+        let state = [meta.advice_column(), meta.advice_column(), meta.advice_column()];
+        let partial_sbox = meta.advice_column();
+        let rc_a = [meta.fixed_column(), meta.fixed_column(), meta.fixed_column()];
+        let rc_b = [meta.fixed_column(), meta.fixed_column(), meta.fixed_column()];
+        meta.enable_constant(rc_b[0]);
+        let poseidon = Pow5Chip::<Fr, 3, 2>::configure::<P128Pow5T3>(
+            meta,
+            state,
+            partial_sbox,
+            rc_a,
+            rc_b,
+        );
+
+        let merkle = MerkleGadget::configure(meta);
         let root = meta.instance_column();
-        let selector = meta.selector();
-
-        GroupAccessConfig {
-            leaf,
-            path_elements,
-            path_indices,
-            root,
-            selector,
-        }
+        meta.enable_equality(root);
+
+        GroupAccessConfig { witness, poseidon, merkle, root }
     }
 
     fn synthesize(
@@ -63,50 +73,91 @@ impl Circuit<Fr> for GroupAccessCircuit {
         config: Self::Config,
         mut layouter: impl Layouter<Fr>,
     ) -> Result<(), Error> {
-        let cell = layouter.assign_region(
-            || "Merkle proof traversal",
+        let (leaf, path) = layouter.assign_region(
+            || "Merkle proof witness",
             |mut region| {
-                let mut current_hash = self.leaf;
-                let mut offset = 0;
-                for (i, (pe, pi)) in self.path_elements.iter().zip(self.path_indices.iter()).enumerate() {
-                    let (left, right) = if *pi { (pe.clone(), current_hash) } else { (current_hash, pe.clone()) };
-                    // 실제로는 Poseidon hash를 써야 하지만, 예시로 left+right 사용
-                    let hash = left + right;
-                    region.assign_advice(
-                        || "leaf",
-                        config.leaf,
-                        offset,
-                        || Value::known(current_hash),
-                    )?;
-                    region.assign_advice(
-                        || "path element",
-                        config.path_elements[i],
-                        offset,
-                        || Value::known(*pe),
-                    )?;
-                    region.assign_advice(
-                        || "path index",
-                        config.path_indices[i],
-                        offset,
-                        || Value::known(Fr::from(*pi as u64)),
-                    )?;
-                    current_hash = hash;
-                    offset += 1;
-                }
-                let cell = region.assign_advice(
-                    || "final root",
-                    config.leaf,
-                    offset,
-                    || Value::known(current_hash),
-                )?;
-                Ok(cell)
+                let leaf = region.assign_advice(|| "leaf", config.witness, 0, || Value::known(self.leaf))?;
+                let path = self
+                    .path_elements
+                    .iter()
+                    .enumerate()
+                    .map(|(i, pe)| {
+                        region.assign_advice(|| format!("path element {i}"), config.witness, i + 1, || Value::known(*pe))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok((leaf, path))
             },
         )?;
-        layouter.constrain_instance(cell.cell(), config.root, 0);
+
+        let chip = Pow5Chip::<Fr, 3, 2>::construct(config.poseidon.clone());
+        let root = MerkleGadget::compute_root(&config.merkle, &chip, &mut layouter, leaf, &path, &self.path_indices)?;
+        layouter.constrain_instance(root.cell(), config.root, 0)?;
         Ok(())
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::audit;
+    use crate::fingerprint::Fingerprint;
+    use crate::prover::{keygen, prove, setup_params, verify};
+    use crate::sizing::estimate;
+    use crate::test_utils::{assert_permutation_failure, index_bits, merkle_proof, mock_prove, random_fr};
+
+    const DEPTH: usize = 20;
+
+    fn valid_circuit() -> GroupAccessCircuit {
+        let leaf = random_fr();
+        let leaf_index = 12345;
+        let (path_elements, root) = merkle_proof(leaf, DEPTH, leaf_index);
+        GroupAccessCircuit { leaf, path_elements, path_indices: index_bits(leaf_index, DEPTH), root }
+    }
+
+    #[test]
+    fn accepts_member() {
+        let circuit = valid_circuit();
+        assert_eq!(mock_prove(&circuit, circuit.instances()), Ok(()));
+    }
+
+    #[test]
+    fn rejects_wrong_sibling() {
+        let mut circuit = valid_circuit();
+        let instances = circuit.instances();
+        circuit.path_elements[7] += Fr::one();
+        assert_permutation_failure(mock_prove(&circuit, instances));
+    }
+
+    #[test]
+    fn rejects_wrong_root() {
+        let circuit = valid_circuit();
+        assert_permutation_failure(mock_prove(&circuit, vec![vec![random_fr()]]));
+    }
+
+    #[test]
+    fn is_clean_under_audit() {
+        let report = audit(&valid_circuit()).unwrap();
+        assert!(report.is_clean(), "{report}");
+    }
+
+    #[test]
+    fn leaf_position_does_not_change_vk() {
+        // leaf 위치는 witness이므로 같은 깊이면 같은 키로 증명할 수 있어야 함
+        let circuit = |leaf_index| {
+            let leaf = random_fr();
+            let (path_elements, root) = merkle_proof(leaf, 4, leaf_index);
+            GroupAccessCircuit { leaf, path_elements, path_indices: index_bits(leaf_index, 4), root }
+        };
+        let (left, right) = (circuit(0), circuit(13));
+        let params = setup_params(estimate(&left).unwrap().k);
+        let pk = keygen(&params, &left).unwrap();
+        assert_eq!(Fingerprint::of(pk.get_vk()), Fingerprint::of(keygen(&params, &right).unwrap().get_vk()));
+
+        let proof = prove(&params, &pk, right.clone(), &right.instances()).unwrap();
+        assert!(verify(&params, pk.get_vk(), &right.instances(), &proof).is_ok());
+    }
+}
+
 /*
 ====================[ Halo2 Prover/Verifier & Solidity 연동 흐름 ]====================
 
@@ -1,75 +1,121 @@
 use halo2curves::bn256::Fr;
-use poseidon::{Pow5Chip, Pow5Config, Spec, ConstantLength, Hash, P128Pow5T3};
-use halo2::plonk::{Circuit, ConstraintSystem, Error};
+use poseidon::{Pow5Chip, Pow5Config, P128Pow5T3};
+use halo2::plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance};
 use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
 use crate::gadgets::poseidon::PoseidonGadget;
-use crate::gadgets::merkle::MerkleGadget;
+use crate::gadgets::merkle::{MerkleConfig, MerkleGadget};
 use crate::gadgets::range_check::{RangeCheckChip, RangeCheckConfig};
 use crate::gadgets::signature::{SignatureChip, SignatureConfig};
-use ecc::{GeneralEccChip, EccConfig};
-use ecdsa::ecdsa::{EcdsaChip, AssignedEcdsaSig, AssignedPublicKey};
-use integer::rns::Integer;
-use integer::AssignedInteger;
-use maingate::RegionCtx;
+use maingate::{MainGateInstructions, RegionCtx};
 use halo2curves::bn256::G1Affine;
-use halo2curves::bn256::Fq;
-use halo2curves::ff::PrimeField;
-use halo2::circuit::AssignedCell;
-
+use halo2curves::secp256r1::Secp256r1Affine;
+use halo2curves::ff::Field;
+use halo2curves::CurveAffine;
+use crate::issuer::{claim_hash_to_scalar, ecdsa_key_hash};
 
+/// instance column 레이아웃
+pub const NULLIFIER_ROW: usize = 0;
+pub const APP_SCOPE_ROW: usize = 1;
+pub const MERKLE_ROOT_ROW: usize = 2;
+/// 서명한 issuer 키의 hash. 검증자는 신뢰하는 issuer의 `ecdsa_key_hash`와 비교해야 함
+pub const ISSUER_KEY_HASH_ROW: usize = 3;
 
+/// C: issuer 서명 곡선. 기본은 BN254 G1, P-256 키를 가진 issuer는 `Es256IdentityClaimCircuit`
 #[derive(Clone, Debug)]
-pub struct IdentityClaimCircuit {
-    pub claim_hash: Fr,
+pub struct IdentityClaimCircuit<C: CurveAffine = G1Affine> {
     pub merkle_root: Fr,
     pub merkle_proof: Vec<Fr>,
     pub leaf_index: usize,
     pub value: Fr,
     pub min: Fr,
     pub max: Fr,
-    pub signature_hash: Fr,
-    pub sig_r: Fr,
-    pub sig_s: Fr,
-    pub pk_x: Fr,
-    pub pk_y: Fr,
+    pub sig_r: C::Scalar,
+    pub sig_s: C::Scalar,
+    pub pk_x: C::Base,
+    pub pk_y: C::Base,
+    /// credential 소유자만 아는 비밀값. credential에는 Poseidon(holder_secret)으로 commit됨
+    pub holder_secret: Fr,
+    /// 검증자(앱) 식별자. nullifier의 scope가 되며 public input으로 노출
+    pub app_scope: Fr,
 }
 
 #[derive(Clone, Debug)]
 pub struct IdentityClaimConfig {
     pub range: RangeCheckConfig,
     pub signature: SignatureConfig,
-    pub poseidon: Pow5Config<Fr, 3, 2>, // 추가!
+    pub poseidon: Pow5Config<Fr, 3, 2>,
+    pub merkle: MerkleConfig,
+    pub holder: Column<Advice>,
+    pub instance: Column<Instance>,
 }
 
-impl Circuit<Fr> for IdentityClaimCircuit {
+/// P-256 키를 가진 issuer가 서명한 credential용 (자체 issuer 방식)
+/// issuer는 claim hash 자체(`signature_hash`)를 ECDSA msg_hash로 서명해야 함
+/// 표준 ES256 JWT처럼 SHA-256(header.payload)에 서명한 토큰은 검증할 수 없으니 `JwtClaimCircuit`을 쓸 것
+pub type Es256IdentityClaimCircuit = IdentityClaimCircuit<Secp256r1Affine>;
+
+impl<C: CurveAffine> IdentityClaimCircuit<C> {
+    /// 앱별 nullifier = Poseidon(holder_secret, app_scope)
+    /// 같은 credential이라도 app_scope가 다르면 서로 연결할 수 없음
+    pub fn nullifier(holder_secret: Fr, app_scope: Fr) -> Fr {
+        PoseidonGadget::hash_native([holder_secret, app_scope])
+    }
+
+    /// credential에 들어가는 holder commitment = Poseidon(holder_secret)
+    pub fn holder_commitment(holder_secret: Fr) -> Fr {
+        PoseidonGadget::hash_native([holder_secret])
+    }
+
+    /// claim hash = Poseidon(value, min, max, holder_commitment). merkle leaf이자 issuer 서명 대상
+    pub fn claim_hash(value: Fr, min: Fr, max: Fr, holder_secret: Fr) -> Fr {
+        PoseidonGadget::hash_native([value, min, max, Self::holder_commitment(holder_secret)])
+    }
+
+    /// issuer가 서명해야 하는 msg_hash. 회로는 claim hash와 같은 정수인지 limb 단위로 확인함
+    pub fn signature_hash(claim_hash: Fr) -> C::Scalar {
+        claim_hash_to_scalar(claim_hash)
+    }
+
+    pub fn instances(&self) -> Vec<Vec<Fr>> {
+        vec![vec![
+            Self::nullifier(self.holder_secret, self.app_scope),
+            self.app_scope,
+            self.merkle_root,
+            ecdsa_key_hash(&self.pk_x, &self.pk_y),
+        ]]
+    }
+}
+
+impl<C: CurveAffine> Circuit<Fr> for IdentityClaimCircuit<C> {
     type Config = IdentityClaimConfig;
     type FloorPlanner = SimpleFloorPlanner;
 
     fn without_witnesses(&self) -> Self {
         Self {
-            claim_hash: Fr::zero(),
             merkle_root: Fr::zero(),
-            merkle_proof: vec![],
+            merkle_proof: vec![Fr::zero(); self.merkle_proof.len()],
             leaf_index: 0,
             value: Fr::zero(),
             min: Fr::zero(),
             max: Fr::zero(),
-            signature_hash: Fr::zero(),
-            sig_r: Fr::zero(),
-            sig_s: Fr::zero(),
-            pk_x: Fr::zero(),
-            pk_y: Fr::zero(),
+            sig_r: C::Scalar::ZERO,
+            sig_s: C::Scalar::ZERO,
+            pk_x: C::Base::ZERO,
+            pk_y: C::Base::ZERO,
+            holder_secret: Fr::zero(),
+            app_scope: Fr::zero(),
         }
     }
 
     fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
         let range = RangeCheckChip::configure(meta);
-        let signature = SignatureChip::<halo2curves::bn256::G1Affine, Fr, 4, 68>::configure(meta);
+        let signature = SignatureChip::<C, Fr, 4, 68>::configure(meta);
         // Poseidon용 컬럼 선언
         let state = [meta.advice_column(), meta.advice_column(), meta.advice_column()];
         let partial_sbox = meta.advice_column();
         let rc_a = [meta.fixed_column(), meta.fixed_column(), meta.fixed_column()];
         let rc_b = [meta.fixed_column(), meta.fixed_column(), meta.fixed_column()];
+        meta.enable_constant(rc_b[0]);
 
         let poseidon = Pow5Chip::<Fr, 3, 2>::configure::<P128Pow5T3>(
             meta,
@@ -79,7 +125,13 @@ impl Circuit<Fr> for IdentityClaimCircuit {
             rc_b,
         );
 
-        IdentityClaimConfig { range, signature, poseidon }
+        let merkle = MerkleGadget::configure(meta);
+        let holder = meta.advice_column();
+        meta.enable_equality(holder);
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        IdentityClaimConfig { range, signature, poseidon, merkle, holder, instance }
     }
 
     fn synthesize(
@@ -88,7 +140,7 @@ impl Circuit<Fr> for IdentityClaimCircuit {
         mut layouter: impl Layouter<Fr>,
     ) -> Result<(), Error> {
         // 1. 값 할당만 region에서
-        let (assigned_value, assigned_min, assigned_max, assigned_path) =
+        let (assigned_value, assigned_min, assigned_max) =
             layouter.assign_region(
                 || "identity claim main",
                 |mut region| {
@@ -101,29 +153,65 @@ impl Circuit<Fr> for IdentityClaimCircuit {
                     let assigned_max = region.assign_advice(
                         || "max", config.range.max, 0, || Value::known(self.max)
                     )?;
-                    let assigned_path: Vec<AssignedCell<Fr, Fr>> = self.merkle_proof.iter()
-                        .enumerate()
-                        .map(|(i, v)| region.assign_advice(
-                            || format!("merkle_path_{i}"), config.range.value, i + 1, || Value::known(*v)
-                        ).unwrap())
-                        .collect();
-                    Ok((assigned_value, assigned_min, assigned_max, assigned_path))
+                    Ok((assigned_value, assigned_min, assigned_max))
                 }
             )?;
+        let assigned_path = layouter.assign_region(
+            || "merkle path",
+            |mut region| {
+                self.merkle_proof
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| {
+                        region.assign_advice(|| format!("merkle_path_{i}"), config.holder, i, || Value::known(*v))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            },
+        )?;
 
        // 2. Poseidon 해시 (in-circuit, layouter 기반)
         let chip = Pow5Chip::<Fr, 3, 2>::construct(config.poseidon.clone());
-        let calc_claim_hash = PoseidonGadget::hash::<3>(
+        let (assigned_holder_secret, assigned_app_scope) = layouter.assign_region(
+            || "holder",
+            |mut region| {
+                let holder_secret = region.assign_advice(
+                    || "holder_secret", config.holder, 0, || Value::known(self.holder_secret)
+                )?;
+                let app_scope = region.assign_advice(
+                    || "app_scope", config.holder, 1, || Value::known(self.app_scope)
+                )?;
+                Ok((holder_secret, app_scope))
+            }
+        )?;
+
+        // 2-1. holder binding: claim hash에 Poseidon(holder_secret)이 포함되므로
+        // holder_secret을 모르면 issuer 서명을 가지고 있어도 증명할 수 없음
+        let holder_commitment = PoseidonGadget::hash::<1>(
+            &chip,
+            layouter.namespace(|| "holder commitment"),
+            [assigned_holder_secret.clone()],
+        )?;
+        let calc_claim_hash = PoseidonGadget::hash::<4>(
             &chip,
             layouter.namespace(|| "poseidon hash"),
-            [assigned_value.clone(), assigned_min.clone(), assigned_max.clone()],
+            [assigned_value.clone(), assigned_min.clone(), assigned_max.clone(), holder_commitment],
+        )?;
+
+        // 2-2. 앱별 nullifier (public)
+        let nullifier = PoseidonGadget::hash::<2>(
+            &chip,
+            layouter.namespace(|| "nullifier"),
+            [assigned_holder_secret, assigned_app_scope.clone()],
         )?;
+        layouter.constrain_instance(nullifier.cell(), config.instance, NULLIFIER_ROW)?;
+        layouter.constrain_instance(assigned_app_scope.cell(), config.instance, APP_SCOPE_ROW)?;
 
         // 3. Merkle root 계산 (layouter 기반)
         let indicies: Vec<bool> = (0..self.merkle_proof.len())
             .map(|i| (self.leaf_index >> i) & 1 == 1)
             .collect();
-        let _merkle_root = MerkleGadget::compute_root(
+        let merkle_root = MerkleGadget::compute_root(
+            &config.merkle,
             &chip,
             &mut layouter,
             calc_claim_hash.clone(),
@@ -131,35 +219,218 @@ impl Circuit<Fr> for IdentityClaimCircuit {
             &indicies,
         )?;
 
+        layouter.constrain_instance(merkle_root.cell(), config.instance, MERKLE_ROOT_ROW)?;
+
         // 4. Range check (in-circuit, layouter 기반)
         let range_chip = RangeCheckChip::<Fr>::construct(config.range.clone());
+        range_chip.load_table(&mut layouter)?;
         range_chip.range_check(
             &mut layouter,
             &assigned_value,
-            self.min,
-            self.max
+            &assigned_min,
+            &assigned_max,
         )?;
     
         // 5. Signature 검증 (in-circuit, 별도 region)
-        layouter.assign_region(
+        config.signature.config_range(&mut layouter)?;
+        let (key_length, key_limbs) = layouter.assign_region(
             || "ecdsa verify",
-            |mut region| {
+            |region| {
                 let mut ctx = RegionCtx::new(region, 0);
+                let signature_chip = SignatureChip::<C, Fr, 4, 68>::construct(&mut ctx, &config.signature)?;
 
-                let ecc_chip = GeneralEccChip::<G1Affine, Fr, 4, 68>::new(config.signature.ecc_chip_config());
-                let ecdsa_chip = EcdsaChip::new(ecc_chip);
-                let signature_chip = SignatureChip::new(ecdsa_chip);
-
-                let pk_x_fq = Fq::from_repr_vartime(self.pk_x.to_repr()).unwrap();
-                let pk_y_fq = Fq::from_repr_vartime(self.pk_y.to_repr()).unwrap();
-                let assigned_pk = signature_chip.assign_public_key(&mut ctx, (pk_x_fq, pk_y_fq))?;
+                let assigned_pk = signature_chip.assign_public_key(&mut ctx, (self.pk_x, self.pk_y))?;
                 let assigned_sig = signature_chip.assign_signature(&mut ctx, (self.sig_r, self.sig_s))?;
-                let assigned_msg_hash = signature_chip.assign_integer(&mut ctx, self.signature_hash)?;
+                // issuer는 holder commitment가 포함된 claim hash에 서명해야 함
+                // native 값만 비교하면 claim_hash + k * |Fr| 도 통과하므로 정수로 고정
+                let assigned_msg_hash = signature_chip.native_to_msg_hash(&mut ctx, &calc_claim_hash)?;
                 signature_chip.verify(&mut ctx, &assigned_sig, &assigned_pk, &assigned_msg_hash)?;
-                Ok(())
+
+                let key_limbs = SignatureChip::<C, Fr, 4, 68>::public_key_limbs(&assigned_pk);
+                let key_length = signature_chip
+                    .main_gate()
+                    .assign_constant(&mut ctx, Fr::from(key_limbs.len() as u64))?;
+                Ok((key_length, key_limbs))
             }
         )?;
 
+        // 6. 서명한 키를 issuer key hash로 공개 (누구나 자기 키로 서명하는 것을 막음)
+        let issuer_key_hash = PoseidonGadget::hash_chain(
+            &chip,
+            layouter.namespace(|| "issuer key hash"),
+            key_length,
+            &key_limbs,
+        )?;
+        layouter.constrain_instance(issuer_key_hash.cell(), config.instance, ISSUER_KEY_HASH_ROW)?;
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::audit;
+    use crate::keys::KeyedCircuit;
+    use crate::test_utils::{
+        assert_constraint_failure, assert_gate_failure, assert_permutation_failure, ecdsa_keypair, ecdsa_sign,
+        merkle_proof, mock_prove, random_fr,
+    };
+
+    const DEPTH: usize = 4;
+
+    fn valid_circuit(value: u64) -> IdentityClaimCircuit {
+        valid_circuit_on::<G1Affine>(value)
+    }
+
+    /// C 곡선의 issuer가 서명한 credential
+    fn valid_circuit_on<C: CurveAffine>(value: u64) -> IdentityClaimCircuit<C> {
+        let (value, min, max) = (Fr::from(value), Fr::from(18), Fr::from(65));
+        let holder_secret = random_fr();
+        let claim_hash = IdentityClaimCircuit::<C>::claim_hash(value, min, max, holder_secret);
+        let leaf_index = 6;
+        let (merkle_proof, merkle_root) = merkle_proof(claim_hash, DEPTH, leaf_index);
+
+        let (sk, pk) = ecdsa_keypair::<C>();
+        let signature_hash = IdentityClaimCircuit::<C>::signature_hash(claim_hash);
+        let (sig_r, sig_s) = ecdsa_sign::<C>(sk, signature_hash);
+        let coordinates = pk.coordinates().unwrap();
+
+        IdentityClaimCircuit {
+            merkle_root,
+            merkle_proof,
+            leaf_index,
+            value,
+            min,
+            max,
+            sig_r,
+            sig_s,
+            pk_x: *coordinates.x(),
+            pk_y: *coordinates.y(),
+            holder_secret,
+            app_scope: random_fr(),
+        }
+    }
+
+    /// circuit의 claim에 대해 issuer가 서명해야 하는 값
+    fn signature_hash_of<C: CurveAffine>(circuit: &IdentityClaimCircuit<C>) -> C::Scalar {
+        let claim_hash = IdentityClaimCircuit::<C>::claim_hash(circuit.value, circuit.min, circuit.max, circuit.holder_secret);
+        IdentityClaimCircuit::<C>::signature_hash(claim_hash)
+    }
+
+    /// 새 issuer 키로 msg_hash에 서명한 circuit
+    fn resigned<C: CurveAffine>(circuit: &IdentityClaimCircuit<C>, msg_hash: C::Scalar) -> IdentityClaimCircuit<C> {
+        let (sk, pk) = ecdsa_keypair::<C>();
+        let (sig_r, sig_s) = ecdsa_sign::<C>(sk, msg_hash);
+        let coordinates = pk.coordinates().unwrap();
+        IdentityClaimCircuit { sig_r, sig_s, pk_x: *coordinates.x(), pk_y: *coordinates.y(), ..circuit.clone() }
+    }
+
+    #[test]
+    fn accepts_valid_claim() {
+        let circuit = valid_circuit(30);
+        assert_eq!(mock_prove(&circuit, circuit.instances()), Ok(()));
+    }
+
+    #[test]
+    fn rejects_wrong_sibling() {
+        let mut circuit = valid_circuit(30);
+        let instances = circuit.instances();
+        circuit.merkle_proof[2] += Fr::one();
+        assert_permutation_failure(mock_prove(&circuit, instances));
+    }
+
+    #[test]
+    fn rejects_wrong_signature() {
+        let mut circuit = valid_circuit(30);
+        circuit.sig_s += Fr::one();
+        let instances = circuit.instances();
+        assert_constraint_failure(mock_prove(&circuit, instances));
+    }
+
+    #[test]
+    fn rejects_out_of_range_value() {
+        // issuer가 서명한 값이라도 범위를 벗어나면 증명할 수 없음
+        let circuit = valid_circuit(17);
+        assert_gate_failure(mock_prove(&circuit, circuit.instances()), "range check");
+    }
+
+    #[test]
+    fn rejects_wrong_public_input() {
+        let circuit = valid_circuit(30);
+        let mut instances = circuit.instances();
+        instances[0][NULLIFIER_ROW] += Fr::one();
+        assert_permutation_failure(mock_prove(&circuit, instances.clone()));
+
+        let mut instances = circuit.instances();
+        instances[0][MERKLE_ROOT_ROW] = random_fr();
+        assert_permutation_failure(mock_prove(&circuit, instances));
+    }
+
+    #[test]
+    fn rejects_other_holder_secret() {
+        // 남의 credential을 가져와 자기 secret으로 nullifier를 만들 수 없음
+        let mut circuit = valid_circuit(30);
+        circuit.holder_secret = random_fr();
+        let instances = circuit.instances();
+        assert_permutation_failure(mock_prove(&circuit, instances));
+    }
+
+    #[test]
+    fn rejects_claim_signed_by_other_key() {
+        // 검증자가 기대하는 issuer 대신 임의의 키로 같은 claim에 서명
+        let circuit = valid_circuit(30);
+        let instances = circuit.instances();
+        let forged = resigned(&circuit, signature_hash_of(&circuit));
+        assert_permutation_failure(mock_prove(&forged, instances));
+    }
+
+    #[test]
+    fn without_witnesses_keeps_key_shape() {
+        let circuit = valid_circuit(30);
+        assert_eq!(circuit.without_witnesses().parameters(), circuit.parameters());
+    }
+
+    #[test]
+    fn is_clean_under_audit() {
+        for report in [audit(&valid_circuit(30)).unwrap(), audit(&valid_circuit_on::<Secp256r1Affine>(30)).unwrap()] {
+            assert!(report.is_clean(), "{report}");
+        }
+    }
+
+    #[test]
+    fn accepts_p256_issuer() {
+        let circuit = valid_circuit_on::<Secp256r1Affine>(30);
+        assert_eq!(mock_prove(&circuit, circuit.instances()), Ok(()));
+    }
+
+    #[test]
+    fn rejects_tampered_p256_signature() {
+        let mut circuit = valid_circuit_on::<Secp256r1Affine>(30);
+        let instances = circuit.instances();
+        circuit.sig_r += <Secp256r1Affine as CurveAffine>::ScalarExt::ONE;
+        assert_constraint_failure(mock_prove(&circuit, instances));
+    }
+
+    #[test]
+    fn rejects_p256_signature_over_jwt_digest() {
+        // claim hash가 아닌 다른 값(예: SHA-256(header.payload))에 대한 서명은 받지 않음
+        type Scalar = <Secp256r1Affine as CurveAffine>::ScalarExt;
+        let circuit = valid_circuit_on::<Secp256r1Affine>(30);
+        let honest = resigned(&circuit, signature_hash_of(&circuit));
+        assert_eq!(mock_prove(&honest, honest.instances()), Ok(()));
+
+        let other = resigned(&circuit, signature_hash_of(&circuit) + Scalar::ONE);
+        assert_constraint_failure(mock_prove(&other, other.instances()));
+    }
+
+    #[test]
+    fn rejects_p256_signature_over_non_canonical_claim_hash() {
+        // claim_hash + |Fr|은 Fr에서는 claim_hash와 같지만 P-256 scalar로는 다른 정수
+        type Scalar = <Secp256r1Affine as CurveAffine>::ScalarExt;
+        let circuit = valid_circuit_on::<Secp256r1Affine>(30);
+        let fr_modulus = claim_hash_to_scalar::<Scalar>(-Fr::one()) + Scalar::ONE;
+        let forged = resigned(&circuit, signature_hash_of(&circuit) + fr_modulus);
+        assert_constraint_failure(mock_prove(&forged, forged.instances()));
+    }
+}
@@ -0,0 +1,522 @@
+//! 백엔드(비 Rust)에서 증명을 요청할 때 쓰는 JSON 입력 형식
+//! field 원소는 모두 문자열: `0x`로 시작하면 big-endian hex, 아니면 10진수
+
+use std::fmt;
+
+use halo2curves::bn256::Fr;
+use halo2curves::ff::PrimeField;
+use halo2curves::CurveAffine;
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+
+use crate::gadgets::merkle::MerkleGadget;
+use crate::group_access::GroupAccessCircuit;
+use crate::identity_claim::{IdentityClaimCircuit, APP_SCOPE_ROW, ISSUER_KEY_HASH_ROW, MERKLE_ROOT_ROW, NULLIFIER_ROW};
+use crate::post_proof::{self, PostProofCircuit};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputError {
+    /// JSON 문법 또는 스키마 오류
+    Json(String),
+    /// hex/10진수로 읽을 수 없는 값
+    InvalidNumber { field: String, value: String },
+    /// field 모듈러스 이상인 값
+    OutOfRange { field: String },
+    /// 64비트 정수여야 하는 값 (credential의 value, min, max)
+    NotU64 { field: String },
+    /// credential의 min > max
+    InvalidBounds { min: u64, max: u64 },
+    /// 공개키가 곡선 위의 점이 아님
+    NotOnCurve { field: String },
+    /// leaf_index가 path 깊이로 표현할 수 없는 값
+    LeafIndexOutOfRange { index: usize, depth: usize },
+    /// merkle path로 계산한 root가 입력의 root와 다름
+    MerkleRootMismatch,
+    /// public input 개수가 회로와 맞지 않음
+    InstanceCount { expected: usize, actual: usize },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Json(message) => write!(f, "malformed JSON: {message}"),
+            InputError::InvalidNumber { field, value } => {
+                write!(f, "{field}: `{value}` is not a hex (0x...) or decimal number")
+            }
+            InputError::OutOfRange { field } => write!(f, "{field}: value is not smaller than the field modulus"),
+            InputError::NotU64 { field } => write!(f, "{field}: value does not fit in 64 bits"),
+            InputError::InvalidBounds { min, max } => write!(f, "credential.min ({min}) is greater than credential.max ({max})"),
+            InputError::NotOnCurve { field } => write!(f, "{field}: point is not on the curve"),
+            InputError::LeafIndexOutOfRange { index, depth } => {
+                write!(f, "merkle_path.leaf_index: {index} does not fit in a path of depth {depth}")
+            }
+            InputError::MerkleRootMismatch => write!(f, "merkle path does not lead to the given root"),
+            InputError::InstanceCount { expected, actual } => {
+                write!(f, "expected {expected} public inputs, got {actual}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl From<serde_json::Error> for InputError {
+    fn from(error: serde_json::Error) -> Self {
+        InputError::Json(error.to_string())
+    }
+}
+
+/// 문자열을 field 원소로. field는 오류 메시지에 쓰는 JSON 경로
+pub fn parse_field<F: PrimeField>(field: &str, value: &str) -> Result<F, InputError> {
+    let invalid = || InputError::InvalidNumber { field: field.to_string(), value: value.to_string() };
+    let trimmed = value.trim();
+    let (digits, radix) = match trimmed.strip_prefix("0x").or_else(|| trimmed.strip_prefix("0X")) {
+        Some(hex) => (hex, 16),
+        None => (trimmed, 10),
+    };
+    let number = BigUint::parse_bytes(digits.as_bytes(), radix).ok_or_else(invalid)?;
+
+    let out_of_range = || InputError::OutOfRange { field: field.to_string() };
+    let bytes = number.to_bytes_le();
+    let mut repr = F::Repr::default();
+    if bytes.len() > repr.as_ref().len() {
+        return Err(out_of_range());
+    }
+    repr.as_mut()[..bytes.len()].copy_from_slice(&bytes);
+    Option::from(F::from_repr(repr)).ok_or_else(out_of_range)
+}
+
+/// field 원소를 `0x` + big-endian hex로
+pub fn format_field<F: PrimeField>(value: &F) -> String {
+    let mut bytes = value.to_repr().as_ref().to_vec();
+    bytes.reverse();
+    let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+    format!("0x{hex}")
+}
+
+fn parse_all(field: &str, values: &[String]) -> Result<Vec<Fr>, InputError> {
+    values.iter().enumerate().map(|(i, v)| parse_field(&format!("{field}[{i}]"), v)).collect()
+}
+
+/// leaf에서 root 방향 sibling과 leaf 위치
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MerklePathInput {
+    pub siblings: Vec<String>,
+    pub leaf_index: usize,
+}
+
+impl MerklePathInput {
+    fn parse(&self) -> Result<(Vec<Fr>, Vec<bool>), InputError> {
+        let depth = self.siblings.len();
+        if depth < usize::BITS as usize && self.leaf_index >> depth != 0 {
+            return Err(InputError::LeafIndexOutOfRange { index: self.leaf_index, depth });
+        }
+        let siblings = parse_all("merkle_path.siblings", &self.siblings)?;
+        let indices = (0..depth).map(|i| (self.leaf_index >> i) & 1 == 1).collect();
+        Ok((siblings, indices))
+    }
+
+    /// leaf에서 계산한 root가 expected인지 확인
+    fn check_root(&self, leaf: Fr, expected: Fr) -> Result<(Vec<Fr>, Vec<bool>), InputError> {
+        let (siblings, indices) = self.parse()?;
+        if MerkleGadget::compute_root_native(leaf, &siblings, &indices) != expected {
+            return Err(InputError::MerkleRootMismatch);
+        }
+        Ok((siblings, indices))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CredentialInput {
+    pub value: String,
+    pub min: String,
+    pub max: String,
+    pub holder_secret: String,
+}
+
+impl CredentialInput {
+    /// (value, min, max, holder_secret)
+    /// range check 회로는 64비트 값만 비교하므로 value, min, max가 64비트이고 min <= max인지 확인
+    fn parse(&self) -> Result<(Fr, Fr, Fr, Fr), InputError> {
+        let value = parse_u64("credential.value", &self.value)?;
+        let min = parse_u64("credential.min", &self.min)?;
+        let max = parse_u64("credential.max", &self.max)?;
+        if min > max {
+            return Err(InputError::InvalidBounds { min, max });
+        }
+        let holder_secret = parse_field("credential.holder_secret", &self.holder_secret)?;
+        Ok((Fr::from(value), Fr::from(min), Fr::from(max), holder_secret))
+    }
+}
+
+fn parse_u64(field: &str, value: &str) -> Result<u64, InputError> {
+    let parsed: Fr = parse_field(field, value)?;
+    let repr = parsed.to_repr();
+    let (low, high) = repr.as_ref().split_at(8);
+    if high.iter().any(|b| *b != 0) {
+        return Err(InputError::NotU64 { field: field.to_string() });
+    }
+    Ok(u64::from_le_bytes(low.try_into().unwrap()))
+}
+
+/// issuer 곡선 위의 공개키 (affine 좌표)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PublicKeyInput {
+    pub x: String,
+    pub y: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SignatureInput {
+    pub r: String,
+    pub s: String,
+}
+
+/// `IdentityClaimCircuit` witness. claim hash는 입력에서 계산함
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IdentityClaimInput {
+    pub credential: CredentialInput,
+    pub issuer_public_key: PublicKeyInput,
+    pub signature: SignatureInput,
+    pub merkle_path: MerklePathInput,
+    pub merkle_root: String,
+    pub app_scope: String,
+}
+
+impl IdentityClaimInput {
+    pub fn from_json(json: &str) -> Result<Self, InputError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// C: issuer 서명 곡선
+    pub fn to_circuit<C: CurveAffine>(&self) -> Result<IdentityClaimCircuit<C>, InputError> {
+        let (value, min, max, holder_secret) = self.credential.parse()?;
+
+        let pk_x = parse_field::<C::Base>("issuer_public_key.x", &self.issuer_public_key.x)?;
+        let pk_y = parse_field::<C::Base>("issuer_public_key.y", &self.issuer_public_key.y)?;
+        if bool::from(C::from_xy(pk_x, pk_y).is_none()) {
+            return Err(InputError::NotOnCurve { field: "issuer_public_key".to_string() });
+        }
+        let sig_r = parse_field::<C::Scalar>("signature.r", &self.signature.r)?;
+        let sig_s = parse_field::<C::Scalar>("signature.s", &self.signature.s)?;
+
+        let claim_hash = IdentityClaimCircuit::<C>::claim_hash(value, min, max, holder_secret);
+        let merkle_root = parse_field("merkle_root", &self.merkle_root)?;
+        let (merkle_proof, _) = self.merkle_path.check_root(claim_hash, merkle_root)?;
+
+        Ok(IdentityClaimCircuit {
+            merkle_root,
+            merkle_proof,
+            leaf_index: self.merkle_path.leaf_index,
+            value,
+            min,
+            max,
+            sig_r,
+            sig_s,
+            pk_x,
+            pk_y,
+            holder_secret,
+            app_scope: parse_field("app_scope", &self.app_scope)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IdentityClaimPublicInputs {
+    pub nullifier: String,
+    pub app_scope: String,
+    pub merkle_root: String,
+    pub issuer_key_hash: String,
+}
+
+impl IdentityClaimPublicInputs {
+    pub fn from_instances(instances: &[Vec<Fr>]) -> Result<Self, InputError> {
+        let row = single_column(instances, 4)?;
+        Ok(Self {
+            nullifier: format_field(&row[NULLIFIER_ROW]),
+            app_scope: format_field(&row[APP_SCOPE_ROW]),
+            merkle_root: format_field(&row[MERKLE_ROOT_ROW]),
+            issuer_key_hash: format_field(&row[ISSUER_KEY_HASH_ROW]),
+        })
+    }
+
+    pub fn to_instances(&self) -> Result<Vec<Vec<Fr>>, InputError> {
+        let mut row = vec![Fr::zero(); 4];
+        row[NULLIFIER_ROW] = parse_field("nullifier", &self.nullifier)?;
+        row[APP_SCOPE_ROW] = parse_field("app_scope", &self.app_scope)?;
+        row[MERKLE_ROOT_ROW] = parse_field("merkle_root", &self.merkle_root)?;
+        row[ISSUER_KEY_HASH_ROW] = parse_field("issuer_key_hash", &self.issuer_key_hash)?;
+        Ok(vec![row])
+    }
+}
+
+/// `GroupAccessCircuit` witness
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GroupAccessInput {
+    pub leaf: String,
+    pub merkle_path: MerklePathInput,
+    pub root: String,
+}
+
+impl GroupAccessInput {
+    pub fn from_json(json: &str) -> Result<Self, InputError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_circuit(&self) -> Result<GroupAccessCircuit, InputError> {
+        let leaf = parse_field("leaf", &self.leaf)?;
+        let root = parse_field("root", &self.root)?;
+        let (path_elements, path_indices) = self.merkle_path.check_root(leaf, root)?;
+        Ok(GroupAccessCircuit { leaf, path_elements, path_indices, root })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GroupAccessPublicInputs {
+    pub root: String,
+}
+
+impl GroupAccessPublicInputs {
+    pub fn from_instances(instances: &[Vec<Fr>]) -> Result<Self, InputError> {
+        let row = single_column(instances, 1)?;
+        Ok(Self { root: format_field(&row[0]) })
+    }
+
+    pub fn to_instances(&self) -> Result<Vec<Vec<Fr>>, InputError> {
+        Ok(vec![vec![parse_field("root", &self.root)?]])
+    }
+}
+
+/// `PostProofCircuit` witness. leaf(claim hash)는 credential에서 계산함
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PostProofInput {
+    pub credential: CredentialInput,
+    pub post_hash: String,
+    pub merkle_path: MerklePathInput,
+    pub merkle_root: String,
+    pub app_scope: String,
+}
+
+impl PostProofInput {
+    pub fn from_json(json: &str) -> Result<Self, InputError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_circuit(&self) -> Result<PostProofCircuit, InputError> {
+        let (value, min, max, holder_secret) = self.credential.parse()?;
+        let mut circuit = PostProofCircuit {
+            value,
+            min,
+            max,
+            holder_secret,
+            app_scope: parse_field("app_scope", &self.app_scope)?,
+            post_hash: parse_field("post_hash", &self.post_hash)?,
+            merkle_root: parse_field("merkle_root", &self.merkle_root)?,
+            merkle_proof: vec![],
+            leaf_index: self.merkle_path.leaf_index,
+        };
+        (circuit.merkle_proof, _) = self.merkle_path.check_root(circuit.claim_hash(), circuit.merkle_root)?;
+        Ok(circuit)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PostProofPublicInputs {
+    pub merkle_root: String,
+    pub post_hash: String,
+    pub nullifier: String,
+    pub app_scope: String,
+}
+
+impl PostProofPublicInputs {
+    pub fn from_instances(instances: &[Vec<Fr>]) -> Result<Self, InputError> {
+        let row = single_column(instances, 4)?;
+        Ok(Self {
+            merkle_root: format_field(&row[post_proof::MERKLE_ROOT_ROW]),
+            post_hash: format_field(&row[post_proof::POST_HASH_ROW]),
+            nullifier: format_field(&row[post_proof::NULLIFIER_ROW]),
+            app_scope: format_field(&row[post_proof::APP_SCOPE_ROW]),
+        })
+    }
+
+    pub fn to_instances(&self) -> Result<Vec<Vec<Fr>>, InputError> {
+        let mut row = vec![Fr::zero(); 4];
+        row[post_proof::MERKLE_ROOT_ROW] = parse_field("merkle_root", &self.merkle_root)?;
+        row[post_proof::POST_HASH_ROW] = parse_field("post_hash", &self.post_hash)?;
+        row[post_proof::NULLIFIER_ROW] = parse_field("nullifier", &self.nullifier)?;
+        row[post_proof::APP_SCOPE_ROW] = parse_field("app_scope", &self.app_scope)?;
+        Ok(vec![row])
+    }
+}
+
+/// instance column 하나에 expected개가 들어 있는지 확인
+fn single_column(instances: &[Vec<Fr>], expected: usize) -> Result<&[Fr], InputError> {
+    match instances {
+        [row] if row.len() == expected => Ok(row),
+        [row] => Err(InputError::InstanceCount { expected, actual: row.len() }),
+        _ => Err(InputError::InstanceCount { expected, actual: instances.iter().map(Vec::len).sum() }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{ecdsa_keypair, ecdsa_sign, merkle_proof, mock_prove, random_fr};
+    use halo2curves::bn256::{Fq, G1Affine};
+
+    fn credential(value: u64, min: u64, max: u64, holder_secret: Fr) -> CredentialInput {
+        CredentialInput {
+            value: value.to_string(),
+            min: min.to_string(),
+            max: max.to_string(),
+            holder_secret: format_field(&holder_secret),
+        }
+    }
+
+    /// BN254 issuer가 서명하고 tree에 등록한 credential
+    fn identity_claim_input() -> IdentityClaimInput {
+        let holder_secret = random_fr();
+        let claim_hash = IdentityClaimCircuit::<G1Affine>::claim_hash(Fr::from(30), Fr::from(18), Fr::from(65), holder_secret);
+        let (sk, pk) = ecdsa_keypair::<G1Affine>();
+        let (r, s) = ecdsa_sign::<G1Affine>(sk, IdentityClaimCircuit::<G1Affine>::signature_hash(claim_hash));
+        let (path, root) = merkle_proof(claim_hash, 4, 9);
+        let coordinates = pk.coordinates().unwrap();
+        IdentityClaimInput {
+            credential: credential(30, 18, 65, holder_secret),
+            issuer_public_key: PublicKeyInput { x: format_field(coordinates.x()), y: format_field(coordinates.y()) },
+            signature: SignatureInput { r: format_field(&r), s: format_field(&s) },
+            merkle_path: MerklePathInput { siblings: path.iter().map(format_field).collect(), leaf_index: 9 },
+            merkle_root: format_field(&root),
+            app_scope: format_field(&random_fr()),
+        }
+    }
+
+    fn post_proof_input() -> PostProofInput {
+        let holder_secret = random_fr();
+        let claim_hash = IdentityClaimCircuit::<G1Affine>::claim_hash(Fr::from(30), Fr::from(18), Fr::from(65), holder_secret);
+        let (path, root) = merkle_proof(claim_hash, 4, 6);
+        PostProofInput {
+            credential: credential(30, 18, 65, holder_secret),
+            post_hash: format_field(&random_fr()),
+            merkle_path: MerklePathInput { siblings: path.iter().map(format_field).collect(), leaf_index: 6 },
+            merkle_root: format_field(&root),
+            app_scope: format_field(&random_fr()),
+        }
+    }
+
+    #[test]
+    fn parses_hex_and_decimal() {
+        assert_eq!(parse_field::<Fr>("x", "0x1f").unwrap(), Fr::from(31));
+        assert_eq!(parse_field::<Fr>("x", "31").unwrap(), Fr::from(31));
+        let x = random_fr();
+        assert_eq!(parse_field::<Fr>("x", &format_field(&x)).unwrap(), x);
+    }
+
+    #[test]
+    fn reports_malformed_fields() {
+        assert_eq!(
+            parse_field::<Fr>("leaf", "0xzz"),
+            Err(InputError::InvalidNumber { field: "leaf".to_string(), value: "0xzz".to_string() })
+        );
+        // BN254 scalar 모듈러스
+        let modulus = "21888242871839275222246405745257275088548364400416034343698204186575808495617";
+        assert_eq!(parse_field::<Fr>("leaf", modulus), Err(InputError::OutOfRange { field: "leaf".to_string() }));
+        assert!(matches!(GroupAccessInput::from_json("{\"leaf\": 1}"), Err(InputError::Json(_))));
+    }
+
+    #[test]
+    fn group_access_json_round_trip() {
+        let leaf = random_fr();
+        let (path, root) = merkle_proof(leaf, 4, 9);
+        let input = GroupAccessInput {
+            leaf: format_field(&leaf),
+            merkle_path: MerklePathInput { siblings: path.iter().map(format_field).collect(), leaf_index: 9 },
+            root: format_field(&root),
+        };
+        let parsed = GroupAccessInput::from_json(&serde_json::to_string(&input).unwrap()).unwrap();
+        let circuit = parsed.to_circuit().unwrap();
+        let public = GroupAccessPublicInputs::from_instances(&circuit.instances()).unwrap();
+        assert_eq!(mock_prove(&circuit, public.to_instances().unwrap()), Ok(()));
+
+        let wrong = GroupAccessInput { root: format_field(&(root + Fr::one())), ..input.clone() };
+        assert_eq!(wrong.to_circuit().err(), Some(InputError::MerkleRootMismatch));
+        let deep = GroupAccessInput { merkle_path: MerklePathInput { leaf_index: 16, ..input.merkle_path }, ..input };
+        assert_eq!(deep.to_circuit().err(), Some(InputError::LeafIndexOutOfRange { index: 16, depth: 4 }));
+    }
+
+    #[test]
+    fn identity_claim_json_round_trip() {
+        let input = identity_claim_input();
+        let parsed = IdentityClaimInput::from_json(&serde_json::to_string(&input).unwrap()).unwrap();
+        assert_eq!(parsed, input);
+        let circuit = parsed.to_circuit::<G1Affine>().unwrap();
+        let public = IdentityClaimPublicInputs::from_instances(&circuit.instances()).unwrap();
+        let public = serde_json::from_str::<IdentityClaimPublicInputs>(&serde_json::to_string(&public).unwrap()).unwrap();
+        assert_eq!(public.to_instances().unwrap(), circuit.instances());
+        assert_eq!(mock_prove(&circuit, public.to_instances().unwrap()), Ok(()));
+    }
+
+    #[test]
+    fn identity_claim_rejects_invalid_credential() {
+        let input = identity_claim_input();
+        let holder_secret = parse_field("", &input.credential.holder_secret).unwrap();
+
+        let wide = format_field(&(Fr::from(u64::MAX) + Fr::one()));
+        let wide_value = CredentialInput { value: wide, ..input.credential.clone() };
+        let wide_value = IdentityClaimInput { credential: wide_value, ..input.clone() };
+        assert_eq!(
+            wide_value.to_circuit::<G1Affine>().err(),
+            Some(InputError::NotU64 { field: "credential.value".to_string() })
+        );
+
+        let swapped = IdentityClaimInput { credential: credential(30, 65, 18, holder_secret), ..input.clone() };
+        assert_eq!(swapped.to_circuit::<G1Affine>().err(), Some(InputError::InvalidBounds { min: 65, max: 18 }));
+
+        let off_curve = PublicKeyInput { y: format_field(&Fq::one()), ..input.issuer_public_key.clone() };
+        let off_curve = IdentityClaimInput { issuer_public_key: off_curve, ..input };
+        assert_eq!(
+            off_curve.to_circuit::<G1Affine>().err(),
+            Some(InputError::NotOnCurve { field: "issuer_public_key".to_string() })
+        );
+    }
+
+    #[test]
+    fn post_proof_json_round_trip() {
+        let input = post_proof_input();
+        let parsed = PostProofInput::from_json(&serde_json::to_string(&input).unwrap()).unwrap();
+        assert_eq!(parsed, input);
+        let circuit = parsed.to_circuit().unwrap();
+        let public = PostProofPublicInputs::from_instances(&circuit.instances()).unwrap();
+        let public = serde_json::from_str::<PostProofPublicInputs>(&serde_json::to_string(&public).unwrap()).unwrap();
+        assert_eq!(public.to_instances().unwrap(), circuit.instances());
+        assert_eq!(mock_prove(&circuit, public.to_instances().unwrap()), Ok(()));
+    }
+
+    #[test]
+    fn post_proof_rejects_invalid_credential() {
+        let input = post_proof_input();
+        let holder_secret = parse_field("", &input.credential.holder_secret).unwrap();
+
+        let wide = format_field(&(Fr::from(u64::MAX) + Fr::one()));
+        let wide_max = CredentialInput { max: wide, ..input.credential.clone() };
+        let wide_max = PostProofInput { credential: wide_max, ..input.clone() };
+        assert_eq!(wide_max.to_circuit().err(), Some(InputError::NotU64 { field: "credential.max".to_string() }));
+
+        let swapped = PostProofInput { credential: credential(30, 65, 18, holder_secret), ..input.clone() };
+        assert_eq!(swapped.to_circuit().err(), Some(InputError::InvalidBounds { min: 65, max: 18 }));
+
+        // 같은 tree라도 holder_secret이 다르면 leaf가 달라짐
+        let other_holder = PostProofInput { credential: credential(30, 18, 65, random_fr()), ..input };
+        assert_eq!(other_holder.to_circuit().err(), Some(InputError::MerkleRootMismatch));
+    }
+}
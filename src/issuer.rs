@@ -0,0 +1,140 @@
+use std::fmt;
+
+use halo2curves::bn256::Fr;
+use halo2curves::ff::PrimeField;
+use halo2curves::secp256r1::{Fp as P256Base, Secp256r1Affine};
+use halo2curves::CurveAffine;
+use num_bigint::BigUint;
+
+use crate::gadgets::poseidon::PoseidonGadget;
+use crate::gadgets::rsa::{biguint_to_fe, LIMB_BITS};
+
+/// ECDSA 공개키 좌표의 RNS 분해 (`SignatureChip<_, _, 4, 68>`과 같음)
+const ECDSA_LIMBS: usize = 4;
+const ECDSA_LIMB_BITS: usize = 68;
+
+/// credential issuer가 쓰는 서명 방식
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IssuerScheme {
+    /// BN254 G1 위의 ECDSA (기존 자체 issuer)
+    Bn254Ecdsa,
+    /// ES256: P-256(secp256r1) ECDSA. OIDC provider의 JWT 서명
+    /// identity claim 회로에서는 JWT가 아니라 claim hash에 직접 서명한 credential만 받음
+    Es256,
+    /// RS256: RSA PKCS#1 v1.5 + SHA-256 (e = 65537). JWT, e-passport
+    Rs256,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IssuerKeyError {
+    /// 길이 또는 prefix가 잘못된 인코딩
+    InvalidEncoding,
+    /// 좌표가 base field 범위를 벗어남
+    InvalidCoordinate,
+    /// 곡선 위의 점이 아님
+    NotOnCurve,
+    /// 지원하지 않는 RSA 모듈러스 크기 (2048, 4096 비트만 지원)
+    UnsupportedModulusSize(usize),
+}
+
+impl fmt::Display for IssuerKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IssuerKeyError::InvalidEncoding => write!(f, "invalid public key encoding"),
+            IssuerKeyError::InvalidCoordinate => write!(f, "public key coordinate is not a field element"),
+            IssuerKeyError::NotOnCurve => write!(f, "public key is not on the curve"),
+            IssuerKeyError::UnsupportedModulusSize(bits) => write!(f, "unsupported RSA modulus size: {bits} bits"),
+        }
+    }
+}
+
+impl std::error::Error for IssuerKeyError {}
+
+/// ES256 issuer 공개키 처리
+#[derive(Clone, Debug)]
+pub struct Es256Key;
+
+impl Es256Key {
+    /// JWK의 x, y (32바이트 big-endian) → 곡선 위의 점
+    pub fn from_coordinates(x: &[u8], y: &[u8]) -> Result<Secp256r1Affine, IssuerKeyError> {
+        let x = be_bytes_to_base(x)?;
+        let y = be_bytes_to_base(y)?;
+        Option::from(Secp256r1Affine::from_xy(x, y)).ok_or(IssuerKeyError::NotOnCurve)
+    }
+
+    /// SEC1 비압축 인코딩 (0x04 || x || y)
+    pub fn from_sec1(bytes: &[u8]) -> Result<Secp256r1Affine, IssuerKeyError> {
+        if bytes.len() != 65 || bytes[0] != 0x04 {
+            return Err(IssuerKeyError::InvalidEncoding);
+        }
+        Self::from_coordinates(&bytes[1..33], &bytes[33..])
+    }
+}
+
+/// RS256 issuer 공개키 처리. 공개 지수는 65537로 고정
+#[derive(Clone, Debug)]
+pub struct RsaKey;
+
+impl RsaKey {
+    /// JWK의 n (big-endian 바이트) → 모듈러스
+    pub fn from_modulus(bytes: &[u8]) -> Result<BigUint, IssuerKeyError> {
+        let modulus = BigUint::from_bytes_be(bytes);
+        match modulus.bits() {
+            2048 | 4096 => Ok(modulus),
+            bits => Err(IssuerKeyError::UnsupportedModulusSize(bits as usize)),
+        }
+    }
+
+    /// 모듈러스 크기에 맞는 RsaChip limb 수
+    pub fn num_limbs(modulus: &BigUint) -> usize {
+        modulus.bits() as usize / LIMB_BITS
+    }
+}
+
+/// ECDSA issuer key hash의 입력: x, y를 68비트 limb 4개씩으로 나눈 값
+/// 회로에서는 `SignatureChip::public_key_limbs`가 같은 순서의 셀을 돌려줌
+pub fn ecdsa_key_limbs<B: PrimeField>(x: &B, y: &B) -> Vec<Fr> {
+    let mask = (BigUint::from(1u64) << ECDSA_LIMB_BITS) - 1u64;
+    [x, y]
+        .into_iter()
+        .flat_map(|coordinate| {
+            let value = BigUint::from_bytes_le(coordinate.to_repr().as_ref());
+            (0..ECDSA_LIMBS)
+                .map(|i| biguint_to_fe(&((&value >> (i * ECDSA_LIMB_BITS)) & &mask)))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// 회로가 public input으로 노출하는 issuer key hash
+/// 검증자는 신뢰하는 issuer의 값과 비교해야 함 (그렇지 않으면 누구나 자기 키로 서명할 수 있음)
+pub fn ecdsa_key_hash<B: PrimeField>(x: &B, y: &B) -> Fr {
+    PoseidonGadget::hash_chain_native(&ecdsa_key_limbs(x, y))
+}
+
+/// claim hash(BN254 Fr)를 issuer 곡선의 scalar로 옮김
+/// Fr 모듈러스가 두 곡선의 scalar 모듈러스보다 작으므로 정수 값이 그대로 유지됨
+pub fn claim_hash_to_scalar<S: PrimeField>(claim_hash: Fr) -> S {
+    let mut repr = S::Repr::default();
+    repr.as_mut()[..32].copy_from_slice(claim_hash.to_repr().as_ref());
+    S::from_repr(repr).unwrap()
+}
+
+/// base field 원소를 정수로 보고 scalar field로 reduce (ECDSA의 r = R.x mod n)
+pub fn mod_n<B: PrimeField, S: PrimeField>(x: B) -> S {
+    x.to_repr()
+        .as_ref()
+        .iter()
+        .rev()
+        .fold(S::ZERO, |acc, byte| acc * S::from(256) + S::from(*byte as u64))
+}
+
+fn be_bytes_to_base(bytes: &[u8]) -> Result<P256Base, IssuerKeyError> {
+    if bytes.len() != 32 {
+        return Err(IssuerKeyError::InvalidEncoding);
+    }
+    let mut repr = <P256Base as PrimeField>::Repr::default();
+    repr.as_mut().copy_from_slice(bytes);
+    repr.as_mut().reverse();
+    Option::from(P256Base::from_repr(repr)).ok_or(IssuerKeyError::InvalidCoordinate)
+}
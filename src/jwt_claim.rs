@@ -0,0 +1,948 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use halo2curves::bn256::Fr;
+use halo2curves::secp256r1::{Fq as P256Scalar, Secp256r1Affine};
+use halo2curves::CurveAffine;
+use num_bigint::BigUint;
+use poseidon::{Pow5Chip, Pow5Config, P128Pow5T3};
+use halo2::plonk::{Circuit, Column, ConstraintSystem, Error, Instance};
+use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+use maingate::{AssignedCondition, AssignedValue, MainGateInstructions, RegionCtx, Term};
+use crate::gadgets::base64::{decode_bytes_native, Base64Chip};
+use crate::gadgets::comparison::{ComparisonChip, ComparisonConfig, COMPARISON_BITS};
+use crate::gadgets::poseidon::PoseidonGadget;
+use crate::gadgets::rsa::{biguint_to_fe, RsaChip, RsaConfig, LIMB_BITS as RSA_LIMB_BITS};
+use crate::gadgets::sha256::{Sha256Chip, Sha256Config};
+use crate::gadgets::signature::{Es256SignatureChip, SignatureConfig};
+use crate::issuer::{ecdsa_key_limbs, Es256Key, IssuerScheme, RsaKey};
+use crate::policy::Policy;
+
+/// payload 시작 위치(header 길이 + 1)의 최댓값
+pub const MAX_HEADER_LEN: usize = 256;
+/// field 값이 차지할 수 있는 최대 바이트 수 (문자열은 따옴표 포함)
+pub const MAX_VALUE_LEN: usize = 32;
+/// 길이, offset 비교에 쓰는 비트 수
+const OFFSET_BITS: usize = 16;
+const BYTE_BITS: usize = 8;
+
+/// 회로가 디코딩하는 payload 문자 수. header가 최소 1문자이므로 max_len - 2 이상인 4의 배수
+pub fn payload_chars(max_len: usize) -> usize {
+    (max_len + 1) / 4 * 4
+}
+
+/// 디코딩된 payload 바이트 수
+pub fn payload_bytes(max_len: usize) -> usize {
+    payload_chars(max_len) / 4 * 3
+}
+
+/// instance column 레이아웃
+pub const ISSUER_KEY_HASH_ROW: usize = 0;
+/// policy hash 또는 공개한 field 값
+pub const OUTPUT_ROW: usize = 1;
+
+/// 추출할 JSON 값의 종류
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JsonValueKind {
+    /// 음이 아닌 정수 (exp, iat 등)
+    Number,
+    /// true → 1, false → 0 (email_verified 등)
+    Boolean,
+    /// 따옴표 안의 31바이트 이하 문자열을 little-endian으로 묶은 값 (sub 등). escape는 지원하지 않음
+    String,
+}
+
+/// 추출할 field. 이름과 종류는 회로 모양을 결정함
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JwtField {
+    pub name: String,
+    pub kind: JsonValueKind,
+}
+
+impl JwtField {
+    pub fn new(name: impl Into<String>, kind: JsonValueKind) -> Self {
+        Self { name: name.into(), kind }
+    }
+
+    /// `"name":` (공백 없는 compact JSON 기준)
+    pub fn pattern(&self) -> Vec<u8> {
+        format!("\"{}\":", self.name).into_bytes()
+    }
+
+    /// 구분자(',' 또는 '{') + pattern + 값 + 값 뒤의 한 바이트
+    fn span(&self) -> usize {
+        1 + self.pattern().len() + MAX_VALUE_LEN + 1
+    }
+
+    /// 값 구간(`"name":` 바로 뒤, MAX_VALUE_LEN + 1 바이트)에서 값을 읽음
+    pub fn parse_native(&self, value: &[u8]) -> Option<Fr> {
+        match self.kind {
+            JsonValueKind::Number => {
+                let digits = value.iter().take_while(|c| c.is_ascii_digit()).count();
+                if digits == 0 || digits > MAX_VALUE_LEN || !is_delimiter(*value.get(digits)?) {
+                    return None;
+                }
+                Some(value[..digits].iter().fold(Fr::zero(), |acc, c| acc * Fr::from(10) + Fr::from((c - b'0') as u64)))
+            }
+            JsonValueKind::Boolean => {
+                if value.starts_with(b"true") && is_delimiter(*value.get(4)?) {
+                    Some(Fr::one())
+                } else if value.starts_with(b"false") && is_delimiter(*value.get(5)?) {
+                    Some(Fr::zero())
+                } else {
+                    None
+                }
+            }
+            JsonValueKind::String => {
+                if value.first() != Some(&b'"') {
+                    return None;
+                }
+                let len = value[1..].iter().position(|c| *c == b'"')?;
+                if len >= MAX_VALUE_LEN {
+                    return None;
+                }
+                Some(value[1..1 + len].iter().rev().fold(Fr::zero(), |acc, c| acc * Fr::from(256) + Fr::from(*c as u64)))
+            }
+        }
+    }
+}
+
+/// 추출한 값으로 무엇을 증명할지
+#[derive(Clone, Debug)]
+pub enum JwtOutput {
+    /// 값을 attribute 0으로 보고 policy를 만족함을 증명. policy hash를 노출
+    Policy(Policy),
+    /// 값 자체를 노출
+    Reveal,
+}
+
+/// issuer 서명. 공개키는 회로 모양에 영향을 주지 않지만 issuer key hash로 노출됨
+#[derive(Clone, Debug)]
+pub enum JwtSignature {
+    Es256 { pk: Secp256r1Affine, r: P256Scalar, s: P256Scalar },
+    Rs256 { modulus: BigUint, signature: BigUint },
+}
+
+impl JwtSignature {
+    pub fn scheme(&self) -> IssuerScheme {
+        match self {
+            JwtSignature::Es256 { .. } => IssuerScheme::Es256,
+            JwtSignature::Rs256 { .. } => IssuerScheme::Rs256,
+        }
+    }
+
+    /// issuer key hash의 입력: ES256은 x, y의 68비트 limb, RS256은 모듈러스의 64비트 limb
+    pub fn issuer_key_limbs(&self) -> Vec<Fr> {
+        match self {
+            JwtSignature::Es256 { pk, .. } => {
+                let coordinates = pk.coordinates().unwrap();
+                ecdsa_key_limbs(coordinates.x(), coordinates.y())
+            }
+            JwtSignature::Rs256 { modulus, .. } => {
+                let mask = (BigUint::from(1u64) << RSA_LIMB_BITS) - 1u64;
+                (0..RsaKey::num_limbs(modulus))
+                    .map(|i| biguint_to_fe(&((modulus >> (i * RSA_LIMB_BITS)) & &mask)))
+                    .collect()
+            }
+        }
+    }
+
+    pub fn issuer_key_hash(&self) -> Fr {
+        PoseidonGadget::hash_chain_native(&self.issuer_key_limbs())
+    }
+
+    fn without_witnesses(&self) -> Self {
+        match self {
+            JwtSignature::Es256 { .. } => JwtSignature::Es256 {
+                pk: Secp256r1Affine::generator(),
+                r: P256Scalar::zero(),
+                s: P256Scalar::zero(),
+            },
+            // limb 수가 모듈러스 크기에 따라 달라지므로 모듈러스는 유지
+            JwtSignature::Rs256 { modulus, .. } => JwtSignature::Rs256 {
+                modulus: modulus.clone(),
+                signature: BigUint::from(0u64),
+            },
+        }
+    }
+}
+
+/// 회로가 검증하는 JWT 서명 방식
+/// configure는 회로 값을 볼 수 없으므로 방식을 타입으로 정하고 그 방식의 chip만 만듦
+pub trait JwtScheme: Clone + fmt::Debug {
+    const SCHEME: IssuerScheme;
+}
+
+impl JwtScheme for Es256Key {
+    const SCHEME: IssuerScheme = IssuerScheme::Es256;
+}
+
+impl JwtScheme for RsaKey {
+    const SCHEME: IssuerScheme = IssuerScheme::Rs256;
+}
+
+/// JWT claim 추출 회로
+///
+/// 1. `header.payload` 바이트의 SHA-256을 계산하고 ES256 또는 RS256 서명을 검증
+/// 2. prover가 준 payload_offset 바로 앞 바이트가 '.'인지 확인
+///    (base64url에는 '.'이 없으므로 header/payload 경계는 유일함)
+/// 3. payload 전체를 base64url 디코딩 (payload 끝 이후는 'A'로 채워 0 바이트가 됨)
+/// 4. 디코딩된 바이트를 처음부터 읽으며 JSON 중첩 깊이와 문자열 안인지를 추적
+/// 5. field_position에서 `,"name":` 또는 `{"name":`을 바이트 단위로 검사하고,
+///    그 구분자가 문자열 밖의 최상위 object에 있는지 확인한 뒤 값을 읽음
+/// 6. 값을 policy gadget에 넣거나 그대로 노출
+///
+/// public input: [issuer_key_hash, policy_hash 또는 값]
+#[derive(Clone, Debug)]
+pub struct JwtClaimCircuit<S: JwtScheme = Es256Key> {
+    pub field: JwtField,
+    pub output: JwtOutput,
+    /// `header.payload`의 최대 길이. 회로 모양을 결정함
+    pub max_len: usize,
+    /// 서명된 `header.payload` 바이트
+    pub signed: Vec<u8>,
+    pub signature: JwtSignature,
+    /// payload 첫 문자의 위치 (header 길이 + 1)
+    pub payload_offset: usize,
+    /// 디코딩된 payload 안에서 field 앞 구분자의 위치
+    pub field_position: usize,
+    pub scheme: PhantomData<S>,
+}
+
+pub type Es256JwtClaimCircuit = JwtClaimCircuit<Es256Key>;
+pub type Rs256JwtClaimCircuit = JwtClaimCircuit<RsaKey>;
+
+#[derive(Clone, Debug)]
+pub struct JwtClaimConfig {
+    pub comparison: ComparisonConfig,
+    /// ES256 회로에서만 만듦
+    pub signature: Option<SignatureConfig>,
+    pub poseidon: Pow5Config<Fr, 3, 2>,
+    pub instance: Column<Instance>,
+}
+
+impl<S: JwtScheme> JwtClaimCircuit<S> {
+    /// compact JWT (`header.payload.signature`)에서 최상위 field 위치를 찾아 회로를 만듦
+    /// field를 찾지 못하거나, 값 구간이 payload_bytes(max_len)를 넘거나, 회로가 지원하지 않는 모양이면 None
+    pub fn new(field: JwtField, output: JwtOutput, max_len: usize, token: &str, signature: JwtSignature) -> Option<Self> {
+        let mut parts = token.split('.');
+        let (header, payload) = (parts.next()?, parts.next()?);
+        let signed = format!("{header}.{payload}").into_bytes();
+        let payload_offset = header.len() + 1;
+        if signed.len() > max_len || payload_offset > MAX_HEADER_LEN {
+            return None;
+        }
+
+        let decoded = decode_bytes_native(payload.as_bytes())?;
+        let pattern = field.pattern();
+        let field_position = top_level_delimiters(&decoded)
+            .into_iter()
+            .find(|&p| decoded[p + 1..].starts_with(&pattern))?;
+
+        let circuit = Self {
+            field,
+            output,
+            max_len,
+            signed,
+            signature,
+            payload_offset,
+            field_position,
+            scheme: PhantomData,
+        };
+        (circuit.is_supported() && field_position + circuit.field.span() <= payload_bytes(max_len)).then_some(circuit)
+    }
+
+    /// 회로 모양에 대한 검사. synthesize는 이를 만족하지 않으면 Error::Synthesis
+    fn is_supported(&self) -> bool {
+        let policy_supported = match &self.output {
+            // policy는 추출한 숫자 값(attribute 0)만 참조할 수 있음
+            JwtOutput::Policy(policy) => self.field.kind != JsonValueKind::String && policy.num_attributes() <= 1,
+            JwtOutput::Reveal => true,
+        };
+        self.signature.scheme() == S::SCHEME
+            && self.max_len < 1 << OFFSET_BITS
+            && self.field.span() <= payload_bytes(self.max_len)
+            && policy_supported
+    }
+
+    /// 회로가 디코딩하는 payload 바이트 기준 값 구간 (payload 뒤는 0으로 채움)
+    fn value_bytes(&self) -> Option<Vec<u8>> {
+        let mut decoded = decode_bytes_native(self.signed.get(self.payload_offset..)?)?;
+        decoded.resize(decoded.len().max(payload_bytes(self.max_len)), 0);
+        let value_start = self.field_position + 1 + self.field.pattern().len();
+        decoded.get(value_start..value_start + MAX_VALUE_LEN + 1).map(|v| v.to_vec())
+    }
+
+    /// 추출되는 field 값
+    pub fn value(&self) -> Option<Fr> {
+        self.field.parse_native(&self.value_bytes()?)
+    }
+
+    pub fn instances(&self) -> Vec<Vec<Fr>> {
+        let output = match &self.output {
+            JwtOutput::Policy(policy) => policy.hash(),
+            JwtOutput::Reveal => self.value().unwrap_or(Fr::zero()),
+        };
+        vec![vec![self.signature.issuer_key_hash(), output]]
+    }
+
+    /// payload_offset 앞이 '.'인지 확인하고 payload 문자들을 고름
+    /// payload 끝 이후의 문자는 'A'로 바꿔 디코딩 결과가 0이 되게 함
+    fn select_payload(
+        &self,
+        ctx: &mut RegionCtx<'_, Fr>,
+        chip: &ComparisonChip<Fr>,
+        bytes: &[AssignedValue<Fr>],
+        len: &AssignedValue<Fr>,
+    ) -> Result<Vec<AssignedValue<Fr>>, Error> {
+        let main_gate = chip.main_gate();
+
+        let payload_offset = chip.assign_bounded(ctx, Value::known(Fr::from(self.payload_offset as u64)), OFFSET_BITS)?;
+        let positions: Vec<usize> = (1..=MAX_HEADER_LEN.min(self.max_len)).collect();
+        let is_offset = one_hot(ctx, chip, &payload_offset, &positions)?;
+        let mut dot = Vec::with_capacity(positions.len());
+        for (j, is) in positions.iter().zip(is_offset.iter()) {
+            dot.push(main_gate.mul(ctx, is, &bytes[j - 1])?);
+        }
+        let terms: Vec<Term<Fr>> = dot.iter().map(|c| Term::Assigned(c, Fr::one())).collect();
+        let dot = main_gate.compose(ctx, &terms, Fr::zero())?;
+        main_gate.assert_equal_to_constant(ctx, &dot, Fr::from(b'.' as u64))?;
+
+        let in_bounds = chip.is_less_or_equal(ctx, &payload_offset, len, OFFSET_BITS)?;
+        main_gate.assert_one(ctx, &in_bounds)?;
+
+        // is_offset[j]는 offset j + 1이므로 bytes[1..]을 j만큼 당김
+        let shifted = select_shifted(ctx, chip, &bytes[1..], &is_offset, payload_chars(self.max_len))?;
+        let payload_len = main_gate.sub(ctx, len, &payload_offset)?;
+        let padding = main_gate.assign_constant(ctx, Fr::from(b'A' as u64))?;
+        let mut in_payload = main_gate.assign_constant(ctx, Fr::one())?;
+        let mut chars = Vec::with_capacity(shifted.len());
+        for (i, c) in shifted.iter().enumerate() {
+            let is_end = is_equal_constant(ctx, chip, &payload_len, Fr::from(i as u64))?;
+            let not_end = main_gate.not(ctx, &is_end)?;
+            in_payload = main_gate.and(ctx, &in_payload, &not_end)?;
+            chars.push(main_gate.select(ctx, c, &padding, &in_payload)?);
+        }
+        Ok(chars)
+    }
+
+    /// 디코딩된 바이트를 처음부터 읽으며 JSON 상태를 추적
+    /// 바이트 p마다 (p를 읽은 뒤의 중첩 깊이, p를 읽기 전에 문자열 안이었는지)를 돌려줌
+    fn json_state(
+        &self,
+        ctx: &mut RegionCtx<'_, Fr>,
+        chip: &ComparisonChip<Fr>,
+        decoded: &[AssignedValue<Fr>],
+    ) -> Result<(Vec<AssignedValue<Fr>>, Vec<AssignedCondition<Fr>>), Error> {
+        let main_gate = chip.main_gate();
+        let mut depth = main_gate.assign_constant(ctx, Fr::zero())?;
+        let mut in_string = main_gate.assign_constant(ctx, Fr::zero())?;
+        let mut escaped = main_gate.assign_constant(ctx, Fr::zero())?;
+        let (mut depths, mut in_strings) = (Vec::with_capacity(decoded.len()), Vec::with_capacity(decoded.len()));
+        for c in decoded {
+            let quote = is_equal_constant(ctx, chip, c, Fr::from(b'"' as u64))?;
+            let backslash = is_equal_constant(ctx, chip, c, Fr::from(b'\\' as u64))?;
+            let open = chip.is_in_set(ctx, c, &[Fr::from(b'{' as u64), Fr::from(b'[' as u64)])?;
+            let close = chip.is_in_set(ctx, c, &[Fr::from(b'}' as u64), Fr::from(b']' as u64)])?;
+            in_strings.push(in_string.clone());
+
+            // 문자열 밖: 따옴표는 문자열을 열고 괄호는 깊이를 바꿈
+            let outside = main_gate.not(ctx, &in_string)?;
+            let opens = main_gate.and(ctx, &outside, &quote)?;
+            let delta = main_gate.sub(ctx, &open, &close)?;
+            let delta = main_gate.mul(ctx, &outside, &delta)?;
+            depth = main_gate.add(ctx, &depth, &delta)?;
+
+            // 문자열 안: escape되지 않은 따옴표가 문자열을 닫음
+            let not_escaped = main_gate.not(ctx, &escaped)?;
+            let unescaped_quote = main_gate.and(ctx, &quote, &not_escaped)?;
+            let closes = main_gate.and(ctx, &in_string, &unescaped_quote)?;
+            let unescaped_backslash = main_gate.and(ctx, &backslash, &not_escaped)?;
+            escaped = main_gate.and(ctx, &in_string, &unescaped_backslash)?;
+            in_string = main_gate.compose(
+                ctx,
+                &[
+                    Term::Assigned(&in_string, Fr::one()),
+                    Term::Assigned(&opens, Fr::one()),
+                    Term::Assigned(&closes, -Fr::one()),
+                ],
+                Fr::zero(),
+            )?;
+            depths.push(depth.clone());
+        }
+        Ok((depths, in_strings))
+    }
+
+    /// field_position의 구분자와 `"name":`을 검사하고 값 구간을 돌려줌
+    fn match_field(
+        &self,
+        ctx: &mut RegionCtx<'_, Fr>,
+        chip: &ComparisonChip<Fr>,
+        decoded: &[AssignedValue<Fr>],
+    ) -> Result<Vec<AssignedValue<Fr>>, Error> {
+        let main_gate = chip.main_gate();
+        let span = self.field.span();
+        let position = chip.assign_bounded(ctx, Value::known(Fr::from(self.field_position as u64)), OFFSET_BITS)?;
+        let positions: Vec<usize> = (0..=decoded.len() - span).collect();
+        let is_position = one_hot(ctx, chip, &position, &positions)?;
+        let extracted = select_shifted(ctx, chip, decoded, &is_position, span)?;
+
+        // 구분자가 있어야 다른 값 안의 문자열과 헷갈리지 않음
+        let a = main_gate.sub_with_constant(ctx, &extracted[0], Fr::from(b',' as u64))?;
+        let b = main_gate.sub_with_constant(ctx, &extracted[0], Fr::from(b'{' as u64))?;
+        let delimiter = main_gate.mul(ctx, &a, &b)?;
+        main_gate.assert_zero(ctx, &delimiter)?;
+
+        // 구분자는 문자열 밖에 있고, 읽은 뒤 깊이가 1이어야 최상위 object의 key 앞임
+        let (depths, in_strings) = self.json_state(ctx, chip, decoded)?;
+        let select = |ctx: &mut RegionCtx<'_, Fr>, values: &[AssignedValue<Fr>]| -> Result<AssignedValue<Fr>, Error> {
+            let products = is_position
+                .iter()
+                .zip(values.iter())
+                .map(|(is, v)| main_gate.mul(ctx, is, v))
+                .collect::<Result<Vec<_>, _>>()?;
+            let terms: Vec<Term<Fr>> = products.iter().map(|c| Term::Assigned(c, Fr::one())).collect();
+            main_gate.compose(ctx, &terms, Fr::zero())
+        };
+        let depth = select(ctx, &depths)?;
+        main_gate.assert_equal_to_constant(ctx, &depth, Fr::one())?;
+        let in_string = select(ctx, &in_strings)?;
+        main_gate.assert_zero(ctx, &in_string)?;
+
+        let pattern = self.field.pattern();
+        for (cell, c) in extracted[1..].iter().zip(pattern.iter()) {
+            main_gate.assert_equal_to_constant(ctx, cell, Fr::from(*c as u64))?;
+        }
+        Ok(extracted[1 + pattern.len()..].to_vec())
+    }
+
+    /// 값 구간 → field 값
+    fn parse_value(
+        &self,
+        ctx: &mut RegionCtx<'_, Fr>,
+        chip: &ComparisonChip<Fr>,
+        value: &[AssignedValue<Fr>],
+    ) -> Result<AssignedValue<Fr>, Error> {
+        let main_gate = chip.main_gate();
+        match self.field.kind {
+            JsonValueKind::Number => {
+                // 첫 문자부터 연속된 숫자를 읽고, 숫자가 끝난 자리는 구분자여야 함
+                let mut in_number = is_digit(ctx, chip, &value[0])?;
+                main_gate.assert_one(ctx, &in_number)?;
+                let mut acc = main_gate.add_constant(ctx, &value[0], -Fr::from(b'0' as u64))?;
+                for c in &value[1..] {
+                    let digit = is_digit(ctx, chip, c)?;
+                    let next = main_gate.and(ctx, &in_number, &digit)?;
+                    let shifted = main_gate.compose(
+                        ctx,
+                        &[Term::Assigned(&acc, Fr::from(10)), Term::Assigned(c, Fr::one())],
+                        -Fr::from(b'0' as u64),
+                    )?;
+                    acc = main_gate.select(ctx, &shifted, &acc, &next)?;
+
+                    let ended = main_gate.sub(ctx, &in_number, &next)?;
+                    let delimiter = is_delimiter_cell(ctx, chip, c)?;
+                    let not_delimiter = main_gate.not(ctx, &delimiter)?;
+                    let invalid = main_gate.mul(ctx, &ended, &not_delimiter)?;
+                    main_gate.assert_zero(ctx, &invalid)?;
+                    in_number = next;
+                }
+                main_gate.assert_zero(ctx, &in_number)?;
+                Ok(acc)
+            }
+            JsonValueKind::Boolean => {
+                let t = match_literal(ctx, chip, value, b"true")?;
+                let f = match_literal(ctx, chip, value, b"false")?;
+                let either = main_gate.or(ctx, &t, &f)?;
+                main_gate.assert_one(ctx, &either)?;
+                Ok(t)
+            }
+            JsonValueKind::String => {
+                main_gate.assert_equal_to_constant(ctx, &value[0], Fr::from(b'"' as u64))?;
+                let mut in_string = main_gate.assign_constant(ctx, Fr::one())?;
+                let mut bytes = Vec::with_capacity(MAX_VALUE_LEN);
+                for c in &value[1..] {
+                    let quote = is_equal_constant(ctx, chip, c, Fr::from(b'"' as u64))?;
+                    let not_quote = main_gate.not(ctx, &quote)?;
+                    in_string = main_gate.and(ctx, &in_string, &not_quote)?;
+                    bytes.push(main_gate.mul(ctx, &in_string, c)?);
+                }
+                // 닫는 따옴표가 구간 안에 있어야 함
+                main_gate.assert_zero(ctx, &in_string)?;
+                let mut base = Fr::one();
+                let terms: Vec<Term<Fr>> = bytes
+                    .iter()
+                    .map(|b| {
+                        let term = Term::Assigned(b, base);
+                        base *= Fr::from(256);
+                        term
+                    })
+                    .collect();
+                main_gate.compose(ctx, &terms, Fr::zero())
+            }
+        }
+    }
+}
+
+impl<S: JwtScheme> Circuit<Fr> for JwtClaimCircuit<S> {
+    type Config = JwtClaimConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    // field, output, max_len, 서명 방식은 회로 모양을 결정하므로 witness가 아님
+    fn without_witnesses(&self) -> Self {
+        Self {
+            field: self.field.clone(),
+            output: self.output.clone(),
+            max_len: self.max_len,
+            signed: vec![],
+            signature: self.signature.without_witnesses(),
+            payload_offset: 0,
+            field_position: 0,
+            scheme: PhantomData,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+        let comparison = ComparisonChip::configure(meta);
+        let signature = (S::SCHEME == IssuerScheme::Es256).then(|| Es256SignatureChip::<Fr>::configure(meta));
+
+        let state = [meta.advice_column(), meta.advice_column(), meta.advice_column()];
+        let partial_sbox = meta.advice_column();
+        let rc_a = [meta.fixed_column(), meta.fixed_column(), meta.fixed_column()];
+        let rc_b = [meta.fixed_column(), meta.fixed_column(), meta.fixed_column()];
+        meta.enable_constant(rc_b[0]);
+        let poseidon = Pow5Chip::<Fr, 3, 2>::configure::<P128Pow5T3>(
+            meta,
+            state,
+            partial_sbox,
+            rc_a,
+            rc_b,
+        );
+
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        JwtClaimConfig { comparison, signature, poseidon, instance }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fr>,
+    ) -> Result<(), Error> {
+        if !self.is_supported() {
+            return Err(Error::Synthesis);
+        }
+
+        let comparison_chip = ComparisonChip::<Fr>::construct(config.comparison.clone());
+        comparison_chip.load_table(&mut layouter)?;
+        if let Some(signature) = &config.signature {
+            signature.config_range(&mut layouter)?;
+        }
+        // SHA-256, RSA는 comparison chip과 같은 main gate, range table을 공유
+        let sha256_chip = Sha256Chip::<Fr>::construct(Sha256Config { main_gate: config.comparison.main_gate.clone() });
+
+        let (key_length, key_limbs, output) = layouter.assign_region(
+            || "jwt claim",
+            |region| {
+                let mut ctx = RegionCtx::new(region, 0);
+                let main_gate = comparison_chip.main_gate();
+
+                // 1. header.payload 해시
+                let bytes = (0..self.max_len)
+                    .map(|i| {
+                        let byte = self.signed.get(i).copied().unwrap_or(0);
+                        main_gate.assign_value(&mut ctx, Value::known(Fr::from(byte as u64)))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let len = comparison_chip.assign_bounded(&mut ctx, Value::known(Fr::from(self.signed.len() as u64)), OFFSET_BITS)?;
+                let digest = sha256_chip.hash_var_bytes(&mut ctx, &bytes, &len)?;
+
+                // 2. issuer 서명 검증
+                let key_limbs = match &self.signature {
+                    JwtSignature::Es256 { pk, r, s } => {
+                        let signature_config = config.signature.as_ref().expect("ES256 circuit configures the signature chip");
+                        let signature_chip = Es256SignatureChip::<Fr>::construct(&mut ctx, signature_config)?;
+                        let coordinates = pk.coordinates().unwrap();
+                        let assigned_pk = signature_chip.assign_public_key(&mut ctx, (*coordinates.x(), *coordinates.y()))?;
+                        let assigned_sig = signature_chip.assign_signature(&mut ctx, (*r, *s))?;
+                        let msg_hash = signature_chip.digest_to_msg_hash(&mut ctx, sha256_chip.bitwise(), &digest)?;
+                        signature_chip.verify(&mut ctx, &assigned_sig, &assigned_pk, &msg_hash)?;
+                        Es256SignatureChip::<Fr>::public_key_limbs(&assigned_pk)
+                    }
+                    JwtSignature::Rs256 { modulus, signature } => {
+                        let rsa_chip = RsaChip::<Fr>::construct(RsaConfig {
+                            main_gate: config.comparison.main_gate.clone(),
+                            range: config.comparison.range.clone(),
+                        });
+                        let num_limbs = RsaKey::num_limbs(modulus);
+                        let n = rsa_chip.assign_biguint(&mut ctx, Value::known(modulus.clone()), num_limbs)?;
+                        let sig = rsa_chip.assign_biguint(&mut ctx, Value::known(signature.clone()), num_limbs)?;
+                        rsa_chip.verify_pkcs1v15_sha256(&mut ctx, &sig, &n, &digest)?;
+                        n.limbs
+                    }
+                };
+                let key_length = main_gate.assign_constant(&mut ctx, Fr::from(key_limbs.len() as u64))?;
+
+                // 3. payload 디코딩 → 4, 5. field 추출
+                let chars = self.select_payload(&mut ctx, &comparison_chip, &bytes, &len)?;
+                let decoded = Base64Chip::new(&comparison_chip).decode(&mut ctx, &chars)?;
+                let value_bytes = self.match_field(&mut ctx, &comparison_chip, &decoded)?;
+                let value = self.parse_value(&mut ctx, &comparison_chip, &value_bytes)?;
+
+                // 6. policy 평가 또는 값 공개
+                let output = match &self.output {
+                    JwtOutput::Policy(policy) => {
+                        let attribute = comparison_chip.assign_bounded(&mut ctx, value.value().copied(), COMPARISON_BITS)?;
+                        main_gate.assert_equal(&mut ctx, &attribute, &value)?;
+                        let result = policy.synthesize(&mut ctx, &comparison_chip, &[attribute])?;
+                        main_gate.assert_one(&mut ctx, &result)?;
+                        Some(policy.assign_encoding(&mut ctx, &comparison_chip)?)
+                    }
+                    JwtOutput::Reveal => None,
+                }
+                .ok_or(value);
+                Ok((key_length, key_limbs, output))
+            },
+        )?;
+
+        let chip = Pow5Chip::<Fr, 3, 2>::construct(config.poseidon.clone());
+        let issuer_key_hash = PoseidonGadget::hash_chain(
+            &chip,
+            layouter.namespace(|| "issuer key hash"),
+            key_length,
+            &key_limbs,
+        )?;
+        let output = match output {
+            Ok((policy_length, policy_encoding)) => PoseidonGadget::hash_chain(
+                &chip,
+                layouter.namespace(|| "policy hash"),
+                policy_length,
+                &policy_encoding,
+            )?,
+            Err(value) => value,
+        };
+
+        layouter.constrain_instance(issuer_key_hash.cell(), config.instance, ISSUER_KEY_HASH_ROW)?;
+        layouter.constrain_instance(output.cell(), config.instance, OUTPUT_ROW)?;
+
+        Ok(())
+    }
+}
+
+fn is_delimiter(c: u8) -> bool {
+    c == b',' || c == b'}'
+}
+
+/// 최상위 object의 key 앞 구분자('{' 또는 ',') 위치
+/// 문자열 안이나 중첩된 object, array 안의 구분자는 제외
+fn top_level_delimiters(json: &[u8]) -> Vec<usize> {
+    let (mut depth, mut in_string, mut escaped) = (0usize, false, false);
+    let mut positions = Vec::new();
+    for (p, &c) in json.iter().enumerate() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            b'"' => in_string = true,
+            b'{' | b'[' => {
+                if depth == 0 && c == b'{' {
+                    positions.push(p);
+                }
+                depth += 1;
+            }
+            b'}' | b']' => depth = depth.saturating_sub(1),
+            b',' if depth == 1 => positions.push(p),
+            _ => {}
+        }
+    }
+    positions
+}
+
+/// a == constant 이면 1
+fn is_equal_constant(
+    ctx: &mut RegionCtx<'_, Fr>,
+    chip: &ComparisonChip<Fr>,
+    a: &AssignedValue<Fr>,
+    constant: Fr,
+) -> Result<AssignedCondition<Fr>, Error> {
+    let diff = chip.main_gate().sub_with_constant(ctx, a, constant)?;
+    chip.main_gate().is_zero(ctx, &diff)
+}
+
+/// value == positions[i] 인 i만 1. 합이 1이어야 하므로 value는 positions 중 하나
+fn one_hot(
+    ctx: &mut RegionCtx<'_, Fr>,
+    chip: &ComparisonChip<Fr>,
+    value: &AssignedValue<Fr>,
+    positions: &[usize],
+) -> Result<Vec<AssignedCondition<Fr>>, Error> {
+    let main_gate = chip.main_gate();
+    let indicators = positions
+        .iter()
+        .map(|p| is_equal_constant(ctx, chip, value, Fr::from(*p as u64)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let terms: Vec<Term<Fr>> = indicators.iter().map(|c| Term::Assigned(c, Fr::one())).collect();
+    let sum = main_gate.compose(ctx, &terms, Fr::zero())?;
+    main_gate.assert_one(ctx, &sum)?;
+    Ok(indicators)
+}
+
+/// out[i] = sum_j is_shift[j] * values[j + i]. values 범위 밖은 0
+fn select_shifted(
+    ctx: &mut RegionCtx<'_, Fr>,
+    chip: &ComparisonChip<Fr>,
+    values: &[AssignedValue<Fr>],
+    is_shift: &[AssignedCondition<Fr>],
+    len: usize,
+) -> Result<Vec<AssignedValue<Fr>>, Error> {
+    let main_gate = chip.main_gate();
+    (0..len)
+        .map(|i| {
+            let products = is_shift
+                .iter()
+                .enumerate()
+                .filter_map(|(j, is)| values.get(j + i).map(|v| (is, v)))
+                .map(|(is, v)| main_gate.mul(ctx, is, v))
+                .collect::<Result<Vec<_>, _>>()?;
+            if products.is_empty() {
+                return main_gate.assign_constant(ctx, Fr::zero());
+            }
+            let terms: Vec<Term<Fr>> = products.iter().map(|c| Term::Assigned(c, Fr::one())).collect();
+            main_gate.compose(ctx, &terms, Fr::zero())
+        })
+        .collect()
+}
+
+/// '0' <= c <= '9'
+fn is_digit(
+    ctx: &mut RegionCtx<'_, Fr>,
+    chip: &ComparisonChip<Fr>,
+    c: &AssignedValue<Fr>,
+) -> Result<AssignedCondition<Fr>, Error> {
+    let main_gate = chip.main_gate();
+    let zero = main_gate.assign_constant(ctx, Fr::from(b'0' as u64))?;
+    let nine = main_gate.assign_constant(ctx, Fr::from(b'9' as u64))?;
+    let ge = chip.is_greater_or_equal(ctx, c, &zero, BYTE_BITS)?;
+    let le = chip.is_less_or_equal(ctx, c, &nine, BYTE_BITS)?;
+    main_gate.and(ctx, &ge, &le)
+}
+
+/// c가 ',' 또는 '}'
+fn is_delimiter_cell(
+    ctx: &mut RegionCtx<'_, Fr>,
+    chip: &ComparisonChip<Fr>,
+    c: &AssignedValue<Fr>,
+) -> Result<AssignedCondition<Fr>, Error> {
+    chip.is_in_set(ctx, c, &[Fr::from(b',' as u64), Fr::from(b'}' as u64)])
+}
+
+/// value가 literal로 시작하고 바로 뒤가 구분자이면 1
+fn match_literal(
+    ctx: &mut RegionCtx<'_, Fr>,
+    chip: &ComparisonChip<Fr>,
+    value: &[AssignedValue<Fr>],
+    literal: &[u8],
+) -> Result<AssignedCondition<Fr>, Error> {
+    let main_gate = chip.main_gate();
+    let mut acc = is_delimiter_cell(ctx, chip, &value[literal.len()])?;
+    for (cell, c) in value.iter().zip(literal.iter()) {
+        let eq = is_equal_constant(ctx, chip, cell, Fr::from(*c as u64))?;
+        acc = main_gate.and(ctx, &acc, &eq)?;
+    }
+    Ok(acc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::audit;
+    use crate::gadgets::base64::tests::encode_native;
+    use crate::test_utils::{assert_constraint_failure, assert_permutation_failure, ecdsa_keypair, ecdsa_sign, mock_prove};
+    use halo2curves::ff::Field;
+    use rand_core::OsRng;
+    use rsa::traits::PublicKeyParts;
+    use rsa::{Pkcs1v15Sign, RsaPrivateKey};
+    use sha2::{Digest, Sha256};
+
+    const MAX_LEN: usize = 512;
+    const EXP: u64 = 1_893_456_000;
+
+    /// field들이 앞쪽에 오고 nonce 길이로 payload 길이를 조절
+    fn payload(nonce_len: usize) -> String {
+        format!(
+            r#"{{"sub":"user-1234","email_verified":true,"exp":{EXP},"iat":1700000000,"nonce":"{}"}}"#,
+            "n".repeat(nonce_len)
+        )
+    }
+
+    fn es256_token(payload: &str) -> (String, JwtSignature) {
+        let signed = format!("{}.{}", encode_native(br#"{"alg":"ES256","typ":"JWT"}"#), encode_native(payload.as_bytes()));
+        // ECDSA 규약대로 digest를 big-endian 정수로 보고 scalar로 reduce
+        let msg_hash = Sha256::digest(signed.as_bytes())
+            .iter()
+            .fold(P256Scalar::ZERO, |acc, byte| acc * P256Scalar::from(256) + P256Scalar::from(*byte as u64));
+        let (sk, pk) = ecdsa_keypair::<Secp256r1Affine>();
+        let (r, s) = ecdsa_sign::<Secp256r1Affine>(sk, msg_hash);
+        (format!("{signed}.c2lnbmF0dXJl"), JwtSignature::Es256 { pk, r, s })
+    }
+
+    fn rs256_token(payload: &str) -> (String, JwtSignature) {
+        let signed = format!("{}.{}", encode_native(br#"{"alg":"RS256","typ":"JWT"}"#), encode_native(payload.as_bytes()));
+        let key = RsaPrivateKey::new(&mut OsRng, 2048).unwrap();
+        let digest = Sha256::digest(signed.as_bytes());
+        let signature = key.sign(Pkcs1v15Sign::new::<Sha256>(), &digest).unwrap();
+        let signature = JwtSignature::Rs256 {
+            modulus: BigUint::from_bytes_be(&key.n().to_bytes_be()),
+            signature: BigUint::from_bytes_be(&signature),
+        };
+        (format!("{signed}.c2lnbmF0dXJl"), signature)
+    }
+
+    fn reveal(field: JwtField, token: &(String, JwtSignature)) -> Es256JwtClaimCircuit {
+        JwtClaimCircuit::new(field, JwtOutput::Reveal, MAX_LEN, &token.0, token.1.clone()).unwrap()
+    }
+
+    #[test]
+    fn reveals_number_boolean_and_string() {
+        let token = es256_token(&payload(64));
+        let sub = b"user-1234".iter().rev().fold(Fr::zero(), |acc, c| acc * Fr::from(256) + Fr::from(*c as u64));
+        for (field, expected) in [
+            (JwtField::new("exp", JsonValueKind::Number), Fr::from(EXP)),
+            (JwtField::new("email_verified", JsonValueKind::Boolean), Fr::one()),
+            (JwtField::new("sub", JsonValueKind::String), sub),
+        ] {
+            let circuit = reveal(field, &token);
+            assert_eq!(circuit.value(), Some(expected));
+            assert_eq!(circuit.instances()[0][OUTPUT_ROW], expected);
+            assert_eq!(mock_prove(&circuit, circuit.instances()), Ok(()));
+        }
+    }
+
+    #[test]
+    fn checks_policy_on_extracted_value() {
+        let (token, signature) = es256_token(&payload(64));
+        let field = JwtField::new("exp", JsonValueKind::Number);
+        let circuit = Es256JwtClaimCircuit::new(
+            field.clone(),
+            JwtOutput::Policy(Policy::gte(0, EXP)),
+            MAX_LEN,
+            &token,
+            signature.clone(),
+        )
+        .unwrap();
+        assert_eq!(mock_prove(&circuit, circuit.instances()), Ok(()));
+
+        let circuit =
+            Es256JwtClaimCircuit::new(field, JwtOutput::Policy(Policy::gte(0, EXP + 1)), MAX_LEN, &token, signature)
+                .unwrap();
+        assert_constraint_failure(mock_prove(&circuit, circuit.instances()));
+    }
+
+    #[test]
+    fn rejects_tampered_payload() {
+        let (token, signature) = es256_token(&payload(64));
+        let tampered = payload(64).replace(&EXP.to_string(), "1999999999");
+        let mut parts: Vec<String> = token.split('.').map(str::to_string).collect();
+        parts[1] = encode_native(tampered.as_bytes());
+        let circuit = reveal(JwtField::new("exp", JsonValueKind::Number), &(parts.join("."), signature));
+        assert_eq!(circuit.value(), Some(Fr::from(1_999_999_999)));
+        assert_constraint_failure(mock_prove(&circuit, circuit.instances()));
+    }
+
+    #[test]
+    fn rejects_other_issuer_key() {
+        let circuit = reveal(JwtField::new("exp", JsonValueKind::Number), &es256_token(&payload(64)));
+        let mut instances = circuit.instances();
+        instances[0][ISSUER_KEY_HASH_ROW] = es256_token(&payload(64)).1.issuer_key_hash();
+        assert_permutation_failure(mock_prove(&circuit, instances));
+    }
+
+    #[test]
+    fn matches_top_level_key_only() {
+        let json = format!(r#"{{"x":{{"age":99}},"age":1,"nonce":"{}"}}"#, "n".repeat(64));
+        assert_eq!(top_level_delimiters(json.as_bytes()), vec![0, 15, 23]);
+
+        let circuit = reveal(JwtField::new("age", JsonValueKind::Number), &es256_token(&json));
+        assert_eq!(circuit.value(), Some(Fr::one()));
+        assert_eq!(mock_prove(&circuit, circuit.instances()), Ok(()));
+
+        // 문자열 안의 구분자와 key는 무시
+        let json = format!(r#"{{"note":",\"age\":7","age":2,"nonce":"{}"}}"#, "n".repeat(64));
+        let circuit = reveal(JwtField::new("age", JsonValueKind::Number), &es256_token(&json));
+        assert_eq!(circuit.value(), Some(Fr::from(2)));
+        assert_eq!(mock_prove(&circuit, circuit.instances()), Ok(()));
+    }
+
+    #[test]
+    fn rejects_nested_key() {
+        let json = format!(r#"{{"x":{{"age":99}},"age":1,"nonce":"{}"}}"#, "n".repeat(64));
+        let mut circuit = reveal(JwtField::new("age", JsonValueKind::Number), &es256_token(&json));
+        // 중첩된 object의 '{' 위치로 옮기면 구분자와 pattern은 맞지만 깊이가 2
+        circuit.field_position = 5;
+        assert_eq!(circuit.value(), Some(Fr::from(99)));
+        assert_constraint_failure(mock_prove(&circuit, circuit.instances()));
+    }
+
+    #[test]
+    fn rejects_unsupported_output() {
+        let (token, signature) = es256_token(&payload(64));
+        let sub = JwtField::new("sub", JsonValueKind::String);
+        let exp = JwtField::new("exp", JsonValueKind::Number);
+        let new = |field: &JwtField, policy| {
+            Es256JwtClaimCircuit::new(field.clone(), JwtOutput::Policy(policy), MAX_LEN, &token, signature.clone())
+        };
+        assert!(new(&sub, Policy::eq(0, 1)).is_none());
+        assert!(new(&exp, Policy::gte(1, EXP)).is_none());
+
+        // new를 거치지 않은 회로는 synthesize에서 거부
+        let mut circuit = reveal(sub, &(token.clone(), signature.clone()));
+        circuit.output = JwtOutput::Policy(Policy::eq(0, 1));
+        let result = halo2::dev::MockProver::run(20, &circuit, circuit.instances());
+        assert!(matches!(result, Err(Error::Synthesis)));
+    }
+
+    #[test]
+    fn accepts_payload_ending_in_partial_group() {
+        // payload 길이 % 4 가 0, 2, 3인 경우 (base64url은 padding 없음)
+        let mut residues = vec![];
+        for nonce_len in 64..67 {
+            let token = es256_token(&payload(nonce_len));
+            residues.push(token.0.split('.').nth(1).unwrap().len() % 4);
+            let circuit = reveal(JwtField::new("exp", JsonValueKind::Number), &token);
+            assert_eq!(mock_prove(&circuit, circuit.instances()), Ok(()));
+        }
+        residues.sort();
+        assert_eq!(residues, vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn accepts_rs256_token() {
+        let (token, signature) = rs256_token(&payload(64));
+        let field = JwtField::new("exp", JsonValueKind::Number);
+        assert!(Es256JwtClaimCircuit::new(field.clone(), JwtOutput::Reveal, MAX_LEN, &token, signature.clone()).is_none());
+
+        let circuit = Rs256JwtClaimCircuit::new(field, JwtOutput::Reveal, MAX_LEN, &token, signature).unwrap();
+        assert_eq!(circuit.value(), Some(Fr::from(EXP)));
+        assert_eq!(mock_prove(&circuit, circuit.instances()), Ok(()));
+    }
+
+    #[test]
+    fn is_clean_under_audit() {
+        let field = JwtField::new("exp", JsonValueKind::Number);
+        let (token, signature) = rs256_token(&payload(64));
+        let rs256 = Rs256JwtClaimCircuit::new(field.clone(), JwtOutput::Reveal, MAX_LEN, &token, signature).unwrap();
+        let (token, signature) = es256_token(&payload(64));
+        let es256 =
+            Es256JwtClaimCircuit::new(field, JwtOutput::Policy(Policy::gte(0, EXP)), MAX_LEN, &token, signature).unwrap();
+        for report in [audit(&es256).unwrap(), audit(&rs256).unwrap()] {
+            assert!(report.is_clean(), "{report}");
+        }
+    }
+}
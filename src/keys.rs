@@ -0,0 +1,418 @@
+//! params, pk, vk 파일 형식
+//!
+//! magic(8) | version u32 | kind u8 | k u32 | cs_hash 32B | fingerprint 32B | circuit id | 회로 parameter 목록 | 본문
+//! 문자열은 u16 길이 + UTF-8, 정수는 little-endian
+//! 읽을 때 header가 현재 회로 설정과 다르면 본문을 읽지 않고 거부함
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use halo2::plonk::{Circuit, ConstraintSystem, ProvingKey, VerifyingKey};
+use halo2::poly::commitment::Params;
+use halo2::poly::kzg::commitment::ParamsKZG;
+use halo2::SerdeFormat;
+use halo2curves::bn256::{Bn256, Fr, G1Affine};
+use halo2curves::{CurveAffine, CurveExt};
+use sha3::{Digest, Keccak256};
+
+use crate::fingerprint::Fingerprint;
+use crate::group_access::GroupAccessCircuit;
+use crate::identity_claim::IdentityClaimCircuit;
+use crate::post_proof::PostProofCircuit;
+
+pub(crate) const MAGIC: [u8; 8] = *b"CLMKEYS\0";
+/// 형식이 바뀌면 올림
+pub const FORMAT_VERSION: u32 = 2;
+
+/// 키를 저장할 수 있는 회로
+pub trait KeyedCircuit: Circuit<Fr> {
+    /// 회로 종류. 타입만으로 정해짐
+    fn circuit_id() -> String;
+
+    /// 키 모양을 바꾸는 회로 parameter (merkle 깊이, limb 수 등)
+    fn parameters(&self) -> BTreeMap<String, u64>;
+}
+
+impl KeyedCircuit for GroupAccessCircuit {
+    fn circuit_id() -> String {
+        "group_access".to_string()
+    }
+
+    fn parameters(&self) -> BTreeMap<String, u64> {
+        // path_indices는 witness라 leaf 위치와 무관하게 키가 같음
+        BTreeMap::from([("depth".to_string(), self.path_elements.len() as u64)])
+    }
+}
+
+impl<C: CurveAffine> KeyedCircuit for IdentityClaimCircuit<C> {
+    fn circuit_id() -> String {
+        format!("identity_claim/{}", C::CurveExt::CURVE_ID)
+    }
+
+    fn parameters(&self) -> BTreeMap<String, u64> {
+        BTreeMap::from([
+            ("depth".to_string(), self.merkle_proof.len() as u64),
+            ("limbs".to_string(), 4),
+            ("limb_bits".to_string(), 68),
+        ])
+    }
+}
+
+impl KeyedCircuit for PostProofCircuit {
+    fn circuit_id() -> String {
+        "post_proof".to_string()
+    }
+
+    fn parameters(&self) -> BTreeMap<String, u64> {
+        BTreeMap::from([("depth".to_string(), self.merkle_proof.len() as u64)])
+    }
+}
+
+/// configure가 만든 ConstraintSystem의 해시 (gate, column, lookup, permutation 포함)
+pub fn cs_hash<C: Circuit<Fr>>() -> [u8; 32] {
+    let mut cs = ConstraintSystem::<Fr>::default();
+    C::configure(&mut cs);
+    Keccak256::digest(format!("{:?}", cs.pinned()).as_bytes()).into()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyKind {
+    Params = 0,
+    Proving = 1,
+    Verifying = 2,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyHeader {
+    pub kind: KeyKind,
+    pub k: u32,
+    pub cs_hash: [u8; 32],
+    /// 키의 `Fingerprint`. params 파일과 아직 키가 없는 expected header에서는 None
+    pub fingerprint: Option<Fingerprint>,
+    /// params 파일에서는 비어 있음
+    pub circuit_id: String,
+    pub parameters: BTreeMap<String, u64>,
+}
+
+#[derive(Debug)]
+pub enum KeyError {
+    Io(io::Error),
+    /// 이 형식으로 쓴 파일이 아님
+    BadMagic,
+    UnsupportedVersion(u32),
+    WrongKind { expected: KeyKind, found: KeyKind },
+    CircuitMismatch { expected: String, found: String },
+    ParametersMismatch { expected: BTreeMap<String, u64>, found: BTreeMap<String, u64> },
+    KMismatch { expected: u32, found: u32 },
+    /// gadget이 바뀌어서 키를 다시 만들어야 함
+    ConstraintSystemMismatch,
+    /// 읽은 키가 header의 fingerprint와 다름 (파일 손상)
+    FingerprintMismatch { expected: Fingerprint, found: Fingerprint },
+}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyError::Io(error) => write!(f, "{error}"),
+            KeyError::BadMagic => write!(f, "not a key file"),
+            KeyError::UnsupportedVersion(version) => write!(f, "unsupported key file version {version}"),
+            KeyError::WrongKind { expected, found } => write!(f, "expected {expected:?} key, found {found:?}"),
+            KeyError::CircuitMismatch { expected, found } => {
+                write!(f, "key is for circuit {found}, expected {expected}")
+            }
+            KeyError::ParametersMismatch { expected, found } => {
+                write!(f, "key circuit parameters {found:?} do not match {expected:?}")
+            }
+            KeyError::KMismatch { expected, found } => write!(f, "key has k = {found}, expected {expected}"),
+            KeyError::ConstraintSystemMismatch => {
+                write!(f, "constraint system changed since the key was generated; regenerate it")
+            }
+            KeyError::FingerprintMismatch { expected, found } => {
+                write!(f, "key fingerprint {found} does not match header fingerprint {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for KeyError {}
+
+impl From<io::Error> for KeyError {
+    fn from(error: io::Error) -> Self {
+        KeyError::Io(error)
+    }
+}
+
+impl KeyHeader {
+    pub fn for_circuit<C: KeyedCircuit>(circuit: &C, kind: KeyKind, k: u32) -> Self {
+        Self {
+            kind,
+            k,
+            cs_hash: cs_hash::<C>(),
+            fingerprint: None,
+            circuit_id: C::circuit_id(),
+            parameters: circuit.parameters(),
+        }
+    }
+
+    pub fn for_params(k: u32) -> Self {
+        Self {
+            kind: KeyKind::Params,
+            k,
+            cs_hash: [0; 32],
+            fingerprint: None,
+            circuit_id: String::new(),
+            parameters: BTreeMap::new(),
+        }
+    }
+
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&FORMAT_VERSION.to_le_bytes())?;
+        writer.write_all(&[self.kind as u8])?;
+        writer.write_all(&self.k.to_le_bytes())?;
+        writer.write_all(&self.cs_hash)?;
+        writer.write_all(&self.fingerprint.map_or([0; 32], |fingerprint| fingerprint.0))?;
+        write_string(writer, &self.circuit_id)?;
+        writer.write_all(&(self.parameters.len() as u16).to_le_bytes())?;
+        for (name, value) in &self.parameters {
+            write_string(writer, name)?;
+            writer.write_all(&value.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    pub fn read(reader: &mut impl Read) -> Result<Self, KeyError> {
+        if read_array::<8>(reader)? != MAGIC {
+            return Err(KeyError::BadMagic);
+        }
+        let version = u32::from_le_bytes(read_array(reader)?);
+        if version != FORMAT_VERSION {
+            return Err(KeyError::UnsupportedVersion(version));
+        }
+        let kind = match read_array::<1>(reader)?[0] {
+            0 => KeyKind::Params,
+            1 => KeyKind::Proving,
+            2 => KeyKind::Verifying,
+            _ => return Err(KeyError::BadMagic),
+        };
+        let k = u32::from_le_bytes(read_array(reader)?);
+        let cs_hash = read_array(reader)?;
+        let fingerprint = Some(Fingerprint(read_array(reader)?)).filter(|fingerprint| fingerprint.0 != [0; 32]);
+        let circuit_id = read_string(reader)?;
+        let count = u16::from_le_bytes(read_array(reader)?);
+        let mut parameters = BTreeMap::new();
+        for _ in 0..count {
+            let name = read_string(reader)?;
+            parameters.insert(name, u64::from_le_bytes(read_array(reader)?));
+        }
+        Ok(Self { kind, k, cs_hash, fingerprint, circuit_id, parameters })
+    }
+
+    /// 읽은 header가 expected와 호환되는지 확인
+    /// check_parameters가 false면 회로 parameter는 비교하지 않음 (witness 없이 vk만 읽을 때)
+    pub fn check(&self, expected: &Self, check_parameters: bool) -> Result<(), KeyError> {
+        if self.kind != expected.kind {
+            return Err(KeyError::WrongKind { expected: expected.kind, found: self.kind });
+        }
+        if self.circuit_id != expected.circuit_id {
+            return Err(KeyError::CircuitMismatch { expected: expected.circuit_id.clone(), found: self.circuit_id.clone() });
+        }
+        if self.k != expected.k {
+            return Err(KeyError::KMismatch { expected: expected.k, found: self.k });
+        }
+        if self.cs_hash != expected.cs_hash {
+            return Err(KeyError::ConstraintSystemMismatch);
+        }
+        if check_parameters && self.parameters != expected.parameters {
+            return Err(KeyError::ParametersMismatch {
+                expected: expected.parameters.clone(),
+                found: self.parameters.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// header에 fingerprint가 있으면 읽은 vk와 비교
+    fn check_fingerprint(&self, vk: &VerifyingKey<G1Affine>) -> Result<(), KeyError> {
+        let found = Fingerprint::of(vk);
+        match self.fingerprint {
+            Some(expected) if expected != found => Err(KeyError::FingerprintMismatch { expected, found }),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn write_string(writer: &mut impl Write, value: &str) -> io::Result<()> {
+    writer.write_all(&(value.len() as u16).to_le_bytes())?;
+    writer.write_all(value.as_bytes())
+}
+
+fn read_string(reader: &mut impl Read) -> Result<String, KeyError> {
+    let len = u16::from_le_bytes(read_array(reader)?) as usize;
+    let mut bytes = vec![0; len];
+    reader.read_exact(&mut bytes)?;
+    String::from_utf8(bytes).map_err(|_| KeyError::BadMagic)
+}
+
+fn read_array<const N: usize>(reader: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+pub fn write_params(path: &Path, params: &ParamsKZG<Bn256>) -> Result<(), KeyError> {
+    write_params_to(&mut BufWriter::new(File::create(path)?), params)
+}
+
+pub fn write_params_to(writer: &mut impl Write, params: &ParamsKZG<Bn256>) -> Result<(), KeyError> {
+    KeyHeader::for_params(params.k()).write(writer)?;
+    params.write(writer)?;
+    Ok(())
+}
+
+pub fn read_params(path: &Path) -> Result<ParamsKZG<Bn256>, KeyError> {
+    read_params_from(&mut BufReader::new(File::open(path)?))
+}
+
+pub fn read_params_from(reader: &mut impl Read) -> Result<ParamsKZG<Bn256>, KeyError> {
+    let header = KeyHeader::read(reader)?;
+    header.check(&KeyHeader::for_params(header.k), false)?;
+    Ok(ParamsKZG::read(reader)?)
+}
+
+pub fn write_pk<C: KeyedCircuit>(path: &Path, circuit: &C, pk: &ProvingKey<G1Affine>) -> Result<(), KeyError> {
+    write_pk_to(&mut BufWriter::new(File::create(path)?), circuit, pk)
+}
+
+pub fn write_pk_to<C: KeyedCircuit>(
+    writer: &mut impl Write,
+    circuit: &C,
+    pk: &ProvingKey<G1Affine>,
+) -> Result<(), KeyError> {
+    let mut header = KeyHeader::for_circuit(circuit, KeyKind::Proving, pk.get_vk().get_domain().k());
+    header.fingerprint = Some(Fingerprint::of(pk.get_vk()));
+    header.write(writer)?;
+    pk.write(writer, SerdeFormat::RawBytes)?;
+    Ok(())
+}
+
+/// circuit의 parameter와 params의 k에 맞는 pk만 읽음
+pub fn read_pk<C: KeyedCircuit>(
+    path: &Path,
+    circuit: &C,
+    params: &ParamsKZG<Bn256>,
+) -> Result<ProvingKey<G1Affine>, KeyError> {
+    read_pk_from(&mut BufReader::new(File::open(path)?), circuit, params)
+}
+
+pub fn read_pk_from<C: KeyedCircuit>(
+    reader: &mut impl Read,
+    circuit: &C,
+    params: &ParamsKZG<Bn256>,
+) -> Result<ProvingKey<G1Affine>, KeyError> {
+    let header = KeyHeader::read(reader)?;
+    header.check(&KeyHeader::for_circuit(circuit, KeyKind::Proving, params.k()), true)?;
+    let pk = ProvingKey::read::<_, C>(reader, SerdeFormat::RawBytes)?;
+    header.check_fingerprint(pk.get_vk())?;
+    Ok(pk)
+}
+
+pub fn write_vk<C: KeyedCircuit>(path: &Path, circuit: &C, vk: &VerifyingKey<G1Affine>) -> Result<(), KeyError> {
+    write_vk_to(&mut BufWriter::new(File::create(path)?), circuit, vk)
+}
+
+pub fn write_vk_to<C: KeyedCircuit>(
+    writer: &mut impl Write,
+    circuit: &C,
+    vk: &VerifyingKey<G1Affine>,
+) -> Result<(), KeyError> {
+    let mut header = KeyHeader::for_circuit(circuit, KeyKind::Verifying, vk.get_domain().k());
+    header.fingerprint = Some(Fingerprint::of(vk));
+    header.write(writer)?;
+    vk.write(writer, SerdeFormat::RawBytes)?;
+    Ok(())
+}
+
+/// circuit이 없으면 회로 종류, k, constraint system만 확인하고 header를 같이 돌려줌
+pub fn read_vk<C: KeyedCircuit>(
+    path: &Path,
+    circuit: Option<&C>,
+    params: &ParamsKZG<Bn256>,
+) -> Result<(KeyHeader, VerifyingKey<G1Affine>), KeyError> {
+    read_vk_from(&mut BufReader::new(File::open(path)?), circuit, params)
+}
+
+pub fn read_vk_from<C: KeyedCircuit>(
+    reader: &mut impl Read,
+    circuit: Option<&C>,
+    params: &ParamsKZG<Bn256>,
+) -> Result<(KeyHeader, VerifyingKey<G1Affine>), KeyError> {
+    let header = KeyHeader::read(reader)?;
+    let expected = match circuit {
+        Some(circuit) => KeyHeader::for_circuit(circuit, KeyKind::Verifying, params.k()),
+        None => KeyHeader {
+            kind: KeyKind::Verifying,
+            k: params.k(),
+            cs_hash: cs_hash::<C>(),
+            fingerprint: None,
+            circuit_id: C::circuit_id(),
+            parameters: BTreeMap::new(),
+        },
+    };
+    header.check(&expected, circuit.is_some())?;
+    let vk = VerifyingKey::read::<_, C>(reader, SerdeFormat::RawBytes)?;
+    header.check_fingerprint(&vk)?;
+    Ok((header, vk))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{merkle_proof, random_fr};
+
+    fn post_proof(depth: usize) -> PostProofCircuit {
+        let mut circuit = PostProofCircuit {
+            value: Fr::from(30),
+            min: Fr::from(20),
+            max: Fr::from(40),
+            holder_secret: random_fr(),
+            app_scope: random_fr(),
+            post_hash: random_fr(),
+            merkle_root: Fr::zero(),
+            merkle_proof: vec![],
+            leaf_index: 1,
+        };
+        (circuit.merkle_proof, circuit.merkle_root) = merkle_proof(circuit.claim_hash(), depth, 1);
+        circuit
+    }
+
+    #[test]
+    fn header_round_trip() {
+        let header = KeyHeader::for_circuit(&post_proof(4), KeyKind::Verifying, 10);
+        let mut bytes = vec![];
+        header.write(&mut bytes).unwrap();
+        assert_eq!(KeyHeader::read(&mut bytes.as_slice()).unwrap(), header);
+    }
+
+    #[test]
+    fn rejects_mismatched_header() {
+        let header = KeyHeader::for_circuit(&post_proof(4), KeyKind::Verifying, 10);
+        let deeper = KeyHeader::for_circuit(&post_proof(8), KeyKind::Verifying, 10);
+        assert!(matches!(header.check(&deeper, true), Err(KeyError::ParametersMismatch { .. })));
+        assert!(header.check(&deeper, false).is_ok());
+
+        let other = KeyHeader::for_circuit(
+            &GroupAccessCircuit { leaf: Fr::zero(), path_elements: vec![], path_indices: vec![], root: Fr::zero() },
+            KeyKind::Verifying,
+            10,
+        );
+        assert!(matches!(header.check(&other, false), Err(KeyError::CircuitMismatch { .. })));
+
+        let mut changed = header.clone();
+        changed.cs_hash[0] ^= 1;
+        assert!(matches!(changed.check(&header, true), Err(KeyError::ConstraintSystemMismatch)));
+    }
+}
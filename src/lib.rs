@@ -1,7 +1,27 @@
 pub mod identity_claim;
 pub mod group_access;
 pub mod post_proof;
+pub mod policy;
+pub mod policy_claim;
+pub mod eth_ownership;
+pub mod issuer;
+pub mod jwt_claim;
+pub mod sizing;
+pub mod cost;
+pub mod audit;
+pub mod witness;
+pub mod input;
+pub mod prover;
+pub mod keys;
+pub mod fingerprint;
+pub mod ceremony;
+pub mod envelope;
 pub mod gadgets;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(test)]
+mod test_utils;
 
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
@@ -0,0 +1,345 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use halo2::poly::commitment::Params;
+use halo2curves::bn256::{Fr, G1Affine};
+use halo2curves::secp256r1::Secp256r1Affine;
+use halo2curves::CurveAffine;
+
+use circuits::ceremony;
+use circuits::envelope::ProofEnvelope;
+use circuits::fingerprint::Fingerprint;
+use circuits::group_access::GroupAccessCircuit;
+use circuits::identity_claim::IdentityClaimCircuit;
+use circuits::input::{
+    GroupAccessInput, GroupAccessPublicInputs, IdentityClaimInput, IdentityClaimPublicInputs, InputError,
+    PostProofInput, PostProofPublicInputs,
+};
+use circuits::keys::{self, KeyedCircuit};
+use circuits::post_proof::PostProofCircuit;
+use circuits::prover;
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// 증명 키 생성, 증명, 검증 도구
+#[derive(Parser)]
+#[command(name = "circuits", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// KZG params 생성 (이미 있으면 읽어서 k만 출력)
+    /// --from이 있으면 ceremony 결과(halo2 params, .ptau)를 k로 줄이고 검사해서 저장
+    Setup {
+        #[arg(long)]
+        k: u32,
+        #[arg(long, default_value = "params.bin")]
+        params: PathBuf,
+        #[arg(long)]
+        from: Option<PathBuf>,
+    },
+    /// 회로 모양(merkle 깊이)만으로 pk, vk 생성. witness는 필요 없음
+    Keygen {
+        circuit: CircuitKind,
+        #[arg(long)]
+        depth: usize,
+        #[arg(long, default_value = "params.bin")]
+        params: PathBuf,
+        #[arg(long, default_value = "pk.bin")]
+        pk: PathBuf,
+        #[arg(long, default_value = "vk.bin")]
+        vk: PathBuf,
+    },
+    /// proof envelope와 public input JSON 생성
+    /// proof 경로가 .json이면 JSON envelope, 아니면 binary
+    Prove {
+        circuit: CircuitKind,
+        #[arg(long)]
+        input: PathBuf,
+        #[arg(long, default_value = "params.bin")]
+        params: PathBuf,
+        #[arg(long, default_value = "pk.bin")]
+        pk: PathBuf,
+        #[arg(long, default_value = "proof.bin")]
+        proof: PathBuf,
+        #[arg(long, default_value = "instances.json")]
+        instances: PathBuf,
+    },
+    /// proof envelope 검증
+    /// --instances가 있으면 envelope의 public input과 같은지도 확인
+    Verify {
+        circuit: CircuitKind,
+        #[arg(long, default_value = "params.bin")]
+        params: PathBuf,
+        #[arg(long, default_value = "vk.bin")]
+        vk: PathBuf,
+        #[arg(long, default_value = "proof.bin")]
+        proof: PathBuf,
+        #[arg(long)]
+        instances: Option<PathBuf>,
+    },
+    /// vk의 회로 fingerprint 출력
+    Fingerprint {
+        circuit: CircuitKind,
+        #[arg(long, default_value = "params.bin")]
+        params: PathBuf,
+        #[arg(long, default_value = "vk.bin")]
+        vk: PathBuf,
+    },
+    /// vk를 내장한 Solidity verifier 생성 (fingerprint 상수 포함)
+    GenSolidity {
+        circuit: CircuitKind,
+        #[arg(long, default_value = "params.bin")]
+        params: PathBuf,
+        #[arg(long, default_value = "vk.bin")]
+        vk: PathBuf,
+        #[arg(long, default_value = "Verifier.sol")]
+        out: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum CircuitKind {
+    GroupAccess,
+    /// BN254 ECDSA issuer
+    IdentityClaim,
+    /// ES256 (P-256) issuer
+    Es256IdentityClaim,
+    PostProof,
+}
+
+/// CLI에서 다루는 회로: JSON witness와 public input 형식
+trait CliCircuit: KeyedCircuit + Sized {
+    const NUM_INSTANCES: usize;
+
+    /// merkle 깊이가 depth인 회로. 값은 모두 0이고 keygen에만 씀
+    fn with_depth(depth: usize) -> Self;
+    fn from_input(json: &str) -> std::result::Result<Self, InputError>;
+    fn instances(&self) -> Vec<Vec<Fr>>;
+    fn public_inputs_json(instances: &[Vec<Fr>]) -> Result<String>;
+    fn parse_public_inputs(json: &str) -> std::result::Result<Vec<Vec<Fr>>, InputError>;
+}
+
+impl CliCircuit for GroupAccessCircuit {
+    const NUM_INSTANCES: usize = 1;
+
+    fn with_depth(depth: usize) -> Self {
+        GroupAccessCircuit {
+            leaf: Fr::zero(),
+            path_elements: vec![Fr::zero(); depth],
+            path_indices: vec![false; depth],
+            root: Fr::zero(),
+        }
+    }
+
+    fn from_input(json: &str) -> std::result::Result<Self, InputError> {
+        GroupAccessInput::from_json(json)?.to_circuit()
+    }
+
+    fn instances(&self) -> Vec<Vec<Fr>> {
+        GroupAccessCircuit::instances(self)
+    }
+
+    fn public_inputs_json(instances: &[Vec<Fr>]) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&GroupAccessPublicInputs::from_instances(instances)?)?)
+    }
+
+    fn parse_public_inputs(json: &str) -> std::result::Result<Vec<Vec<Fr>>, InputError> {
+        serde_json::from_str::<GroupAccessPublicInputs>(json)?.to_instances()
+    }
+}
+
+impl<C: CurveAffine> CliCircuit for IdentityClaimCircuit<C> {
+    const NUM_INSTANCES: usize = 4;
+
+    fn with_depth(depth: usize) -> Self {
+        IdentityClaimCircuit {
+            merkle_root: Fr::zero(),
+            merkle_proof: vec![Fr::zero(); depth],
+            leaf_index: 0,
+            value: Fr::zero(),
+            min: Fr::zero(),
+            max: Fr::zero(),
+            sig_r: C::Scalar::ZERO,
+            sig_s: C::Scalar::ZERO,
+            pk_x: C::Base::ZERO,
+            pk_y: C::Base::ZERO,
+            holder_secret: Fr::zero(),
+            app_scope: Fr::zero(),
+        }
+    }
+
+    fn from_input(json: &str) -> std::result::Result<Self, InputError> {
+        IdentityClaimInput::from_json(json)?.to_circuit()
+    }
+
+    fn instances(&self) -> Vec<Vec<Fr>> {
+        IdentityClaimCircuit::instances(self)
+    }
+
+    fn public_inputs_json(instances: &[Vec<Fr>]) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&IdentityClaimPublicInputs::from_instances(instances)?)?)
+    }
+
+    fn parse_public_inputs(json: &str) -> std::result::Result<Vec<Vec<Fr>>, InputError> {
+        serde_json::from_str::<IdentityClaimPublicInputs>(json)?.to_instances()
+    }
+}
+
+impl CliCircuit for PostProofCircuit {
+    const NUM_INSTANCES: usize = 4;
+
+    fn with_depth(depth: usize) -> Self {
+        PostProofCircuit {
+            value: Fr::zero(),
+            min: Fr::zero(),
+            max: Fr::zero(),
+            holder_secret: Fr::zero(),
+            app_scope: Fr::zero(),
+            post_hash: Fr::zero(),
+            merkle_root: Fr::zero(),
+            merkle_proof: vec![Fr::zero(); depth],
+            leaf_index: 0,
+        }
+    }
+
+    fn from_input(json: &str) -> std::result::Result<Self, InputError> {
+        PostProofInput::from_json(json)?.to_circuit()
+    }
+
+    fn instances(&self) -> Vec<Vec<Fr>> {
+        PostProofCircuit::instances(self)
+    }
+
+    fn public_inputs_json(instances: &[Vec<Fr>]) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&PostProofPublicInputs::from_instances(instances)?)?)
+    }
+
+    fn parse_public_inputs(json: &str) -> std::result::Result<Vec<Vec<Fr>>, InputError> {
+        serde_json::from_str::<PostProofPublicInputs>(json)?.to_instances()
+    }
+}
+
+/// CircuitKind에 맞는 회로 타입으로 제네릭 함수 호출
+macro_rules! dispatch {
+    ($kind:expr, $f:ident($($arg:expr),*)) => {
+        match $kind {
+            CircuitKind::GroupAccess => $f::<GroupAccessCircuit>($($arg),*),
+            CircuitKind::IdentityClaim => $f::<IdentityClaimCircuit<G1Affine>>($($arg),*),
+            CircuitKind::Es256IdentityClaim => $f::<IdentityClaimCircuit<Secp256r1Affine>>($($arg),*),
+            CircuitKind::PostProof => $f::<PostProofCircuit>($($arg),*),
+        }
+    };
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Setup { k, params, from } => setup(k, params, from),
+        Command::Keygen { circuit, depth, params, pk, vk } => {
+            dispatch!(circuit, keygen(depth, params, pk, vk))
+        }
+        Command::Prove { circuit, input, params, pk, proof, instances } => {
+            dispatch!(circuit, prove(input, params, pk, proof, instances))
+        }
+        Command::Verify { circuit, params, vk, proof, instances } => {
+            dispatch!(circuit, verify(params, vk, proof, instances))
+        }
+        Command::Fingerprint { circuit, params, vk } => dispatch!(circuit, fingerprint(params, vk)),
+        Command::GenSolidity { circuit, params, vk, out } => {
+            dispatch!(circuit, gen_solidity(params, vk, out))
+        }
+    }
+}
+
+fn setup(k: u32, path: PathBuf, from: Option<PathBuf>) -> Result<()> {
+    if let Some(from) = from {
+        keys::write_params(&path, &ceremony::load_params(&from, Some(k))?)?;
+        println!("wrote params with k = {k} from {} to {}", from.display(), path.display());
+    } else if path.exists() {
+        let params = keys::read_params(&path)?;
+        println!("loaded params with k = {} from {}", params.k(), path.display());
+    } else {
+        keys::write_params(&path, &prover::setup_params(k))?;
+        println!("wrote params with k = {k} to {}", path.display());
+    }
+    Ok(())
+}
+
+fn keygen<C: CliCircuit>(depth: usize, params: PathBuf, pk_path: PathBuf, vk_path: PathBuf) -> Result<()> {
+    let circuit = C::with_depth(depth).without_witnesses();
+    let params = keys::read_params(&params)?;
+    let pk = prover::keygen(&params, &circuit)?;
+    keys::write_pk(&pk_path, &circuit, &pk)?;
+    keys::write_vk(&vk_path, &circuit, pk.get_vk())?;
+    println!("wrote {} and {}", pk_path.display(), vk_path.display());
+    println!("fingerprint {}", Fingerprint::of(pk.get_vk()));
+    Ok(())
+}
+
+fn prove<C: CliCircuit>(
+    input: PathBuf,
+    params: PathBuf,
+    pk: PathBuf,
+    proof_path: PathBuf,
+    instances_path: PathBuf,
+) -> Result<()> {
+    let circuit = C::from_input(&fs::read_to_string(input)?)?;
+    let instances = circuit.instances();
+    let params = keys::read_params(&params)?;
+    let pk = keys::read_pk(&pk, &circuit, &params)?;
+    let envelope = ProofEnvelope::prove(&params, &pk, circuit, instances.clone())?;
+    if is_json(&proof_path) {
+        fs::write(&proof_path, envelope.to_json())?;
+    } else {
+        fs::write(&proof_path, envelope.encode())?;
+    }
+    fs::write(&instances_path, C::public_inputs_json(&instances)?)?;
+    println!("wrote {} and {}", proof_path.display(), instances_path.display());
+    println!("fingerprint {}", envelope.fingerprint);
+    Ok(())
+}
+
+fn verify<C: CliCircuit>(params: PathBuf, vk: PathBuf, proof: PathBuf, instances: Option<PathBuf>) -> Result<()> {
+    let params = keys::read_params(&params)?;
+    let (_, vk) = keys::read_vk::<C>(&vk, None, &params)?;
+    let envelope = if is_json(&proof) {
+        ProofEnvelope::from_json(&fs::read_to_string(&proof)?)?
+    } else {
+        ProofEnvelope::decode(&fs::read(&proof)?)?
+    };
+    if let Some(instances) = instances {
+        if C::parse_public_inputs(&fs::read_to_string(instances)?)? != envelope.instances {
+            return Err("public inputs do not match the proof envelope".into());
+        }
+    }
+    envelope.verify(&params, &vk)?;
+    println!("proof is valid");
+    Ok(())
+}
+
+fn is_json(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension == "json")
+}
+
+fn gen_solidity<C: CliCircuit>(params: PathBuf, vk: PathBuf, out: PathBuf) -> Result<()> {
+    let params = keys::read_params(&params)?;
+    let (_, vk) = keys::read_vk::<C>(&vk, None, &params)?;
+    let source = prover::gen_solidity(&params, &vk, C::NUM_INSTANCES)?;
+    fs::write(&out, Fingerprint::of(&vk).embed_in_solidity(&source))?;
+    println!("wrote {}", out.display());
+    Ok(())
+}
+
+fn fingerprint<C: CliCircuit>(params: PathBuf, vk: PathBuf) -> Result<()> {
+    let params = keys::read_params(&params)?;
+    let (header, vk) = keys::read_vk::<C>(&vk, None, &params)?;
+    println!("circuit {} {:?}", header.circuit_id, header.parameters);
+    println!("fingerprint {}", Fingerprint::of(&vk));
+    Ok(())
+}
@@ -0,0 +1,322 @@
+use halo2::circuit::Value;
+use halo2::plonk::Error;
+use halo2curves::bn256::Fr;
+use maingate::{AssignedCondition, AssignedValue, MainGateInstructions, RegionCtx};
+
+use crate::gadgets::comparison::{ComparisonChip, COMPARISON_BITS};
+use crate::gadgets::poseidon::PoseidonGadget;
+
+// encode()에서 쓰는 노드 태그
+const TAG_GTE: u64 = 1;
+const TAG_LTE: u64 = 2;
+const TAG_EQ: u64 = 3;
+const TAG_IN_SET: u64 = 4;
+const TAG_AND: u64 = 5;
+const TAG_OR: u64 = 6;
+const TAG_NOT: u64 = 7;
+
+/// 하나의 attribute에 대한 조건. attribute는 credential attribute 벡터의 index
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Condition {
+    Gte { attribute: usize, value: u64 },
+    Lte { attribute: usize, value: u64 },
+    Eq { attribute: usize, value: u64 },
+    InSet { attribute: usize, set: Vec<u64> },
+}
+
+/// 조건들의 boolean 조합
+/// 예: `(score >= 700 AND country in EU) OR verified_by_admin`
+/// ```ignore
+/// Policy::gte(SCORE, 700)
+///     .and(Policy::in_set(COUNTRY, EU))
+///     .or(Policy::eq(VERIFIED_BY_ADMIN, 1))
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Policy {
+    Condition(Condition),
+    And(Vec<Policy>),
+    Or(Vec<Policy>),
+    Not(Box<Policy>),
+}
+
+impl Policy {
+    pub fn gte(attribute: usize, value: u64) -> Self {
+        Policy::Condition(Condition::Gte { attribute, value })
+    }
+
+    pub fn lte(attribute: usize, value: u64) -> Self {
+        Policy::Condition(Condition::Lte { attribute, value })
+    }
+
+    pub fn eq(attribute: usize, value: u64) -> Self {
+        Policy::Condition(Condition::Eq { attribute, value })
+    }
+
+    pub fn in_set(attribute: usize, set: impl Into<Vec<u64>>) -> Self {
+        Policy::Condition(Condition::InSet { attribute, set: set.into() })
+    }
+
+    pub fn and(self, other: Policy) -> Self {
+        match self {
+            Policy::And(mut children) => {
+                children.push(other);
+                Policy::And(children)
+            }
+            policy => Policy::And(vec![policy, other]),
+        }
+    }
+
+    pub fn or(self, other: Policy) -> Self {
+        match self {
+            Policy::Or(mut children) => {
+                children.push(other);
+                Policy::Or(children)
+            }
+            policy => Policy::Or(vec![policy, other]),
+        }
+    }
+
+    pub fn not(self) -> Self {
+        Policy::Not(Box::new(self))
+    }
+
+    /// policy가 참조하는 가장 큰 attribute index + 1
+    pub fn num_attributes(&self) -> usize {
+        match self {
+            Policy::Condition(
+                Condition::Gte { attribute, .. }
+                | Condition::Lte { attribute, .. }
+                | Condition::Eq { attribute, .. }
+                | Condition::InSet { attribute, .. },
+            ) => attribute + 1,
+            Policy::And(children) | Policy::Or(children) => {
+                children.iter().map(Policy::num_attributes).max().unwrap_or(0)
+            }
+            Policy::Not(child) => child.num_attributes(),
+        }
+    }
+
+    /// native 평가 (witness 검증용)
+    pub fn evaluate(&self, attributes: &[u64]) -> bool {
+        match self {
+            Policy::Condition(Condition::Gte { attribute, value }) => attributes[*attribute] >= *value,
+            Policy::Condition(Condition::Lte { attribute, value }) => attributes[*attribute] <= *value,
+            Policy::Condition(Condition::Eq { attribute, value }) => attributes[*attribute] == *value,
+            Policy::Condition(Condition::InSet { attribute, set }) => set.contains(&attributes[*attribute]),
+            Policy::And(children) => children.iter().all(|c| c.evaluate(attributes)),
+            Policy::Or(children) => children.iter().any(|c| c.evaluate(attributes)),
+            Policy::Not(child) => !child.evaluate(attributes),
+        }
+    }
+
+    /// 전위 순회 인코딩. 태그 뒤에 attribute/상수 또는 자식 개수가 온다
+    pub fn encode(&self) -> Vec<Fr> {
+        let mut out = vec![];
+        self.encode_into(&mut out);
+        out
+    }
+
+    fn encode_into(&self, out: &mut Vec<Fr>) {
+        match self {
+            Policy::Condition(Condition::Gte { attribute, value }) => {
+                out.extend([TAG_GTE, *attribute as u64, *value].map(Fr::from))
+            }
+            Policy::Condition(Condition::Lte { attribute, value }) => {
+                out.extend([TAG_LTE, *attribute as u64, *value].map(Fr::from))
+            }
+            Policy::Condition(Condition::Eq { attribute, value }) => {
+                out.extend([TAG_EQ, *attribute as u64, *value].map(Fr::from))
+            }
+            Policy::Condition(Condition::InSet { attribute, set }) => {
+                out.extend([TAG_IN_SET, *attribute as u64, set.len() as u64].map(Fr::from));
+                out.extend(set.iter().map(|v| Fr::from(*v)));
+            }
+            Policy::And(children) | Policy::Or(children) => {
+                let tag = if matches!(self, Policy::And(_)) { TAG_AND } else { TAG_OR };
+                out.extend([tag, children.len() as u64].map(Fr::from));
+                children.iter().for_each(|c| c.encode_into(out));
+            }
+            Policy::Not(child) => {
+                out.push(Fr::from(TAG_NOT));
+                child.encode_into(out);
+            }
+        }
+    }
+
+    /// public input으로 노출되는 policy hash
+    pub fn hash(&self) -> Fr {
+        PoseidonGadget::hash_chain_native(&self.encode())
+    }
+
+    /// 인코딩을 constant 셀로 할당. 회로 안에서 policy hash를 계산할 때 사용
+    /// (첫 원소는 hash_chain의 length 셀)
+    pub fn assign_encoding(
+        &self,
+        ctx: &mut RegionCtx<'_, Fr>,
+        chip: &ComparisonChip<Fr>,
+    ) -> Result<(AssignedValue<Fr>, Vec<AssignedValue<Fr>>), Error> {
+        let main_gate = chip.main_gate();
+        let encoding = self.encode();
+        let length = main_gate.assign_constant(ctx, Fr::from(encoding.len() as u64))?;
+        let cells = encoding
+            .into_iter()
+            .map(|v| main_gate.assign_constant(ctx, v))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((length, cells))
+    }
+
+    /// policy를 comparison/set membership gadget 호출로 컴파일
+    /// attributes는 COMPARISON_BITS 이내로 range check된 셀이어야 함
+    pub fn synthesize(
+        &self,
+        ctx: &mut RegionCtx<'_, Fr>,
+        chip: &ComparisonChip<Fr>,
+        attributes: &[AssignedValue<Fr>],
+    ) -> Result<AssignedCondition<Fr>, Error> {
+        let main_gate = chip.main_gate();
+        match self {
+            Policy::Condition(Condition::Gte { attribute, value }) => {
+                let value = main_gate.assign_constant(ctx, Fr::from(*value))?;
+                chip.is_greater_or_equal(ctx, &attributes[*attribute], &value, COMPARISON_BITS)
+            }
+            Policy::Condition(Condition::Lte { attribute, value }) => {
+                let value = main_gate.assign_constant(ctx, Fr::from(*value))?;
+                chip.is_less_or_equal(ctx, &attributes[*attribute], &value, COMPARISON_BITS)
+            }
+            Policy::Condition(Condition::Eq { attribute, value }) => {
+                let value = main_gate.assign_constant(ctx, Fr::from(*value))?;
+                main_gate.is_equal(ctx, &attributes[*attribute], &value)
+            }
+            Policy::Condition(Condition::InSet { attribute, set }) => {
+                let set: Vec<Fr> = set.iter().map(|v| Fr::from(*v)).collect();
+                chip.is_in_set(ctx, &attributes[*attribute], &set)
+            }
+            Policy::And(children) => {
+                let mut acc = main_gate.assign_constant(ctx, Fr::one())?;
+                for child in children {
+                    let c = child.synthesize(ctx, chip, attributes)?;
+                    acc = main_gate.and(ctx, &acc, &c)?;
+                }
+                Ok(acc)
+            }
+            Policy::Or(children) => {
+                let mut acc = main_gate.assign_constant(ctx, Fr::zero())?;
+                for child in children {
+                    let c = child.synthesize(ctx, chip, attributes)?;
+                    acc = main_gate.or(ctx, &acc, &c)?;
+                }
+                Ok(acc)
+            }
+            Policy::Not(child) => {
+                let c = child.synthesize(ctx, chip, attributes)?;
+                main_gate.not(ctx, &c)
+            }
+        }
+    }
+}
+
+/// attribute 값을 witness로 할당 (COMPARISON_BITS range check 포함)
+pub fn assign_attributes(
+    ctx: &mut RegionCtx<'_, Fr>,
+    chip: &ComparisonChip<Fr>,
+    attributes: &[Fr],
+) -> Result<Vec<AssignedValue<Fr>>, Error> {
+    attributes
+        .iter()
+        .map(|v| chip.assign_bounded(ctx, Value::known(*v), COMPARISON_BITS))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::comparison::ComparisonConfig;
+    use crate::test_utils::{assert_permutation_failure, mock_prove};
+    use halo2::circuit::{Layouter, SimpleFloorPlanner};
+    use halo2::plonk::{Circuit, Column, ConstraintSystem, Instance};
+
+    const SCORE: usize = 0;
+    const COUNTRY: usize = 1;
+    const VERIFIED: usize = 2;
+
+    fn policy() -> Policy {
+        Policy::gte(SCORE, 700)
+            .and(Policy::in_set(COUNTRY, [82, 410]))
+            .or(Policy::eq(VERIFIED, 1))
+    }
+
+    /// policy.synthesize 결과를 public input 0에 노출
+    #[derive(Clone)]
+    struct PolicyCircuit {
+        policy: Policy,
+        attributes: Vec<u64>,
+    }
+
+    impl Circuit<Fr> for PolicyCircuit {
+        type Config = (ComparisonConfig, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+            (ComparisonChip::configure(meta), instance)
+        }
+
+        fn synthesize(&self, (config, instance): Self::Config, mut layouter: impl Layouter<Fr>) -> Result<(), Error> {
+            let chip = ComparisonChip::construct(config);
+            chip.load_table(&mut layouter)?;
+            let attributes: Vec<Fr> = self.attributes.iter().map(|v| Fr::from(*v)).collect();
+            let result = layouter.assign_region(
+                || "policy",
+                |region| {
+                    let mut ctx = RegionCtx::new(region, 0);
+                    let attributes = assign_attributes(&mut ctx, &chip, &attributes)?;
+                    self.policy.synthesize(&mut ctx, &chip, &attributes)
+                },
+            )?;
+            layouter.constrain_instance(result.cell(), instance, 0)
+        }
+    }
+
+    #[test]
+    fn evaluates_natively() {
+        let policy = policy();
+        assert!(policy.evaluate(&[700, 410, 0]));
+        assert!(policy.evaluate(&[0, 0, 1]));
+        assert!(!policy.evaluate(&[699, 410, 0]));
+        assert!(!policy.evaluate(&[700, 1, 0]));
+        assert!(policy.not().evaluate(&[700, 1, 0]));
+        assert!(Policy::lte(0, u64::MAX).evaluate(&[u64::MAX]));
+        assert_eq!(policy().num_attributes(), 3);
+    }
+
+    #[test]
+    fn circuit_matches_native_evaluation() {
+        let policies = [policy(), policy().not(), Policy::lte(SCORE, 700), Policy::in_set(COUNTRY, [0u64; 0])];
+        let attributes = [[700, 410, 0], [699, 410, 0], [700, 1, 0], [0, 0, 1], [u64::MAX, 82, 2]];
+        for policy in &policies {
+            for attributes in attributes {
+                let expected = Fr::from(policy.evaluate(&attributes) as u64);
+                let circuit = PolicyCircuit { policy: policy.clone(), attributes: attributes.to_vec() };
+                assert_eq!(mock_prove(&circuit, vec![vec![expected]]), Ok(()), "{policy:?} on {attributes:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_result() {
+        let circuit = PolicyCircuit { policy: policy(), attributes: vec![699, 410, 0] };
+        assert_permutation_failure(mock_prove(&circuit, vec![vec![Fr::one()]]));
+    }
+
+    #[test]
+    fn encoding_distinguishes_policies() {
+        assert_ne!(Policy::gte(0, 1).hash(), Policy::lte(0, 1).hash());
+        assert_ne!(policy().hash(), policy().not().hash());
+        assert_ne!(Policy::in_set(0, [1, 2]).hash(), Policy::in_set(0, [1]).and(Policy::eq(0, 2)).hash());
+    }
+}
@@ -0,0 +1,343 @@
+use halo2curves::bn256::{Fq, Fr, G1Affine};
+use poseidon::{Pow5Chip, Pow5Config, P128Pow5T3};
+use halo2::plonk::{Circuit, Column, ConstraintSystem, Error, Instance};
+use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+use maingate::{MainGateInstructions, RegionCtx};
+use crate::gadgets::comparison::{ComparisonChip, ComparisonConfig};
+use crate::gadgets::merkle::{MerkleConfig, MerkleGadget};
+use crate::gadgets::poseidon::PoseidonGadget;
+use crate::gadgets::signature::{SignatureChip, SignatureConfig};
+use crate::issuer::ecdsa_key_hash;
+use crate::policy::{assign_attributes, Policy};
+
+/// instance column 레이아웃
+pub const MERKLE_ROOT_ROW: usize = 0;
+pub const POLICY_HASH_ROW: usize = 1;
+pub const ISSUER_KEY_HASH_ROW: usize = 2;
+pub const NULLIFIER_ROW: usize = 3;
+pub const APP_SCOPE_ROW: usize = 4;
+
+/// IdentityClaimCircuit 구성 요소(Poseidon, Merkle, ECDSA)를 그대로 쓰고
+/// range check 대신 임의의 Policy를 증명하는 claim 회로
+///
+/// credential hash = hash_chain(attributes, Poseidon(holder_secret)), issuer가 이 값에 서명하고
+/// credential hash가 merkle tree의 leaf가 됨
+/// holder_secret을 모르면 서명을 가지고 있어도 증명할 수 없고, nullifier는 `IdentityClaimCircuit`과 같음
+/// public input: [merkle_root, policy_hash, issuer_key_hash, nullifier, app_scope]
+#[derive(Clone, Debug)]
+pub struct PolicyClaimCircuit {
+    pub policy: Policy,
+    pub attributes: Vec<Fr>,
+    pub merkle_proof: Vec<Fr>,
+    pub leaf_index: usize,
+    pub sig_r: Fr,
+    pub sig_s: Fr,
+    pub pk_x: Fq,
+    pub pk_y: Fq,
+    /// credential 소유자만 아는 비밀값
+    pub holder_secret: Fr,
+    /// 검증자(앱) 식별자. nullifier의 scope
+    pub app_scope: Fr,
+}
+
+#[derive(Clone, Debug)]
+pub struct PolicyClaimConfig {
+    pub comparison: ComparisonConfig,
+    pub signature: SignatureConfig,
+    pub poseidon: Pow5Config<Fr, 3, 2>,
+    pub merkle: MerkleConfig,
+    pub instance: Column<Instance>,
+}
+
+impl PolicyClaimCircuit {
+    /// issuer가 서명하는 credential hash (merkle leaf)
+    pub fn credential_hash(attributes: &[Fr], holder_secret: Fr) -> Fr {
+        let mut inputs = attributes.to_vec();
+        inputs.push(PoseidonGadget::hash_native([holder_secret]));
+        PoseidonGadget::hash_chain_native(&inputs)
+    }
+
+    pub fn instances(&self, merkle_root: Fr) -> Vec<Vec<Fr>> {
+        vec![vec![
+            merkle_root,
+            self.policy.hash(),
+            ecdsa_key_hash(&self.pk_x, &self.pk_y),
+            PoseidonGadget::hash_native([self.holder_secret, self.app_scope]),
+            self.app_scope,
+        ]]
+    }
+}
+
+impl Circuit<Fr> for PolicyClaimCircuit {
+    type Config = PolicyClaimConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    // policy는 회로 모양을 결정하므로 witness가 아님
+    fn without_witnesses(&self) -> Self {
+        Self {
+            policy: self.policy.clone(),
+            attributes: vec![Fr::zero(); self.attributes.len()],
+            merkle_proof: vec![Fr::zero(); self.merkle_proof.len()],
+            leaf_index: 0,
+            sig_r: Fr::zero(),
+            sig_s: Fr::zero(),
+            pk_x: Fq::zero(),
+            pk_y: Fq::zero(),
+            holder_secret: Fr::zero(),
+            app_scope: Fr::zero(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+        let comparison = ComparisonChip::configure(meta);
+        let signature = SignatureChip::<G1Affine, Fr, 4, 68>::configure(meta);
+
+        let state = [meta.advice_column(), meta.advice_column(), meta.advice_column()];
+        let partial_sbox = meta.advice_column();
+        let rc_a = [meta.fixed_column(), meta.fixed_column(), meta.fixed_column()];
+        let rc_b = [meta.fixed_column(), meta.fixed_column(), meta.fixed_column()];
+        meta.enable_constant(rc_b[0]);
+
+        let poseidon = Pow5Chip::<Fr, 3, 2>::configure::<P128Pow5T3>(
+            meta,
+            state,
+            partial_sbox,
+            rc_a,
+            rc_b,
+        );
+
+        let merkle = MerkleGadget::configure(meta);
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        PolicyClaimConfig { comparison, signature, poseidon, merkle, instance }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fr>,
+    ) -> Result<(), Error> {
+        // policy가 credential에 없는 attribute를 참조함
+        if self.attributes.len() < self.policy.num_attributes() {
+            return Err(Error::Synthesis);
+        }
+        let comparison_chip = ComparisonChip::<Fr>::construct(config.comparison.clone());
+        comparison_chip.load_table(&mut layouter)?;
+        config.signature.config_range(&mut layouter)?;
+
+        // 1. attribute 할당 + policy 평가 (결과는 반드시 1)
+        let (attributes, holder_secret, app_scope, policy_length, policy_encoding, path) = layouter.assign_region(
+            || "policy",
+            |region| {
+                let mut ctx = RegionCtx::new(region, 0);
+                let main_gate = comparison_chip.main_gate();
+
+                let attributes = assign_attributes(&mut ctx, &comparison_chip, &self.attributes)?;
+                let result = self.policy.synthesize(&mut ctx, &comparison_chip, &attributes)?;
+                main_gate.assert_one(&mut ctx, &result)?;
+                let holder_secret = main_gate.assign_value(&mut ctx, Value::known(self.holder_secret))?;
+                let app_scope = main_gate.assign_value(&mut ctx, Value::known(self.app_scope))?;
+
+                let (policy_length, policy_encoding) =
+                    self.policy.assign_encoding(&mut ctx, &comparison_chip)?;
+                let path = self
+                    .merkle_proof
+                    .iter()
+                    .map(|v| main_gate.assign_value(&mut ctx, Value::known(*v)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok((attributes, holder_secret, app_scope, policy_length, policy_encoding, path))
+            },
+        )?;
+
+        // 2. credential hash, policy hash, nullifier
+        let chip = Pow5Chip::<Fr, 3, 2>::construct(config.poseidon.clone());
+        let holder_commitment = PoseidonGadget::hash::<1>(
+            &chip,
+            layouter.namespace(|| "holder commitment"),
+            [holder_secret.clone()],
+        )?;
+        let nullifier = PoseidonGadget::hash::<2>(
+            &chip,
+            layouter.namespace(|| "nullifier"),
+            [holder_secret, app_scope.clone()],
+        )?;
+        let credential_inputs: Vec<_> = attributes.iter().cloned().chain([holder_commitment]).collect();
+        let credential_length = layouter.assign_region(
+            || "attributes length",
+            |region| {
+                let mut ctx = RegionCtx::new(region, 0);
+                comparison_chip
+                    .main_gate()
+                    .assign_constant(&mut ctx, Fr::from(credential_inputs.len() as u64))
+            },
+        )?;
+        let credential_hash = PoseidonGadget::hash_chain(
+            &chip,
+            layouter.namespace(|| "credential hash"),
+            credential_length,
+            &credential_inputs,
+        )?;
+        let policy_hash = PoseidonGadget::hash_chain(
+            &chip,
+            layouter.namespace(|| "policy hash"),
+            policy_length,
+            &policy_encoding,
+        )?;
+
+        // 3. Merkle root
+        let indices: Vec<bool> = (0..self.merkle_proof.len())
+            .map(|i| (self.leaf_index >> i) & 1 == 1)
+            .collect();
+        let merkle_root = MerkleGadget::compute_root(
+            &config.merkle,
+            &chip,
+            &mut layouter,
+            credential_hash.clone(),
+            &path,
+            &indices,
+        )?;
+
+        // 4. issuer 서명 검증. msg_hash는 credential hash와 같아야 함
+        let credential_hash_value = Self::credential_hash(&self.attributes, self.holder_secret);
+        let (key_length, key_limbs) = layouter.assign_region(
+            || "ecdsa verify",
+            |region| {
+                let mut ctx = RegionCtx::new(region, 0);
+                let signature_chip =
+                    SignatureChip::<G1Affine, Fr, 4, 68>::construct(&mut ctx, &config.signature)?;
+
+                let assigned_pk = signature_chip.assign_public_key(&mut ctx, (self.pk_x, self.pk_y))?;
+                let assigned_sig = signature_chip.assign_signature(&mut ctx, (self.sig_r, self.sig_s))?;
+                let assigned_msg_hash = signature_chip.assign_integer(&mut ctx, credential_hash_value)?;
+                ctx.constrain_equal(assigned_msg_hash.native().cell(), credential_hash.cell())?;
+                signature_chip.verify(&mut ctx, &assigned_sig, &assigned_pk, &assigned_msg_hash)?;
+
+                let key_limbs = SignatureChip::<G1Affine, Fr, 4, 68>::public_key_limbs(&assigned_pk);
+                let key_length = comparison_chip
+                    .main_gate()
+                    .assign_constant(&mut ctx, Fr::from(key_limbs.len() as u64))?;
+                Ok((key_length, key_limbs))
+            },
+        )?;
+
+        // 5. 서명한 키를 issuer key hash로 공개 (검증자가 신뢰하는 issuer인지 확인)
+        let issuer_key_hash = PoseidonGadget::hash_chain(
+            &chip,
+            layouter.namespace(|| "issuer key hash"),
+            key_length,
+            &key_limbs,
+        )?;
+
+        // 6. public input
+        layouter.constrain_instance(merkle_root.cell(), config.instance, MERKLE_ROOT_ROW)?;
+        layouter.constrain_instance(policy_hash.cell(), config.instance, POLICY_HASH_ROW)?;
+        layouter.constrain_instance(issuer_key_hash.cell(), config.instance, ISSUER_KEY_HASH_ROW)?;
+        layouter.constrain_instance(nullifier.cell(), config.instance, NULLIFIER_ROW)?;
+        layouter.constrain_instance(app_scope.cell(), config.instance, APP_SCOPE_ROW)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::audit;
+    use halo2curves::CurveAffine;
+    use crate::test_utils::{
+        assert_constraint_failure, assert_permutation_failure, ecdsa_keypair, ecdsa_sign, merkle_proof, mock_prove,
+        random_fr,
+    };
+
+    const DEPTH: usize = 4;
+
+    /// (score, country) credential과 `score >= 700 AND country in {82, 410}` policy
+    fn valid_circuit(score: u64, country: u64) -> (PolicyClaimCircuit, Fr) {
+        let attributes = vec![Fr::from(score), Fr::from(country)];
+        let holder_secret = random_fr();
+        let credential_hash = PolicyClaimCircuit::credential_hash(&attributes, holder_secret);
+        let leaf_index = 5;
+        let (merkle_proof, merkle_root) = merkle_proof(credential_hash, DEPTH, leaf_index);
+
+        let (sk, pk) = ecdsa_keypair::<G1Affine>();
+        let (sig_r, sig_s) = ecdsa_sign::<G1Affine>(sk, credential_hash);
+        let coordinates = pk.coordinates().unwrap();
+        let circuit = PolicyClaimCircuit {
+            policy: Policy::gte(0, 700).and(Policy::in_set(1, [82, 410])),
+            attributes,
+            merkle_proof,
+            leaf_index,
+            sig_r,
+            sig_s,
+            pk_x: *coordinates.x(),
+            pk_y: *coordinates.y(),
+            holder_secret,
+            app_scope: random_fr(),
+        };
+        (circuit, merkle_root)
+    }
+
+    #[test]
+    fn accepts_satisfied_policy() {
+        let (circuit, root) = valid_circuit(700, 410);
+        assert_eq!(mock_prove(&circuit, circuit.instances(root)), Ok(()));
+    }
+
+    #[test]
+    fn rejects_unsatisfied_policy() {
+        // 서명, merkle proof는 유효하지만 score가 부족함
+        let (circuit, root) = valid_circuit(699, 410);
+        assert_constraint_failure(mock_prove(&circuit, circuit.instances(root)));
+    }
+
+    #[test]
+    fn rejects_other_policy_hash() {
+        let (circuit, root) = valid_circuit(700, 410);
+        let mut instances = circuit.instances(root);
+        instances[0][POLICY_HASH_ROW] = Policy::gte(0, 600).hash();
+        assert_permutation_failure(mock_prove(&circuit, instances));
+    }
+
+    #[test]
+    fn rejects_credential_signed_by_other_key() {
+        // 검증자가 기대하는 issuer가 아닌 키로 서명한 credential
+        let (issuer, root) = valid_circuit(700, 410);
+        let (forged, _) = valid_circuit(700, 410);
+        let forged = PolicyClaimCircuit { merkle_proof: issuer.merkle_proof.clone(), ..forged };
+        let mut instances = forged.instances(root);
+        instances[0][ISSUER_KEY_HASH_ROW] = issuer.instances(root)[0][ISSUER_KEY_HASH_ROW];
+        assert_permutation_failure(mock_prove(&forged, instances));
+    }
+
+    #[test]
+    fn rejects_other_holder_secret() {
+        // 서명된 credential을 가져와도 holder_secret을 모르면 증명할 수 없음
+        let (mut circuit, root) = valid_circuit(700, 410);
+        circuit.holder_secret = random_fr();
+        assert_permutation_failure(mock_prove(&circuit, circuit.instances(root)));
+    }
+
+    #[test]
+    fn rejects_wrong_nullifier() {
+        let (circuit, root) = valid_circuit(700, 410);
+        let mut instances = circuit.instances(root);
+        instances[0][NULLIFIER_ROW] += Fr::one();
+        assert_permutation_failure(mock_prove(&circuit, instances));
+    }
+
+    #[test]
+    fn missing_attribute_is_a_synthesis_error() {
+        let (mut circuit, root) = valid_circuit(700, 410);
+        circuit.attributes.pop();
+        let result = halo2::dev::MockProver::run(18, &circuit, circuit.instances(root));
+        assert!(matches!(result, Err(Error::Synthesis)));
+    }
+
+    #[test]
+    fn is_clean_under_audit() {
+        let (circuit, _) = valid_circuit(700, 410);
+        let report = audit(&circuit).unwrap();
+        assert!(report.is_clean(), "{report}");
+    }
+}
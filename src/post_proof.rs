@@ -1,42 +1,237 @@
 use halo2curves::bn256::Fr;
 use halo2::{
     circuit::{Layouter, SimpleFloorPlanner, Value},
-    plonk::{Circuit, ConstraintSystem, Error},
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance},
 };
+use halo2curves::bn256::G1Affine;
+use poseidon::{Pow5Chip, Pow5Config, P128Pow5T3};
+use crate::gadgets::merkle::{MerkleConfig, MerkleGadget};
+use crate::gadgets::poseidon::PoseidonGadget;
+use crate::identity_claim::IdentityClaimCircuit;
 
+/// instance column 레이아웃
+pub const MERKLE_ROOT_ROW: usize = 0;
+pub const POST_HASH_ROW: usize = 1;
+pub const NULLIFIER_ROW: usize = 2;
+pub const APP_SCOPE_ROW: usize = 3;
+
+/// 등록된 claim을 가진 사람이 글(post_hash)을 올렸음을 증명
+/// claim hash를 credential과 holder_secret에서 계산해 merkle_root 아래의 leaf임을 보이므로
+/// leaf만 알아서는 증명할 수 없음. post_hash를 public input에 묶어 proof를 다른 글에 재사용할 수 없게 함
+/// nullifier = Poseidon(holder_secret, app_scope)로 app_scope(게시판, epoch 등)마다 한 번으로 제한
+/// public input: [merkle_root, post_hash, nullifier, app_scope]
 #[derive(Clone, Debug)]
 pub struct PostProofCircuit {
-    pub claim_hash: Fr,
+    /// identity claim과 같은 credential (claim hash의 입력)
+    pub value: Fr,
+    pub min: Fr,
+    pub max: Fr,
+    pub holder_secret: Fr,
+    pub app_scope: Fr,
     pub post_hash: Fr,
     pub merkle_root: Fr,
     pub merkle_proof: Vec<Fr>,
     pub leaf_index: usize,
 }
 
+#[derive(Clone, Debug)]
+pub struct PostProofConfig {
+    pub witness: Column<Advice>,
+    pub poseidon: Pow5Config<Fr, 3, 2>,
+    pub merkle: MerkleConfig,
+    pub instance: Column<Instance>,
+}
+
+impl PostProofCircuit {
+    /// merkle leaf. identity claim의 claim hash와 같음
+    pub fn claim_hash(&self) -> Fr {
+        IdentityClaimCircuit::<G1Affine>::claim_hash(self.value, self.min, self.max, self.holder_secret)
+    }
+
+    pub fn instances(&self) -> Vec<Vec<Fr>> {
+        let mut row = vec![Fr::zero(); 4];
+        row[MERKLE_ROOT_ROW] = self.merkle_root;
+        row[POST_HASH_ROW] = self.post_hash;
+        row[NULLIFIER_ROW] = IdentityClaimCircuit::<G1Affine>::nullifier(self.holder_secret, self.app_scope);
+        row[APP_SCOPE_ROW] = self.app_scope;
+        vec![row]
+    }
+}
+
 impl Circuit<Fr> for PostProofCircuit {
-    type Config = ();
+    type Config = PostProofConfig;
     type FloorPlanner = SimpleFloorPlanner;
 
     fn without_witnesses(&self) -> Self {
         Self {
-            claim_hash: Fr::zero(),
+            value: Fr::zero(),
+            min: Fr::zero(),
+            max: Fr::zero(),
+            holder_secret: Fr::zero(),
+            app_scope: Fr::zero(),
             post_hash: Fr::zero(),
             merkle_root: Fr::zero(),
-            merkle_proof: vec![],
+            merkle_proof: vec![Fr::zero(); self.merkle_proof.len()],
             leaf_index: 0,
         }
     }
 
-    fn configure(_meta: &mut ConstraintSystem<Fr>) -> Self::Config {
-        // TODO: Claim, 상태, Merkle proof 등 제약조건
+    fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+        let witness = meta.advice_column();
+        meta.enable_equality(witness);
+
+        let state = [meta.advice_column(), meta.advice_column(), meta.advice_column()];
+        let partial_sbox = meta.advice_column();
+        let rc_a = [meta.fixed_column(), meta.fixed_column(), meta.fixed_column()];
+        let rc_b = [meta.fixed_column(), meta.fixed_column(), meta.fixed_column()];
+        meta.enable_constant(rc_b[0]);
+        let poseidon = Pow5Chip::<Fr, 3, 2>::configure::<P128Pow5T3>(
+            meta,
+            state,
+            partial_sbox,
+            rc_a,
+            rc_b,
+        );
+
+        let merkle = MerkleGadget::configure(meta);
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        PostProofConfig { witness, poseidon, merkle, instance }
     }
 
     fn synthesize(
         &self,
-        _config: Self::Config,
-        _layouter: impl Layouter<Fr>,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fr>,
     ) -> Result<(), Error> {
-        // Claim, 상태, Merkle proof 등 증명 로직
+        let (credential, holder_secret, app_scope, post_hash, path) = layouter.assign_region(
+            || "post proof witness",
+            |mut region| {
+                let mut assign = |name: &str, row: usize, value: Fr| {
+                    region.assign_advice(|| name.to_string(), config.witness, row, || Value::known(value))
+                };
+                let value = assign("value", 0, self.value)?;
+                let min = assign("min", 1, self.min)?;
+                let max = assign("max", 2, self.max)?;
+                let holder_secret = assign("holder_secret", 3, self.holder_secret)?;
+                let app_scope = assign("app_scope", 4, self.app_scope)?;
+                let post_hash = assign("post_hash", 5, self.post_hash)?;
+                let path = self
+                    .merkle_proof
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| assign(&format!("merkle_path_{i}"), i + 6, *v))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(([value, min, max], holder_secret, app_scope, post_hash, path))
+            },
+        )?;
+
+        // holder binding: leaf는 Poseidon(holder_secret)을 포함한 claim hash
+        let chip = Pow5Chip::<Fr, 3, 2>::construct(config.poseidon.clone());
+        let holder_commitment = PoseidonGadget::hash::<1>(
+            &chip,
+            layouter.namespace(|| "holder commitment"),
+            [holder_secret.clone()],
+        )?;
+        let [value, min, max] = credential;
+        let claim_hash = PoseidonGadget::hash::<4>(
+            &chip,
+            layouter.namespace(|| "claim hash"),
+            [value, min, max, holder_commitment],
+        )?;
+        let nullifier = PoseidonGadget::hash::<2>(
+            &chip,
+            layouter.namespace(|| "nullifier"),
+            [holder_secret, app_scope.clone()],
+        )?;
+
+        let indices: Vec<bool> = (0..self.merkle_proof.len())
+            .map(|i| (self.leaf_index >> i) & 1 == 1)
+            .collect();
+        let merkle_root = MerkleGadget::compute_root(&config.merkle, &chip, &mut layouter, claim_hash, &path, &indices)?;
+
+        layouter.constrain_instance(merkle_root.cell(), config.instance, MERKLE_ROOT_ROW)?;
+        layouter.constrain_instance(post_hash.cell(), config.instance, POST_HASH_ROW)?;
+        layouter.constrain_instance(nullifier.cell(), config.instance, NULLIFIER_ROW)?;
+        layouter.constrain_instance(app_scope.cell(), config.instance, APP_SCOPE_ROW)?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_permutation_failure, merkle_proof, mock_prove, random_fr};
+
+    const DEPTH: usize = 4;
+
+    fn valid_circuit() -> PostProofCircuit {
+        let mut circuit = PostProofCircuit {
+            value: Fr::from(30),
+            min: Fr::from(20),
+            max: Fr::from(40),
+            holder_secret: random_fr(),
+            app_scope: random_fr(),
+            post_hash: random_fr(),
+            merkle_root: Fr::zero(),
+            merkle_proof: vec![],
+            leaf_index: 9,
+        };
+        (circuit.merkle_proof, circuit.merkle_root) = merkle_proof(circuit.claim_hash(), DEPTH, circuit.leaf_index);
+        circuit
+    }
+
+    #[test]
+    fn accepts_registered_claim() {
+        let circuit = valid_circuit();
+        assert_eq!(mock_prove(&circuit, circuit.instances()), Ok(()));
+    }
+
+    #[test]
+    fn rejects_unregistered_claim() {
+        let mut circuit = valid_circuit();
+        let instances = circuit.instances();
+        circuit.value = Fr::from(31);
+        assert_permutation_failure(mock_prove(&circuit, instances));
+    }
+
+    #[test]
+    fn rejects_wrong_holder_secret() {
+        // leaf를 알아도 holder_secret 없이는 같은 root에 도달할 수 없음
+        // nullifier는 바꾼 secret으로 맞춰 주고 root만 원래 값
+        let mut circuit = valid_circuit();
+        circuit.holder_secret = random_fr();
+        assert_permutation_failure(mock_prove(&circuit, circuit.instances()));
+    }
+
+    #[test]
+    fn rejects_wrong_sibling() {
+        let mut circuit = valid_circuit();
+        let instances = circuit.instances();
+        circuit.merkle_proof[0] += Fr::one();
+        assert_permutation_failure(mock_prove(&circuit, instances));
+    }
+
+    #[test]
+    fn proof_is_bound_to_post() {
+        let circuit = valid_circuit();
+        let mut instances = circuit.instances();
+        instances[0][POST_HASH_ROW] = random_fr();
+        assert_permutation_failure(mock_prove(&circuit, instances));
+    }
+
+    #[test]
+    fn nullifier_is_scoped() {
+        let circuit = valid_circuit();
+        let mut instances = circuit.instances();
+        instances[0][NULLIFIER_ROW] = random_fr();
+        assert_permutation_failure(mock_prove(&circuit, instances));
+
+        // 같은 holder라도 scope가 다르면 nullifier가 다름
+        let mut other = circuit.clone();
+        other.app_scope = random_fr();
+        assert_ne!(other.instances()[0][NULLIFIER_ROW], circuit.instances()[0][NULLIFIER_ROW]);
+        assert_eq!(mock_prove(&other, other.instances()), Ok(()));
+    }
+}
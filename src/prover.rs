@@ -0,0 +1,68 @@
+//! 단일 회로의 KZG(SHPLONK) 키 생성, 증명, 검증, Solidity verifier 생성
+//! transcript는 Solidity verifier와 같은 Keccak256을 씀. 파일 입출력은 `keys`
+
+use halo2::plonk::{create_proof, keygen_pk, keygen_vk, verify_proof, Circuit, Error, ProvingKey, VerifyingKey};
+use halo2::poly::commitment::ParamsProver;
+use halo2::poly::kzg::commitment::{KZGCommitmentScheme, ParamsKZG};
+use halo2::poly::kzg::multiopen::{ProverSHPLONK, VerifierSHPLONK};
+use halo2::poly::kzg::strategy::SingleStrategy;
+use halo2::transcript::TranscriptWriterBuffer;
+use halo2_solidity_verifier::{BatchOpenScheme, Keccak256Transcript, SolidityGenerator};
+use halo2curves::bn256::{Bn256, Fr, G1Affine};
+use rand_core::OsRng;
+
+/// 테스트/개발용 params. 운영에서는 `ceremony::load_params`로 ceremony 결과를 읽어서 씀
+pub fn setup_params(k: u32) -> ParamsKZG<Bn256> {
+    ParamsKZG::<Bn256>::setup(k, OsRng)
+}
+
+/// circuit은 모양(merkle 깊이 등)만 쓰임
+pub fn keygen<C: Circuit<Fr>>(params: &ParamsKZG<Bn256>, circuit: &C) -> Result<ProvingKey<G1Affine>, Error> {
+    let vk = keygen_vk(params, circuit)?;
+    keygen_pk(params, vk, circuit)
+}
+
+pub fn prove<C: Circuit<Fr>>(
+    params: &ParamsKZG<Bn256>,
+    pk: &ProvingKey<G1Affine>,
+    circuit: C,
+    instances: &[Vec<Fr>],
+) -> Result<Vec<u8>, Error> {
+    let instances: Vec<&[Fr]> = instances.iter().map(Vec::as_slice).collect();
+    let mut transcript = Keccak256Transcript::new(Vec::new());
+    create_proof::<KZGCommitmentScheme<Bn256>, ProverSHPLONK<'_, Bn256>, _, _, _, _>(
+        params,
+        pk,
+        &[circuit],
+        &[&instances],
+        OsRng,
+        &mut transcript,
+    )?;
+    Ok(transcript.finalize())
+}
+
+pub fn verify(
+    params: &ParamsKZG<Bn256>,
+    vk: &VerifyingKey<G1Affine>,
+    instances: &[Vec<Fr>],
+    proof: &[u8],
+) -> Result<(), Error> {
+    let instances: Vec<&[Fr]> = instances.iter().map(Vec::as_slice).collect();
+    let mut transcript = Keccak256Transcript::new(proof);
+    verify_proof::<KZGCommitmentScheme<Bn256>, VerifierSHPLONK<'_, Bn256>, _, _, _>(
+        params.verifier_params(),
+        vk,
+        SingleStrategy::new(params),
+        &[&instances],
+        &mut transcript,
+    )
+}
+
+/// vk를 내장한 Solidity verifier 소스
+pub fn gen_solidity(
+    params: &ParamsKZG<Bn256>,
+    vk: &VerifyingKey<G1Affine>,
+    num_instances: usize,
+) -> Result<String, std::fmt::Error> {
+    SolidityGenerator::new(params, vk, BatchOpenScheme::Bdfg21, num_instances).render()
+}
@@ -0,0 +1,341 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use halo2curves::bn256::Fr;
+use halo2::circuit::Value;
+use halo2::dev::{MockProver, VerifyFailure};
+use halo2::plonk::{
+    Advice, Any, Assigned, Assignment, Challenge, Circuit, Column, ConstraintSystem, Error, FloorPlanner,
+    Fixed, Instance, Selector,
+};
+
+/// region 밖에서 할당된 셀 (lookup 테이블, floor planner가 배치한 상수)
+const TABLE_REGION: &str = "<tables and constants>";
+
+/// region 하나가 차지한 행
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegionUsage {
+    /// namespace 경로를 포함한 region 이름
+    pub name: String,
+    pub start: usize,
+    pub rows: usize,
+}
+
+/// 회로 크기 보고서
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircuitSize {
+    /// 할당된 가장 큰 행 + 1
+    pub rows: usize,
+    pub advice_columns: usize,
+    pub fixed_columns: usize,
+    pub instance_columns: usize,
+    pub selectors: usize,
+    pub lookups: usize,
+    pub gates: usize,
+    pub permutation_columns: usize,
+    pub degree: usize,
+    pub blinding_factors: usize,
+    pub regions: Vec<RegionUsage>,
+    /// 최상위 namespace(없으면 region 이름)별 행 수 합계
+    pub gadgets: BTreeMap<String, usize>,
+    /// 위 행과 blinding 행이 들어가는 최소 k
+    pub k: u32,
+}
+
+impl fmt::Display for CircuitSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "k = {} (rows used {} / {})", self.k, self.rows, 1usize << self.k)?;
+        writeln!(
+            f,
+            "columns: advice {}, fixed {}, instance {}, selectors {}",
+            self.advice_columns, self.fixed_columns, self.instance_columns, self.selectors
+        )?;
+        writeln!(
+            f,
+            "gates {}, lookups {}, permutation columns {}, degree {}",
+            self.gates, self.lookups, self.permutation_columns, self.degree
+        )?;
+        writeln!(f, "rows per gadget:")?;
+        for (name, rows) in &self.gadgets {
+            writeln!(f, "  {name}: {rows}")?;
+        }
+        Ok(())
+    }
+}
+
+/// 회로를 합성만 해서 크기를 잰다 (증명, 제약 검사 없음)
+pub fn estimate<C: Circuit<Fr>>(circuit: &C) -> Result<CircuitSize, Error> {
+    let mut cs = ConstraintSystem::default();
+    let config = C::configure(&mut cs);
+    let mut counter = RowCounter::default();
+    C::FloorPlanner::synthesize(&mut counter, circuit, config, cs.constants().clone())?;
+    counter.finish();
+
+    let rows = counter.max_row.map_or(0, |row| row + 1);
+    let blinding_factors = cs.blinding_factors();
+    let k = minimal_k(rows, blinding_factors, cs.minimum_rows());
+
+    let mut gadgets = BTreeMap::new();
+    for region in &counter.regions {
+        let gadget = region.name.split('/').next().unwrap_or(&region.name).to_string();
+        *gadgets.entry(gadget).or_insert(0) += region.rows;
+    }
+
+    Ok(CircuitSize {
+        rows,
+        advice_columns: cs.num_advice_columns(),
+        fixed_columns: cs.num_fixed_columns(),
+        instance_columns: cs.num_instance_columns(),
+        selectors: cs.num_selectors(),
+        lookups: cs.lookups().len(),
+        gates: cs.gates().len(),
+        permutation_columns: cs.permutation().get_columns().len(),
+        degree: cs.degree(),
+        blinding_factors,
+        regions: counter.regions,
+        gadgets,
+        k,
+    })
+}
+
+#[derive(Debug)]
+pub enum SizingError {
+    /// 합성 실패 또는 추정한 k에 행이 들어가지 않음
+    Synthesis(Error),
+    /// 추정한 k에서 MockProver 검증 실패
+    Unsatisfied(Vec<VerifyFailure>),
+}
+
+impl fmt::Display for SizingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SizingError::Synthesis(error) => write!(f, "synthesis failed: {error}"),
+            SizingError::Unsatisfied(failures) => {
+                writeln!(f, "{} constraint(s) not satisfied:", failures.len())?;
+                failures.iter().try_for_each(|failure| writeln!(f, "  {failure}"))
+            }
+        }
+    }
+}
+
+impl std::error::Error for SizingError {}
+
+impl From<Error> for SizingError {
+    fn from(error: Error) -> Self {
+        SizingError::Synthesis(error)
+    }
+}
+
+/// 추정한 k로 MockProver를 돌려서 실제로 들어가는지 확인
+pub fn confirm<C: Circuit<Fr>>(circuit: &C, instances: Vec<Vec<Fr>>) -> Result<CircuitSize, SizingError> {
+    let size = estimate(circuit)?;
+    let prover = MockProver::run(size.k, circuit, instances)?;
+    prover.verify().map_err(SizingError::Unsatisfied)?;
+    Ok(size)
+}
+
+/// 2^k - (blinding + 1) 개의 usable row에 rows가 들어가는 최소 k
+pub fn minimal_k(rows: usize, blinding_factors: usize, minimum_rows: usize) -> u32 {
+    let needed = (rows + blinding_factors + 1).max(minimum_rows);
+    needed.next_power_of_two().trailing_zeros()
+}
+
+/// 할당된 행만 세는 Assignment
+#[derive(Default)]
+struct RowCounter {
+    namespaces: Vec<String>,
+    current: Option<(String, Option<(usize, usize)>)>,
+    regions: Vec<RegionUsage>,
+    table_rows: Option<(usize, usize)>,
+    max_row: Option<usize>,
+}
+
+impl RowCounter {
+    fn touch(&mut self, row: usize) {
+        self.max_row = Some(self.max_row.map_or(row, |max| max.max(row)));
+        let range = match &mut self.current {
+            Some((_, range)) => range,
+            None => &mut self.table_rows,
+        };
+        *range = Some(range.map_or((row, row), |(lo, hi)| (lo.min(row), hi.max(row))));
+    }
+
+    fn close_region(&mut self) {
+        if let Some((name, Some((lo, hi)))) = self.current.take() {
+            self.regions.push(RegionUsage { name, start: lo, rows: hi - lo + 1 });
+        }
+    }
+
+    /// region 밖 할당(테이블)을 마지막 항목으로 추가
+    fn finish(&mut self) {
+        self.close_region();
+        if let Some((lo, hi)) = self.table_rows.take() {
+            self.regions.push(RegionUsage { name: TABLE_REGION.to_string(), start: lo, rows: hi - lo + 1 });
+        }
+    }
+}
+
+impl Assignment<Fr> for RowCounter {
+    fn enter_region<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        let mut path = self.namespaces.clone();
+        path.push(name_fn().into());
+        self.current = Some((path.join("/"), None));
+    }
+
+    fn exit_region(&mut self) {
+        self.close_region();
+    }
+
+    fn enable_selector<A, AR>(&mut self, _: A, _: &Selector, row: usize) -> Result<(), Error>
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.touch(row);
+        Ok(())
+    }
+
+    fn query_instance(&self, _: Column<Instance>, _: usize) -> Result<Value<Fr>, Error> {
+        Ok(Value::unknown())
+    }
+
+    fn assign_advice<V, VR, A, AR>(&mut self, _: A, _: Column<Advice>, row: usize, _: V) -> Result<(), Error>
+    where
+        V: FnOnce() -> Value<VR>,
+        VR: Into<Assigned<Fr>>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.touch(row);
+        Ok(())
+    }
+
+    fn assign_fixed<V, VR, A, AR>(&mut self, _: A, _: Column<Fixed>, row: usize, _: V) -> Result<(), Error>
+    where
+        V: FnOnce() -> Value<VR>,
+        VR: Into<Assigned<Fr>>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.touch(row);
+        Ok(())
+    }
+
+    fn copy(&mut self, _: Column<Any>, _: usize, _: Column<Any>, _: usize) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn fill_from_row(&mut self, _: Column<Fixed>, _: usize, _: Value<Assigned<Fr>>) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn get_challenge(&self, _: Challenge) -> Value<Fr> {
+        Value::unknown()
+    }
+
+    fn annotate_column<A, AR>(&mut self, _: A, _: Column<Any>)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.namespaces.push(name_fn().into());
+    }
+
+    fn pop_namespace(&mut self, _: Option<String>) {
+        self.namespaces.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::group_access::GroupAccessCircuit;
+    use crate::test_utils::{index_bits, merkle_proof, random_fr};
+    use halo2::circuit::{Layouter, SimpleFloorPlanner};
+
+    /// advice 셀을 rows개 할당하기만 하는 회로
+    #[derive(Clone, Default)]
+    struct RowsCircuit {
+        rows: usize,
+    }
+
+    impl Circuit<Fr> for RowsCircuit {
+        type Config = Column<Advice>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            meta.advice_column()
+        }
+
+        fn synthesize(&self, column: Self::Config, mut layouter: impl Layouter<Fr>) -> Result<(), Error> {
+            layouter.assign_region(
+                || "rows",
+                |mut region| {
+                    for row in 0..self.rows {
+                        region.assign_advice(|| "cell", column, row, || Value::known(Fr::zero()))?;
+                    }
+                    Ok(())
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn minimal_k_boundaries() {
+        // rows + blinding + 1 이 2^k 이하인 최소 k
+        assert_eq!(minimal_k(10, 5, 0), 4);
+        assert_eq!(minimal_k(11, 5, 0), 5);
+        assert_eq!(minimal_k(122, 5, 0), 7);
+        assert_eq!(minimal_k(123, 5, 0), 8);
+        assert_eq!(minimal_k(0, 5, 0), 3);
+        // 행이 적으면 minimum_rows가 정함
+        assert_eq!(minimal_k(1, 5, 100), 7);
+    }
+
+    #[test]
+    fn mock_prover_fits_estimated_k() {
+        let size = estimate(&RowsCircuit { rows: 1000 }).unwrap();
+        let usable = (1 << size.k) - size.blinding_factors - 1;
+
+        let circuit = RowsCircuit { rows: usable };
+        let fits = estimate(&circuit).unwrap();
+        assert_eq!((fits.rows, fits.k), (usable, size.k));
+        assert_eq!(MockProver::run(fits.k, &circuit, vec![]).unwrap().verify(), Ok(()));
+
+        // 한 행 더 쓰면 k가 하나 커지고, 이전 k에는 들어가지 않음
+        let circuit = RowsCircuit { rows: usable + 1 };
+        let grown = estimate(&circuit).unwrap();
+        assert_eq!(grown.k, size.k + 1);
+        assert!(MockProver::run(size.k, &circuit, vec![]).is_err());
+        assert_eq!(MockProver::run(grown.k, &circuit, vec![]).unwrap().verify(), Ok(()));
+    }
+
+    #[test]
+    fn confirm_reports_failures() {
+        let leaf = random_fr();
+        let (path_elements, root) = merkle_proof(leaf, 8, 3);
+        let circuit = GroupAccessCircuit { leaf, path_elements, path_indices: index_bits(3, 8), root };
+        let size = confirm(&circuit, circuit.instances()).unwrap();
+        assert_eq!(size, estimate(&circuit).unwrap());
+
+        match confirm(&circuit, vec![vec![root + Fr::one()]]) {
+            Err(SizingError::Unsatisfied(failures)) => assert!(!failures.is_empty()),
+            other => panic!("expected unsatisfied constraints, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//! 테스트 공용 도우미: witness 생성, MockProver 실패 종류 확인, advice 셀 변조
+
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+use halo2::circuit::{Layouter, Value};
+use halo2::dev::{MockProver, VerifyFailure};
+use halo2::plonk::{
+    Advice, Any, Assigned, Assignment, Challenge, Circuit, Column, ConstraintSystem, Error, FloorPlanner, Fixed,
+    Instance, Selector,
+};
+use halo2curves::bn256::Fr;
+use halo2curves::ff::Field;
+use halo2curves::group::Curve;
+use halo2curves::CurveAffine;
+use poseidon::{Pow5Chip, Pow5Config, P128Pow5T3};
+use rand_core::OsRng;
+
+use crate::gadgets::merkle::MerkleGadget;
+use crate::issuer::mod_n;
+use crate::sizing::estimate;
+
+pub fn random_fr() -> Fr {
+    Fr::random(OsRng)
+}
+
+/// 회로들과 같은 Poseidon 컬럼 배치
+pub fn configure_poseidon(meta: &mut ConstraintSystem<Fr>) -> Pow5Config<Fr, 3, 2> {
+    let state = [meta.advice_column(), meta.advice_column(), meta.advice_column()];
+    let partial_sbox = meta.advice_column();
+    let rc_a = [meta.fixed_column(), meta.fixed_column(), meta.fixed_column()];
+    let rc_b = [meta.fixed_column(), meta.fixed_column(), meta.fixed_column()];
+    meta.enable_constant(rc_b[0]);
+    Pow5Chip::<Fr, 3, 2>::configure::<P128Pow5T3>(meta, state, partial_sbox, rc_a, rc_b)
+}
+
+/// 임의의 sibling으로 depth 깊이의 merkle proof를 만들고 (path, root) 반환
+pub fn merkle_proof(leaf: Fr, depth: usize, leaf_index: usize) -> (Vec<Fr>, Fr) {
+    let path: Vec<Fr> = (0..depth).map(|_| random_fr()).collect();
+    let root = MerkleGadget::compute_root_native(leaf, &path, &index_bits(leaf_index, depth));
+    (path, root)
+}
+
+pub fn index_bits(leaf_index: usize, depth: usize) -> Vec<bool> {
+    (0..depth).map(|i| (leaf_index >> i) & 1 == 1).collect()
+}
+
+/// issuer 키 쌍 (sk, pk)
+pub fn ecdsa_keypair<C: CurveAffine>() -> (C::Scalar, C) {
+    let sk = C::Scalar::random(OsRng);
+    (sk, (C::generator() * sk).to_affine())
+}
+
+/// native ECDSA 서명 (r, s)
+pub fn ecdsa_sign<C: CurveAffine>(sk: C::Scalar, msg_hash: C::Scalar) -> (C::Scalar, C::Scalar) {
+    let k = C::Scalar::random(OsRng);
+    let big_r = (C::generator() * k).to_affine();
+    let r = mod_n::<C::Base, C::Scalar>(*big_r.coordinates().unwrap().x());
+    let s = k.invert().unwrap() * (msg_hash + r * sk);
+    (r, s)
+}
+
+/// 회로 크기에 맞는 k로 MockProver 실행
+pub fn mock_prove<C: Circuit<Fr>>(circuit: &C, instances: Vec<Vec<Fr>>) -> Result<(), Vec<VerifyFailure>> {
+    let k = estimate(circuit).expect("synthesis failed").k;
+    MockProver::run(k, circuit, instances).expect("mock prover failed to run").verify()
+}
+
+/// 이름이 gate인 gate의 제약이 깨졌는지 확인
+pub fn assert_gate_failure(result: Result<(), Vec<VerifyFailure>>, gate: &str) {
+    let failures = result.expect_err("expected a constraint failure");
+    let needle = format!("('{gate}')");
+    assert!(
+        failures.iter().any(|f| matches!(f, VerifyFailure::ConstraintNotSatisfied { .. }) && f.to_string().contains(&needle)),
+        "expected gate {gate} to fail, got {failures:#?}"
+    );
+}
+
+/// 어떤 gate든 제약이 깨졌는지 확인 (ECDSA처럼 여러 gate에 걸친 검사용)
+pub fn assert_constraint_failure(result: Result<(), Vec<VerifyFailure>>) {
+    let failures = result.expect_err("expected a constraint failure");
+    assert!(
+        failures.iter().any(|f| matches!(f, VerifyFailure::ConstraintNotSatisfied { .. })),
+        "expected a gate constraint to fail, got {failures:#?}"
+    );
+}
+
+/// copy constraint (public input 포함)가 깨졌는지 확인
+pub fn assert_permutation_failure(result: Result<(), Vec<VerifyFailure>>) {
+    let failures = result.expect_err("expected a permutation failure");
+    assert!(
+        failures.iter().any(|f| matches!(f, VerifyFailure::Permutation { .. })),
+        "expected a permutation failure, got {failures:#?}"
+    );
+}
+
+/// lookup이 깨졌는지 확인
+pub fn assert_lookup_failure(result: Result<(), Vec<VerifyFailure>>) {
+    let failures = result.expect_err("expected a lookup failure");
+    assert!(
+        failures.iter().any(|f| matches!(f, VerifyFailure::Lookup { .. })),
+        "expected a lookup failure, got {failures:#?}"
+    );
+}
+
+thread_local! {
+    /// 변조할 advice 셀의 할당 순번 (None이면 변조 없이 셀 수만 셈)
+    static MUTATION_TARGET: Cell<Option<usize>> = Cell::new(None);
+    /// 이번 합성에서 지금까지 할당된 advice 셀 수
+    static ADVICE_CELLS: Cell<usize> = Cell::new(0);
+}
+
+/// 회로를 그대로 합성하되 MUTATION_TARGET 번째 advice 셀에 1을 더한다
+#[derive(Clone, Default)]
+pub struct Mutated<C>(pub C);
+
+impl<C: Circuit<Fr>> Circuit<Fr> for Mutated<C> {
+    type Config = C::Config;
+    type FloorPlanner = MutatingFloorPlanner<C::FloorPlanner>;
+
+    fn without_witnesses(&self) -> Self {
+        Self(self.0.without_witnesses())
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+        C::configure(meta)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<Fr>) -> Result<(), Error> {
+        self.0.synthesize(config, layouter)
+    }
+}
+
+/// 원래 floor planner에 MutatingAssignment를 끼워 넣는다
+#[derive(Debug)]
+pub struct MutatingFloorPlanner<P>(PhantomData<P>);
+
+impl<P: FloorPlanner> FloorPlanner for MutatingFloorPlanner<P> {
+    fn synthesize<F: Field, CS: Assignment<F>, C: Circuit<F>>(
+        cs: &mut CS,
+        circuit: &C,
+        config: C::Config,
+        constants: Vec<Column<Fixed>>,
+    ) -> Result<(), Error> {
+        ADVICE_CELLS.with(|count| count.set(0));
+        P::synthesize(&mut MutatingAssignment { cs, _marker: PhantomData }, circuit, config, constants)
+    }
+}
+
+struct MutatingAssignment<'a, F: Field, CS: Assignment<F>> {
+    cs: &'a mut CS,
+    _marker: PhantomData<F>,
+}
+
+impl<'a, F: Field, CS: Assignment<F>> Assignment<F> for MutatingAssignment<'a, F, CS> {
+    fn enter_region<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.cs.enter_region(name_fn)
+    }
+
+    fn exit_region(&mut self) {
+        self.cs.exit_region()
+    }
+
+    fn enable_selector<A, AR>(&mut self, annotation: A, selector: &Selector, row: usize) -> Result<(), Error>
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.cs.enable_selector(annotation, selector, row)
+    }
+
+    fn query_instance(&self, column: Column<Instance>, row: usize) -> Result<Value<F>, Error> {
+        self.cs.query_instance(column, row)
+    }
+
+    fn assign_advice<V, VR, A, AR>(&mut self, annotation: A, column: Column<Advice>, row: usize, to: V) -> Result<(), Error>
+    where
+        V: FnOnce() -> Value<VR>,
+        VR: Into<Assigned<F>>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let index = ADVICE_CELLS.with(|count| count.replace(count.get() + 1));
+        let mutate = MUTATION_TARGET.with(|target| target.get()) == Some(index);
+        self.cs.assign_advice(annotation, column, row, || {
+            to().map(|v| {
+                let v: Assigned<F> = v.into();
+                if mutate { v + Assigned::from(F::ONE) } else { v }
+            })
+        })
+    }
+
+    fn assign_fixed<V, VR, A, AR>(&mut self, annotation: A, column: Column<Fixed>, row: usize, to: V) -> Result<(), Error>
+    where
+        V: FnOnce() -> Value<VR>,
+        VR: Into<Assigned<F>>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.cs.assign_fixed(annotation, column, row, to)
+    }
+
+    fn copy(&mut self, left: Column<Any>, left_row: usize, right: Column<Any>, right_row: usize) -> Result<(), Error> {
+        self.cs.copy(left, left_row, right, right_row)
+    }
+
+    fn fill_from_row(&mut self, column: Column<Fixed>, row: usize, to: Value<Assigned<F>>) -> Result<(), Error> {
+        self.cs.fill_from_row(column, row, to)
+    }
+
+    fn get_challenge(&self, challenge: Challenge) -> Value<F> {
+        self.cs.get_challenge(challenge)
+    }
+
+    fn annotate_column<A, AR>(&mut self, annotation: A, column: Column<Any>)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.cs.annotate_column(annotation, column)
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.cs.push_namespace(name_fn)
+    }
+
+    fn pop_namespace(&mut self, gadget_name: Option<String>) {
+        self.cs.pop_namespace(gadget_name)
+    }
+}
+
+/// advice 셀을 하나씩 변조해 보고 MockProver가 여전히 통과시키는 셀의 할당 순번을 반환
+/// 정상 witness가 통과하는 회로에만 의미가 있음
+/// 회로 단위 검사는 `audit::audit`을 쓰고, 이 함수는 gadget의 값 변조 테스트에 씀
+pub fn find_unconstrained_cells<C: Circuit<Fr> + Clone>(circuit: &C, instances: Vec<Vec<Fr>>) -> Vec<usize> {
+    let circuit = Mutated(circuit.clone());
+    let k = estimate(&circuit).expect("synthesis failed").k;
+
+    MUTATION_TARGET.with(|target| target.set(None));
+    let prover = MockProver::run(k, &circuit, instances.clone()).expect("mock prover failed to run");
+    assert_eq!(prover.verify(), Ok(()), "unmutated witness must be accepted");
+    let cells = ADVICE_CELLS.with(|count| count.get());
+
+    let unconstrained = (0..cells)
+        .filter(|&index| {
+            MUTATION_TARGET.with(|target| target.set(Some(index)));
+            MockProver::run(k, &circuit, instances.clone()).expect("mock prover failed to run").verify().is_ok()
+        })
+        .collect();
+    MUTATION_TARGET.with(|target| target.set(None));
+    unconstrained
+}
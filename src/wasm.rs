@@ -0,0 +1,238 @@
+//! 브라우저, Node에서 증명하기 위한 wasm-bindgen 바인딩 (`wasm` feature)
+//!
+//! witness는 JSON(`input` 모듈 형식), params/pk/vk는 `keys` 파일 바이트 그대로 받음
+//! prove는 binary proof envelope를 돌려주고 verify는 그 envelope만으로 검증함
+//!
+//! 빌드: `wasm-pack build --target web --features wasm`
+//! 테스트: `wasm-pack test --node --features wasm`, native 테스트는 `cargo test --features wasm`
+
+use std::fmt;
+
+use halo2::plonk::Error;
+use halo2curves::bn256::{Fr, G1Affine};
+use halo2curves::secp256r1::Secp256r1Affine;
+use halo2curves::CurveAffine;
+use wasm_bindgen::prelude::*;
+
+use crate::envelope::{EnvelopeError, ProofEnvelope};
+use crate::group_access::GroupAccessCircuit;
+use crate::identity_claim::IdentityClaimCircuit;
+use crate::input::{GroupAccessInput, IdentityClaimInput, InputError, PostProofInput};
+use crate::keys::{self, KeyError, KeyedCircuit};
+use crate::post_proof::PostProofCircuit;
+
+/// 바인딩 내부 오류. JS로 넘길 때 `JsError`가 됨
+/// JsError는 wasm32가 아니면 만들 수 없어서 native에서 테스트할 수 있게 따로 둠
+#[derive(Debug)]
+pub enum WasmError {
+    Input(InputError),
+    Key(KeyError),
+    Envelope(EnvelopeError),
+    Proving(Error),
+}
+
+impl fmt::Display for WasmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WasmError::Input(error) => write!(f, "{error}"),
+            WasmError::Key(error) => write!(f, "{error}"),
+            WasmError::Envelope(error) => write!(f, "{error}"),
+            WasmError::Proving(error) => write!(f, "proving failed: {error:?}"),
+        }
+    }
+}
+
+impl std::error::Error for WasmError {}
+
+impl From<InputError> for WasmError {
+    fn from(error: InputError) -> Self {
+        WasmError::Input(error)
+    }
+}
+
+impl From<KeyError> for WasmError {
+    fn from(error: KeyError) -> Self {
+        WasmError::Key(error)
+    }
+}
+
+impl From<EnvelopeError> for WasmError {
+    fn from(error: EnvelopeError) -> Self {
+        WasmError::Envelope(error)
+    }
+}
+
+impl From<Error> for WasmError {
+    fn from(error: Error) -> Self {
+        WasmError::Proving(error)
+    }
+}
+
+fn prove<C: KeyedCircuit>(params: &[u8], pk: &[u8], circuit: C, instances: Vec<Vec<Fr>>) -> Result<Vec<u8>, WasmError> {
+    let params = keys::read_params_from(&mut &params[..])?;
+    let pk = keys::read_pk_from(&mut &pk[..], &circuit, &params)?;
+    Ok(ProofEnvelope::prove(&params, &pk, circuit, instances)?.encode())
+}
+
+/// proof가 틀리면 false, 키나 envelope가 잘못되면 Err
+fn verify<C: KeyedCircuit>(params: &[u8], vk: &[u8], envelope: &[u8]) -> Result<bool, WasmError> {
+    let params = keys::read_params_from(&mut &params[..])?;
+    let (_, vk) = keys::read_vk_from::<C>(&mut &vk[..], None, &params)?;
+    match ProofEnvelope::decode(envelope)?.verify(&params, &vk) {
+        Ok(()) => Ok(true),
+        Err(EnvelopeError::Verification(_)) => Ok(false),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn prove_identity_claim<C: CurveAffine>(params: &[u8], pk: &[u8], witness: &str) -> Result<Vec<u8>, JsError> {
+    let circuit = IdentityClaimInput::from_json(witness)?.to_circuit::<C>()?;
+    let instances = circuit.instances();
+    Ok(prove(params, pk, circuit, instances)?)
+}
+
+#[wasm_bindgen(js_name = proveGroupAccess)]
+pub fn prove_group_access(params: &[u8], pk: &[u8], witness: &str) -> Result<Vec<u8>, JsError> {
+    let circuit = GroupAccessInput::from_json(witness)?.to_circuit()?;
+    let instances = circuit.instances();
+    Ok(prove(params, pk, circuit, instances)?)
+}
+
+#[wasm_bindgen(js_name = verifyGroupAccess)]
+pub fn verify_group_access(params: &[u8], vk: &[u8], envelope: &[u8]) -> Result<bool, JsError> {
+    Ok(verify::<GroupAccessCircuit>(params, vk, envelope)?)
+}
+
+#[wasm_bindgen(js_name = provePostProof)]
+pub fn prove_post_proof(params: &[u8], pk: &[u8], witness: &str) -> Result<Vec<u8>, JsError> {
+    let circuit = PostProofInput::from_json(witness)?.to_circuit()?;
+    let instances = circuit.instances();
+    Ok(prove(params, pk, circuit, instances)?)
+}
+
+#[wasm_bindgen(js_name = verifyPostProof)]
+pub fn verify_post_proof(params: &[u8], vk: &[u8], envelope: &[u8]) -> Result<bool, JsError> {
+    Ok(verify::<PostProofCircuit>(params, vk, envelope)?)
+}
+
+/// BN254 ECDSA issuer. ECDSA 검증 때문에 k가 커서 브라우저에서는 수십 초 걸릴 수 있음
+#[wasm_bindgen(js_name = proveIdentityClaim)]
+pub fn prove_bn256_identity_claim(params: &[u8], pk: &[u8], witness: &str) -> Result<Vec<u8>, JsError> {
+    prove_identity_claim::<G1Affine>(params, pk, witness)
+}
+
+#[wasm_bindgen(js_name = verifyIdentityClaim)]
+pub fn verify_bn256_identity_claim(params: &[u8], vk: &[u8], envelope: &[u8]) -> Result<bool, JsError> {
+    Ok(verify::<IdentityClaimCircuit<G1Affine>>(params, vk, envelope)?)
+}
+
+/// ES256 (P-256) issuer
+#[wasm_bindgen(js_name = proveEs256IdentityClaim)]
+pub fn prove_es256_identity_claim(params: &[u8], pk: &[u8], witness: &str) -> Result<Vec<u8>, JsError> {
+    prove_identity_claim::<Secp256r1Affine>(params, pk, witness)
+}
+
+#[wasm_bindgen(js_name = verifyEs256IdentityClaim)]
+pub fn verify_es256_identity_claim(params: &[u8], vk: &[u8], envelope: &[u8]) -> Result<bool, JsError> {
+    Ok(verify::<IdentityClaimCircuit<Secp256r1Affine>>(params, vk, envelope)?)
+}
+
+/// 서버로 보낼 때 쓰는 JSON envelope
+#[wasm_bindgen(js_name = envelopeToJson)]
+pub fn envelope_to_json(envelope: &[u8]) -> Result<String, JsError> {
+    Ok(ProofEnvelope::decode(envelope)?.to_json())
+}
+
+#[wasm_bindgen(js_name = envelopeFromJson)]
+pub fn envelope_from_json(json: &str) -> Result<Vec<u8>, JsError> {
+    Ok(ProofEnvelope::from_json(json)?.encode())
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2::poly::kzg::commitment::ParamsKZG;
+    use halo2curves::bn256::Bn256;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    use crate::input::{format_field, MerklePathInput};
+    use crate::prover;
+    use crate::sizing::estimate;
+    use crate::test_utils::{merkle_proof, random_fr};
+
+    /// depth 깊이 group access witness
+    fn group_access_input(depth: usize) -> GroupAccessInput {
+        let leaf = random_fr();
+        let (path, root) = merkle_proof(leaf, depth, 3);
+        GroupAccessInput {
+            leaf: format_field(&leaf),
+            merkle_path: MerklePathInput { siblings: path.iter().map(format_field).collect(), leaf_index: 3 },
+            root: format_field(&root),
+        }
+    }
+
+    /// params 바이트와 circuit으로 만든 pk, vk 바이트
+    fn group_access_keys(params: &ParamsKZG<Bn256>, circuit: &GroupAccessCircuit) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+        let pk = prover::keygen(params, circuit).unwrap();
+        let (mut params_bytes, mut pk_bytes, mut vk_bytes) = (vec![], vec![], vec![]);
+        keys::write_params_to(&mut params_bytes, params).unwrap();
+        keys::write_pk_to(&mut pk_bytes, circuit, &pk).unwrap();
+        keys::write_vk_to(&mut vk_bytes, circuit, pk.get_vk()).unwrap();
+        (params_bytes, pk_bytes, vk_bytes)
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn prove_verify_round_trip() {
+        let circuit = group_access_input(4).to_circuit().unwrap();
+        let (params, pk, vk) = group_access_keys(&prover::setup_params(estimate(&circuit).unwrap().k), &circuit);
+        let envelope = prove(&params, &pk, circuit.clone(), circuit.instances()).unwrap();
+        assert!(verify::<GroupAccessCircuit>(&params, &vk, &envelope).unwrap());
+
+        let mut tampered = ProofEnvelope::decode(&envelope).unwrap();
+        tampered.instances[0][0] += Fr::one();
+        assert!(!verify::<GroupAccessCircuit>(&params, &vk, &tampered.encode()).unwrap());
+
+        assert!(matches!(
+            verify::<GroupAccessCircuit>(&params, &vk, &envelope[..envelope.len() - 1]),
+            Err(WasmError::Envelope(EnvelopeError::Truncated))
+        ));
+        // pk 바이트는 vk로 읽을 수 없음
+        assert!(matches!(verify::<GroupAccessCircuit>(&params, &pk, &envelope), Err(WasmError::Key(_))));
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn rejects_envelope_for_other_circuit() {
+        // 깊이가 다르면 회로 모양이 달라 같은 params라도 vk fingerprint가 맞지 않음
+        let shallow = group_access_input(4).to_circuit().unwrap();
+        let deep = group_access_input(8).to_circuit().unwrap();
+        let params = prover::setup_params(estimate(&deep).unwrap().k.max(estimate(&shallow).unwrap().k));
+        let (params_bytes, pk, _) = group_access_keys(&params, &shallow);
+        let (_, _, other_vk) = group_access_keys(&params, &deep);
+
+        let envelope = prove(&params_bytes, &pk, shallow.clone(), shallow.instances()).unwrap();
+        assert!(matches!(
+            verify::<GroupAccessCircuit>(&params_bytes, &other_vk, &envelope),
+            Err(WasmError::Envelope(EnvelopeError::FingerprintMismatch { .. }))
+        ));
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    #[wasm_bindgen_test]
+    fn exported_bindings_round_trip() {
+        let input = group_access_input(4);
+        let circuit = input.to_circuit().unwrap();
+        let (params, pk, vk) = group_access_keys(&prover::setup_params(estimate(&circuit).unwrap().k), &circuit);
+        let witness = serde_json::to_string(&input).unwrap();
+
+        let envelope = prove_group_access(&params, &pk, &witness).unwrap();
+        assert!(verify_group_access(&params, &vk, &envelope).unwrap());
+
+        let json = envelope_to_json(&envelope).unwrap();
+        assert_eq!(envelope_from_json(&json).unwrap(), envelope);
+        // group access vk를 post proof vk로 읽으면 회로 종류가 달라 예외
+        assert!(verify_post_proof(&params, &vk, &envelope).is_err());
+    }
+}
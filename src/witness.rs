@@ -0,0 +1,303 @@
+use std::fmt;
+
+use halo2curves::bn256::{Fr, G1Affine};
+use halo2curves::ff::Field;
+use halo2curves::group::Curve;
+use halo2curves::CurveAffine;
+
+use crate::gadgets::merkle::MerkleGadget;
+use crate::gadgets::poseidon::PoseidonGadget;
+use crate::identity_claim::IdentityClaimCircuit;
+use crate::issuer::mod_n;
+
+/// issuer가 발급한 credential. claim hash = Poseidon(value, min, max, Poseidon(holder_secret))
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Credential {
+    pub value: u64,
+    pub min: u64,
+    pub max: u64,
+    pub holder_secret: Fr,
+}
+
+impl Credential {
+    pub fn claim_hash(&self) -> Fr {
+        IdentityClaimCircuit::<G1Affine>::claim_hash(
+            Fr::from(self.value),
+            Fr::from(self.min),
+            Fr::from(self.max),
+            self.holder_secret,
+        )
+    }
+
+    /// issuer 곡선 C에서 서명되는 msg_hash
+    pub fn signature_hash<C: CurveAffine>(&self) -> C::Scalar {
+        IdentityClaimCircuit::<C>::signature_hash(self.claim_hash())
+    }
+}
+
+/// claim hash에 대한 issuer의 ECDSA 서명
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IssuerSignature<C: CurveAffine> {
+    pub pk: C,
+    pub r: C::Scalar,
+    pub s: C::Scalar,
+}
+
+impl<C: CurveAffine> IssuerSignature<C> {
+    /// 주어진 nonce로 서명 (같은 입력이면 같은 서명)
+    pub fn sign(sk: C::Scalar, nonce: C::Scalar, credential: &Credential) -> Result<Self, WitnessError> {
+        let msg_hash = credential.signature_hash::<C>();
+        let r = x_mod_n::<C>((C::generator() * nonce).to_affine()).ok_or(WitnessError::InvalidSignature)?;
+        let nonce_inv = Option::<C::Scalar>::from(nonce.invert()).ok_or(WitnessError::InvalidSignature)?;
+        let s = nonce_inv * (msg_hash + r * sk);
+        if r.is_zero_vartime() || s.is_zero_vartime() {
+            return Err(WitnessError::InvalidSignature);
+        }
+        Ok(Self { pk: (C::generator() * sk).to_affine(), r, s })
+    }
+
+    /// native ECDSA 검증
+    pub fn verify(&self, msg_hash: C::Scalar) -> bool {
+        let s_inv = match Option::<C::Scalar>::from(self.s.invert()) {
+            Some(s_inv) => s_inv,
+            None => return false,
+        };
+        if self.r.is_zero_vartime() {
+            return false;
+        }
+        let point = (C::generator() * (msg_hash * s_inv) + self.pk * (self.r * s_inv)).to_affine();
+        x_mod_n::<C>(point) == Some(self.r)
+    }
+}
+
+/// R.x mod n. 무한원점이면 None
+fn x_mod_n<C: CurveAffine>(point: C) -> Option<C::Scalar> {
+    let coordinates: Option<_> = point.coordinates().into();
+    Some(mod_n(*coordinates?.x()))
+}
+
+/// 회로와 같은 Poseidon 해시를 쓰는 native Merkle tree
+/// 채워지지 않은 leaf는 0이고, 채워진 부분만 저장함
+#[derive(Clone, Debug)]
+pub struct MerkleTree {
+    depth: usize,
+    /// levels[0]: leaf, levels[depth]: root
+    levels: Vec<Vec<Fr>>,
+    /// 높이별 빈 subtree의 해시
+    zeros: Vec<Fr>,
+}
+
+impl MerkleTree {
+    pub fn new(depth: usize) -> Self {
+        let mut zeros = vec![Fr::zero()];
+        for level in 0..depth {
+            zeros.push(PoseidonGadget::hash_native([zeros[level], zeros[level]]));
+        }
+        Self { depth, levels: vec![vec![]; depth + 1], zeros }
+    }
+
+    pub fn from_leaves(depth: usize, leaves: &[Fr]) -> Result<Self, WitnessError> {
+        let mut tree = Self::new(depth);
+        for leaf in leaves {
+            tree.insert(*leaf)?;
+        }
+        Ok(tree)
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// 다음 빈 자리에 leaf를 넣고 index 반환
+    pub fn insert(&mut self, leaf: Fr) -> Result<usize, WitnessError> {
+        let index = self.len();
+        if index >= 1 << self.depth {
+            return Err(WitnessError::TreeFull);
+        }
+        self.levels[0].push(leaf);
+
+        let mut position = index;
+        for level in 0..self.depth {
+            let left = self.node(level, position & !1);
+            let right = self.node(level, position | 1);
+            position >>= 1;
+            let parent = PoseidonGadget::hash_native([left, right]);
+            match self.levels[level + 1].get_mut(position) {
+                Some(node) => *node = parent,
+                None => self.levels[level + 1].push(parent),
+            }
+        }
+        Ok(index)
+    }
+
+    pub fn root(&self) -> Fr {
+        self.node(self.depth, 0)
+    }
+
+    pub fn leaf(&self, index: usize) -> Option<Fr> {
+        self.levels[0].get(index).copied()
+    }
+
+    pub fn position(&self, leaf: Fr) -> Option<usize> {
+        self.levels[0].iter().position(|l| *l == leaf)
+    }
+
+    /// leaf에서 root 방향 sibling 목록
+    pub fn proof(&self, index: usize) -> Result<Vec<Fr>, WitnessError> {
+        if index >= self.len() {
+            return Err(WitnessError::LeafIndexOutOfRange(index));
+        }
+        Ok((0..self.depth).map(|level| self.node(level, (index >> level) ^ 1)).collect())
+    }
+
+    fn node(&self, level: usize, position: usize) -> Fr {
+        self.levels[level].get(position).copied().unwrap_or(self.zeros[level])
+    }
+}
+
+/// 검증자(앱) 식별자. nullifier의 scope
+/// 값 조건은 credential의 [min, max] range check뿐이고, 임의의 `Policy`는 `PolicyClaimCircuit`에서 증명함
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClaimScope {
+    pub app_scope: Fr,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WitnessError {
+    /// value가 credential의 [min, max] 밖
+    ValueOutOfRange,
+    /// 서명이 credential의 claim hash와 맞지 않음
+    InvalidSignature,
+    /// claim hash가 tree의 leaf가 아님
+    ClaimNotInTree,
+    LeafIndexOutOfRange(usize),
+    TreeFull,
+}
+
+impl fmt::Display for WitnessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WitnessError::ValueOutOfRange => write!(f, "credential value is outside [min, max]"),
+            WitnessError::InvalidSignature => write!(f, "issuer signature does not match the credential"),
+            WitnessError::ClaimNotInTree => write!(f, "claim hash is not a leaf of the merkle tree"),
+            WitnessError::LeafIndexOutOfRange(index) => write!(f, "leaf index {index} is out of range"),
+            WitnessError::TreeFull => write!(f, "merkle tree is full"),
+        }
+    }
+}
+
+impl std::error::Error for WitnessError {}
+
+/// `IdentityClaimCircuit`의 witness를 native로 계산하고 검사한다
+pub struct IdentityClaimWitness<'a, C: CurveAffine> {
+    pub credential: &'a Credential,
+    pub signature: &'a IssuerSignature<C>,
+    pub tree: &'a MerkleTree,
+    pub scope: &'a ClaimScope,
+}
+
+impl<'a, C: CurveAffine> IdentityClaimWitness<'a, C> {
+    /// 회로와 public input을 만든다. 증명 전에 실패할 조건을 모두 native로 확인함
+    pub fn build(&self) -> Result<(IdentityClaimCircuit<C>, Vec<Vec<Fr>>), WitnessError> {
+        let credential = self.credential;
+        if !(credential.min <= credential.value && credential.value <= credential.max) {
+            return Err(WitnessError::ValueOutOfRange);
+        }
+
+        let claim_hash = credential.claim_hash();
+        let signature_hash = credential.signature_hash::<C>();
+        if !self.signature.verify(signature_hash) {
+            return Err(WitnessError::InvalidSignature);
+        }
+
+        let leaf_index = self.tree.position(claim_hash).ok_or(WitnessError::ClaimNotInTree)?;
+        let merkle_proof = self.tree.proof(leaf_index)?;
+        let indices: Vec<bool> = (0..self.tree.depth()).map(|i| (leaf_index >> i) & 1 == 1).collect();
+        debug_assert_eq!(MerkleGadget::compute_root_native(claim_hash, &merkle_proof, &indices), self.tree.root());
+
+        let coordinates: Option<_> = self.signature.pk.coordinates().into();
+        let coordinates = coordinates.ok_or(WitnessError::InvalidSignature)?;
+        let circuit = IdentityClaimCircuit {
+            merkle_root: self.tree.root(),
+            merkle_proof,
+            leaf_index,
+            value: Fr::from(credential.value),
+            min: Fr::from(credential.min),
+            max: Fr::from(credential.max),
+            sig_r: self.signature.r,
+            sig_s: self.signature.s,
+            pk_x: *coordinates.x(),
+            pk_y: *coordinates.y(),
+            holder_secret: credential.holder_secret,
+            app_scope: self.scope.app_scope,
+        };
+        let instances = circuit.instances();
+        Ok((circuit, instances))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_prove, random_fr};
+
+    const DEPTH: usize = 4;
+
+    fn credential(value: u64) -> Credential {
+        Credential { value, min: 18, max: 65, holder_secret: random_fr() }
+    }
+
+    fn issue(credential: &Credential) -> (IssuerSignature<G1Affine>, MerkleTree) {
+        let signature = IssuerSignature::sign(Fr::from(7), Fr::from(11), credential).unwrap();
+        let leaves = [random_fr(), random_fr(), credential.claim_hash(), random_fr()];
+        (signature, MerkleTree::from_leaves(DEPTH, &leaves).unwrap())
+    }
+
+    #[test]
+    fn tree_proofs_match_root() {
+        let leaves: Vec<Fr> = (0..5).map(|_| random_fr()).collect();
+        let tree = MerkleTree::from_leaves(DEPTH, &leaves).unwrap();
+        for (index, leaf) in leaves.iter().enumerate() {
+            let indices: Vec<bool> = (0..DEPTH).map(|i| (index >> i) & 1 == 1).collect();
+            let root = MerkleGadget::compute_root_native(*leaf, &tree.proof(index).unwrap(), &indices);
+            assert_eq!(root, tree.root());
+        }
+    }
+
+    #[test]
+    fn builds_accepted_circuit() {
+        let credential = credential(30);
+        let (signature, tree) = issue(&credential);
+        let scope = ClaimScope { app_scope: random_fr() };
+        let witness = IdentityClaimWitness { credential: &credential, signature: &signature, tree: &tree, scope: &scope };
+        let (circuit, instances) = witness.build().unwrap();
+        assert_eq!(mock_prove(&circuit, instances), Ok(()));
+    }
+
+    #[test]
+    fn rejects_inconsistent_inputs() {
+        let credential = credential(30);
+        let (signature, tree) = issue(&credential);
+        let scope = ClaimScope { app_scope: random_fr() };
+
+        let other = Credential { value: 31, ..credential.clone() };
+        let witness = IdentityClaimWitness { credential: &other, signature: &signature, tree: &tree, scope: &scope };
+        assert_eq!(witness.build().err(), Some(WitnessError::InvalidSignature));
+
+        let outside = Credential { value: 70, ..credential.clone() };
+        let witness = IdentityClaimWitness { credential: &outside, signature: &signature, tree: &tree, scope: &scope };
+        assert_eq!(witness.build().err(), Some(WitnessError::ValueOutOfRange));
+
+        let empty = MerkleTree::new(DEPTH);
+        let witness = IdentityClaimWitness { credential: &credential, signature: &signature, tree: &empty, scope: &scope };
+        assert_eq!(witness.build().err(), Some(WitnessError::ClaimNotInTree));
+    }
+}
@@ -0,0 +1,189 @@
+//! 회로별 비용 snapshot
+//!
+//! tests/snapshots/<회로>.snap 과 현재 CostReport를 비교한다
+//! snapshot을 처음 만들거나 의도한 변경이면 `UPDATE_SNAPSHOTS=1 cargo test --test cost_snapshots`로 쓰고 commit
+//! snapshot이 없으면 실패함 (CI에서 조용히 새로 쓰고 통과하지 않도록)
+
+use std::marker::PhantomData;
+use std::path::PathBuf;
+
+use circuits::cost::CostReport;
+use circuits::eth_ownership::{AddressVisibility, EthOwnershipCircuit};
+use circuits::group_access::GroupAccessCircuit;
+use circuits::identity_claim::{Es256IdentityClaimCircuit, IdentityClaimCircuit};
+use circuits::jwt_claim::{Es256JwtClaimCircuit, JsonValueKind, JwtField, JwtOutput, JwtSignature, Rs256JwtClaimCircuit};
+use circuits::policy::Policy;
+use circuits::policy_claim::PolicyClaimCircuit;
+use circuits::post_proof::PostProofCircuit;
+use halo2::plonk::Circuit;
+use halo2curves::bn256::Fr;
+use halo2curves::ff::Field;
+use halo2curves::group::Curve;
+use halo2curves::secp256k1::{Fq as Secp256k1Scalar, Secp256k1Affine};
+use halo2curves::secp256r1::{Fq as P256Scalar, Secp256r1Affine};
+use halo2curves::CurveAffine;
+use num_bigint::BigUint;
+
+/// 샘플 회로의 merkle 깊이
+const MERKLE_DEPTH: usize = 20;
+
+fn check_snapshot<C: Circuit<Fr>>(name: &str, circuit: &C, num_instances: usize) {
+    let report = CostReport::measure(circuit, num_instances).expect("synthesis failed");
+    let actual = report.to_snapshot();
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots").join(format!("{name}.snap"));
+
+    if std::env::var("UPDATE_SNAPSHOTS").as_deref() == Ok("1") {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing cost snapshot {}; run `UPDATE_SNAPSHOTS=1 cargo test --test cost_snapshots` and commit it\n\n{actual}",
+            path.display()
+        )
+    });
+    assert_eq!(
+        expected, actual,
+        "cost snapshot for {name} changed; rerun with UPDATE_SNAPSHOTS=1 if intended"
+    );
+}
+
+// 비용만 재므로 witness는 합성 중 panic하지 않을 정도면 충분 (서명이 유효할 필요 없음)
+
+fn identity_claim() -> IdentityClaimCircuit {
+    let pk = halo2curves::bn256::G1Affine::generator();
+    IdentityClaimCircuit {
+        merkle_root: Fr::one(),
+        merkle_proof: vec![Fr::one(); MERKLE_DEPTH],
+        leaf_index: 0,
+        value: Fr::from(20),
+        min: Fr::from(18),
+        max: Fr::from(65),
+        sig_r: Fr::one(),
+        sig_s: Fr::one(),
+        pk_x: *pk.coordinates().unwrap().x(),
+        pk_y: *pk.coordinates().unwrap().y(),
+        holder_secret: Fr::one(),
+        app_scope: Fr::one(),
+    }
+}
+
+fn es256_identity_claim() -> Es256IdentityClaimCircuit {
+    let pk = Secp256r1Affine::generator();
+    IdentityClaimCircuit {
+        merkle_root: Fr::one(),
+        merkle_proof: vec![Fr::one(); MERKLE_DEPTH],
+        leaf_index: 0,
+        value: Fr::from(20),
+        min: Fr::from(18),
+        max: Fr::from(65),
+        sig_r: P256Scalar::ONE,
+        sig_s: P256Scalar::ONE,
+        pk_x: *pk.coordinates().unwrap().x(),
+        pk_y: *pk.coordinates().unwrap().y(),
+        holder_secret: Fr::one(),
+        app_scope: Fr::one(),
+    }
+}
+
+#[test]
+fn identity_claim_cost() {
+    check_snapshot("identity_claim", &identity_claim(), 4);
+}
+
+#[test]
+fn es256_identity_claim_cost() {
+    check_snapshot("es256_identity_claim", &es256_identity_claim(), 4);
+}
+
+#[test]
+fn group_access_cost() {
+    let circuit = GroupAccessCircuit {
+        leaf: Fr::one(),
+        path_elements: vec![Fr::one(); MERKLE_DEPTH],
+        path_indices: vec![false; MERKLE_DEPTH],
+        root: Fr::one(),
+    };
+    check_snapshot("group_access", &circuit, 1);
+}
+
+#[test]
+fn post_proof_cost() {
+    let circuit = PostProofCircuit {
+        value: Fr::from(20),
+        min: Fr::from(18),
+        max: Fr::from(65),
+        holder_secret: Fr::one(),
+        app_scope: Fr::one(),
+        post_hash: Fr::one(),
+        merkle_root: Fr::one(),
+        merkle_proof: vec![Fr::one(); MERKLE_DEPTH],
+        leaf_index: 0,
+    };
+    check_snapshot("post_proof", &circuit, 4);
+}
+
+#[test]
+fn policy_claim_cost() {
+    let pk = halo2curves::bn256::G1Affine::generator();
+    let circuit = PolicyClaimCircuit {
+        policy: Policy::gte(0, 18).and(Policy::in_set(1, [82, 410])),
+        attributes: vec![Fr::from(20), Fr::from(410)],
+        merkle_proof: vec![Fr::one(); MERKLE_DEPTH],
+        leaf_index: 0,
+        sig_r: Fr::one(),
+        sig_s: Fr::one(),
+        pk_x: *pk.coordinates().unwrap().x(),
+        pk_y: *pk.coordinates().unwrap().y(),
+        holder_secret: Fr::one(),
+        app_scope: Fr::one(),
+    };
+    check_snapshot("policy_claim", &circuit, 5);
+}
+
+#[test]
+fn eth_ownership_cost() {
+    let circuit = EthOwnershipCircuit {
+        visibility: AddressVisibility::Hidden,
+        pk: (Secp256k1Affine::generator() * Secp256k1Scalar::from(7)).to_affine(),
+        sig_r: Secp256k1Scalar::ONE,
+        sig_s: Secp256k1Scalar::ONE,
+        message: b"sign in to example.org: nonce 0123456789abcdef".to_vec(),
+        blinding: Fr::one(),
+    };
+    let num_instances = circuit.instances()[0].len();
+    check_snapshot("eth_ownership", &circuit, num_instances);
+}
+
+#[test]
+fn jwt_claim_cost() {
+    let circuit = Es256JwtClaimCircuit {
+        field: JwtField::new("exp", JsonValueKind::Number),
+        output: JwtOutput::Policy(Policy::gte(0, 1_700_000_000)),
+        max_len: 1024,
+        signed: vec![b'A'; 512],
+        signature: JwtSignature::Es256 {
+            pk: Secp256r1Affine::generator(),
+            r: P256Scalar::ONE,
+            s: P256Scalar::ONE,
+        },
+        payload_offset: 37,
+        field_position: 0,
+        scheme: PhantomData,
+    };
+    check_snapshot("jwt_claim_es256", &circuit, 2);
+
+    let modulus = (BigUint::from(1u64) << 2047) + 1u64;
+    let circuit = Rs256JwtClaimCircuit {
+        field: circuit.field,
+        output: circuit.output,
+        max_len: circuit.max_len,
+        signed: circuit.signed,
+        signature: JwtSignature::Rs256 { signature: BigUint::from(3u64), modulus },
+        payload_offset: circuit.payload_offset,
+        field_position: circuit.field_position,
+        scheme: PhantomData,
+    };
+    check_snapshot("jwt_claim_rs256", &circuit, 2);
+}